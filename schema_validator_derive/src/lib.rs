@@ -1,45 +1,629 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Data, Fields};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, DeriveInput, Data, DataEnum, Expr, ExprLit, Fields,
+    FieldsNamed, FieldsUnnamed, GenericArgument, GenericParam, Generics, Ident, Lit, LitStr,
+    PathArguments, Type,
+};
 
-#[proc_macro_derive(Validate)]
+#[proc_macro_derive(Validate, attributes(validate))]
 pub fn derive_validate(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let name = input.ident;
 
-    let fields = match input.data {
-        Data::Struct(data) => match data.fields {
-            Fields::Named(fields) => fields.named,
-            _ => panic!("Only named fields are supported"),
+    match expand(input) {
+        Ok(gen) => TokenStream::from(gen),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let attrs = Attrs::parse(&input.attrs)?;
+    attrs.reject_calls()?;
+    let target = Target::new(input.ident, &input.generics, &input.data, attrs.title.clone())?;
+
+    let gen = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => derive_struct(&target, fields)?,
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => derive_newtype_struct(&target, fields)?,
+            Fields::Unnamed(fields) => derive_tuple_struct(&target, fields)?,
+            Fields::Unit => return Err(syn::Error::new(target.name.span(), "unit structs are not supported")),
         },
-        _ => panic!("Only structs are supported"),
-    };
+        Data::Enum(data) => {
+            let unit_only = data.variants.iter().all(|variant| matches!(variant.fields, Fields::Unit));
+            let gen = if unit_only && attrs.tag.is_none() {
+                derive_unit_enum(&target, data)?
+            } else {
+                derive_tagged_enum(&target, data, &attrs)?
+            };
+            let from_any = target.impl_for(quote! { schema_validator::schema::mapping::FromAny });
+            quote! {
+                #gen
 
-    let field_names: Vec<_> = fields.iter()
-        .map(|f| f.ident.as_ref().unwrap())
-        .collect();
-    let field_types: Vec<_> = fields.iter()
-        .map(|f| &f.ty)
-        .collect();
+                #from_any {
+                    #[allow(clippy::result_large_err)]
+                    fn from_any(value: &dyn std::any::Any) -> schema_validator::ValidationResult<Self> {
+                        if let Some(value) = value.downcast_ref::<Self>() {
+                            return Ok(value.clone());
+                        }
+                        schema_validator::Schema::validate(
+                            &<Self as schema_validator::schema::mapping::Validate>::schema(),
+                            value,
+                        )
+                    }
+                }
+            }
+        }
+        Data::Union(_) => return Err(syn::Error::new(target.name.span(), "unions are not supported")),
+    };
 
-    let gen = quote! {
-        impl schema_validator::schema::clone::CloneAny for #name {
+    let clone_any = target.impl_for(quote! { schema_validator::schema::clone::CloneAny });
+    Ok(quote! {
+        #clone_any {
             fn clone_any(&self) -> Box<dyn std::any::Any> {
                 Box::new(self.clone())
             }
         }
 
-        impl schema_validator::schema::mapping::FromFields for #name {
-            fn from_fields(fields: &std::collections::HashMap<String, Box<dyn std::any::Any>>) -> Option<Self> {
-                Some(Self {
-                    #(
-                        #field_names: fields.get(stringify!(#field_names))?
-                            .downcast_ref::<#field_types>()?.clone(),
-                    )*
-                })
+        #gen
+    })
+}
+
+/// The type a derive is generated for, with the bounds its impls need.
+struct Target {
+    name: Ident,
+    generics: Generics,
+    /// The `title` of the type, exported with its schema.
+    title: Option<LitStr>,
+}
+
+impl Target {
+    /// Collects the generics of the type. Type parameters must be `Clone` and
+    /// `'static` like the type itself, and every field type must have a schema,
+    /// so the impls carry those bounds.
+    ///
+    /// Validation produces owned values, so lifetime parameters and borrowed
+    /// fields are rejected.
+    fn new(name: Ident, generics: &Generics, data: &Data, title: Option<LitStr>) -> syn::Result<Self> {
+        if let Some(lifetime) = generics.lifetimes().next() {
+            return Err(syn::Error::new(
+                lifetime.lifetime.span(),
+                "lifetime parameters are not supported, validated values are owned",
+            ));
+        }
+        if let Some(ty) = field_types(data).into_iter().find(|ty| matches!(ty, Type::Reference(_))) {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "borrowed fields are not supported, use an owned type such as `String`",
+            ));
+        }
+
+        let mut generics = generics.clone();
+        if generics.params.is_empty() {
+            return Ok(Target { name, generics, title });
+        }
+
+        let params: Vec<GenericParam> = generics.params.iter().cloned().collect();
+        let where_clause = generics.make_where_clause();
+        let mut has_types = false;
+        for param in params {
+            match param {
+                GenericParam::Type(param) => {
+                    let ident = param.ident;
+                    has_types = true;
+                    where_clause.predicates.push(parse_quote! { #ident: Clone + 'static });
+                }
+                GenericParam::Lifetime(_) | GenericParam::Const(_) => {}
             }
         }
+
+        if has_types {
+            for ty in field_types(data) {
+                where_clause.predicates.push(parse_quote! {
+                    #ty: schema_validator::schema::mapping::Validate + schema_validator::schema::mapping::FromAny
+                });
+            }
+        }
+
+        Ok(Target { name, generics, title })
+    }
+
+    /// Returns `impl<...> #trait_path for Name<...> where ...`.
+    fn impl_for(&self, trait_path: TokenStream2) -> TokenStream2 {
+        let name = &self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        quote! { impl #impl_generics #trait_path for #name #ty_generics #where_clause }
+    }
+
+    /// Returns the `Validate` impl returning `schema` of type `schema_type`,
+    /// wrapped with the title of the type if it has one.
+    fn impl_validate(&self, schema_type: TokenStream2, schema: TokenStream2) -> TokenStream2 {
+        let validate = self.impl_for(quote! { schema_validator::schema::mapping::Validate });
+        let (schema_type, schema) = match &self.title {
+            Some(title) => (
+                quote! { schema_validator::schema::annotated::AnnotatedSchema<#schema_type> },
+                quote! { schema_validator::schema::annotated::AnnotatedSchema::new(#schema).title(#title) },
+            ),
+            None => (schema_type, schema),
+        };
+        quote! {
+            #validate {
+                type Schema = #schema_type;
+
+                #[allow(clippy::result_large_err)]
+                fn schema() -> Self::Schema {
+                    #schema
+                }
+            }
+        }
+    }
+}
+
+/// Returns the types of every field of a struct or enum.
+fn field_types(data: &Data) -> Vec<&Type> {
+    match data {
+        Data::Struct(data) => data.fields.iter().map(|field| &field.ty).collect(),
+        Data::Enum(data) => data.variants.iter()
+            .flat_map(|variant| variant.fields.iter().map(|field| &field.ty))
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    }
+}
+
+/// Options given with `#[validate(...)]`.
+#[derive(Default)]
+struct Attrs {
+    rename: Option<String>,
+    tag: Option<String>,
+    content: Option<String>,
+    /// The `title` of the field or type, for generated documentation.
+    title: Option<LitStr>,
+    /// Method calls applied to the schema of a field, in the order written.
+    calls: Vec<TokenStream2>,
+    /// The `.set_message(...)` call, applied after every other call.
+    message: Option<TokenStream2>,
+    /// The `default` of the field, substituted when it is absent.
+    default: Option<Expr>,
+    /// The `description` of the field, for generated documentation.
+    description: Option<LitStr>,
+    /// The `example`s of the field, for generated documentation.
+    examples: Vec<Expr>,
+}
+
+impl Attrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = Attrs::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    parsed.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("tag") {
+                    parsed.tag = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("content") {
+                    parsed.content = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("default") {
+                    parsed.default = Some(meta.value()?.parse::<Expr>()?);
+                } else if meta.path.is_ident("title") {
+                    parsed.title = Some(meta.value()?.parse::<LitStr>()?);
+                } else if meta.path.is_ident("description") {
+                    parsed.description = Some(meta.value()?.parse::<LitStr>()?);
+                } else if meta.path.is_ident("example") {
+                    parsed.examples.push(meta.value()?.parse::<Expr>()?);
+                } else if meta.path.is_ident("message") {
+                    let mut code = None;
+                    let mut text = None;
+                    meta.parse_nested_meta(|inner| {
+                        if inner.path.is_ident("code") {
+                            code = Some(inner.value()?.parse::<LitStr>()?);
+                        } else if inner.path.is_ident("text") {
+                            text = Some(inner.value()?.parse::<LitStr>()?);
+                        } else {
+                            return Err(inner.error("expected `code` or `text`"));
+                        }
+                        Ok(())
+                    })?;
+                    let (code, text) = match (code, text) {
+                        (Some(code), Some(text)) => (code, text),
+                        _ => return Err(meta.error("`message` requires both `code` and `text`")),
+                    };
+                    parsed.message = Some(quote! { .set_message(#code, #text) });
+                } else {
+                    let method = match meta.path.get_ident() {
+                        Some(ident) => schema_method(ident),
+                        None => return Err(meta.error("unsupported validate attribute")),
+                    };
+                    if meta.input.peek(syn::Token![=]) {
+                        let value = meta.value()?.parse::<Expr>()?;
+                        if method == "multiple_of" && !is_positive(&value) {
+                            return Err(syn::Error::new_spanned(value, "`multiple_of` must be a number greater than 0"));
+                        }
+                        let arg = schema_argument(value);
+                        parsed.calls.push(quote! { .#method(#arg) });
+                    } else {
+                        parsed.calls.push(quote! { .#method() });
+                    }
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(parsed)
+    }
+
+    /// Fails if schema options were given where there is no field schema to apply them to.
+    fn reject_calls(&self) -> syn::Result<()> {
+        if let Some(call) = self.calls.first().or(self.message.as_ref()) {
+            return Err(syn::Error::new_spanned(call, "validation options are only supported on fields"));
+        }
+        if let Some(default) = &self.default {
+            return Err(syn::Error::new_spanned(default, "`default` is only supported on fields"));
+        }
+        if let Some(description) = &self.description {
+            return Err(syn::Error::new_spanned(description, "`description` is only supported on fields"));
+        }
+        if let Some(example) = self.examples.first() {
+            return Err(syn::Error::new_spanned(example, "`example` is only supported on fields"));
+        }
+        Ok(())
+    }
+
+    /// Appends the schema options to `schema`, and wraps it with the default.
+    fn apply(&self, schema: TokenStream2) -> TokenStream2 {
+        let calls = &self.calls;
+        let message = &self.message;
+        let schema = quote! { #schema #(#calls)* #message };
+        match &self.default {
+            Some(default) => quote! { schema_validator::schema::Schema::default(#schema, #default) },
+            None => schema,
+        }
+    }
+
+    /// Fails if a `title` was given on a variant, whose schema is not exported
+    /// on its own.
+    fn reject_title(&self) -> syn::Result<()> {
+        match &self.title {
+            Some(title) => Err(syn::Error::new_spanned(title, "`title` is only supported on types and fields")),
+            None => Ok(()),
+        }
+    }
+
+    /// Wraps `schema` with the title, description and examples, if any.
+    fn annotate(&self, schema: TokenStream2) -> TokenStream2 {
+        if self.title.is_none() && self.description.is_none() && self.examples.is_empty() {
+            return schema;
+        }
+        let title = self.title.iter();
+        let description = self.description.iter();
+        let examples = &self.examples;
+        quote! {
+            schema_validator::schema::annotated::AnnotatedSchema::new(#schema)
+                #( .title(#title) )*
+                #( .describe(#description) )*
+                #( .example(schema_validator::__private::serde_json::json!(#examples)) )*
+        }
+    }
+}
+
+/// Maps an attribute name to the schema method it calls.
+fn schema_method(ident: &Ident) -> Ident {
+    match ident.to_string().as_str() {
+        "lowercase" => Ident::new("to_lowercase", ident.span()),
+        "uppercase" => Ident::new("to_uppercase", ident.span()),
+        _ => ident.clone(),
+    }
+}
+
+/// Converts an attribute value into a method argument. Numbers are cast to
+/// the parameter type, so `min = 0` works for both `f64` and integer schemas.
+fn schema_argument(expr: Expr) -> TokenStream2 {
+    match &expr {
+        Expr::Lit(ExprLit { lit: Lit::Str(_) | Lit::Bool(_), .. }) => expr.into_token_stream(),
+        _ => quote! { (#expr) as _ },
+    }
+}
+
+/// Returns `false` if `expr` is a number literal that is zero or negative.
+/// Other expressions are checked by the schema when it is built.
+fn is_positive(expr: &Expr) -> bool {
+    let (negative, lit) = match expr {
+        Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => match &**expr {
+            Expr::Lit(ExprLit { lit, .. }) => (true, lit),
+            _ => return true,
+        },
+        Expr::Lit(ExprLit { lit, .. }) => (false, lit),
+        _ => return true,
     };
+    let value = match lit {
+        Lit::Int(lit) => lit.base10_parse::<f64>(),
+        Lit::Float(lit) => lit.base10_parse::<f64>(),
+        _ => return true,
+    };
+    match value {
+        Ok(value) => !negative && value > 0.0,
+        Err(_) => true,
+    }
+}
+
+/// Returns the key a variant or field is matched by: its `rename` or its name.
+fn key(ident: &Ident, attrs: &Attrs) -> String {
+    attrs.rename.clone().unwrap_or_else(|| ident.to_string())
+}
+
+/// Returns `T` if `ty` is written as `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
 
-    TokenStream::from(gen)
-}
\ No newline at end of file
+/// Builds the schema of a field from its type and options. The options of an
+/// `Option<T>` field apply to the schema of `T`, so an absent field takes the
+/// default while `null` is `None`; the description and examples apply to the
+/// field.
+fn field_schema(ty: &Type, attrs: &Attrs) -> TokenStream2 {
+    let schema = match option_inner(ty) {
+        Some(inner) if !attrs.calls.is_empty() || attrs.message.is_some() || attrs.default.is_some() => {
+            let schema = attrs.apply(quote! {
+                <#inner as schema_validator::schema::mapping::Validate>::schema()
+            });
+            quote! { schema_validator::schema::Schema::optional(#schema) }
+        }
+        _ => attrs.apply(quote! { <#ty as schema_validator::schema::mapping::Validate>::schema() }),
+    };
+    attrs.annotate(schema)
+}
+
+/// Returns the object schema validating `fields` and the expression building
+/// `path { ... }` from the validated `fields` map.
+fn named_fields(path: TokenStream2, fields: &FieldsNamed) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let mut keys = Vec::new();
+    let mut field_names = Vec::new();
+    let mut schemas = Vec::new();
+    for field in &fields.named {
+        let field_name = field.ident.as_ref().unwrap();
+        let attrs = Attrs::parse(&field.attrs)?;
+        keys.push(key(field_name, &attrs));
+        field_names.push(field_name);
+        schemas.push(field_schema(&field.ty, &attrs));
+    }
+
+    let schema = quote! {
+        schema_validator::schema::object::ObjectSchema::new()
+            #( .field(#keys, #schemas) )*
+    };
+    let construct = quote! {
+        #path {
+            #( #field_names: schema_validator::schema::mapping::field(&fields, #keys)?, )*
+        }
+    };
+    Ok((schema, construct))
+}
+
+/// Returns the tuple schema validating `fields` by position and the expression
+/// building `path(...)` from the validated `items`.
+fn unnamed_fields(path: TokenStream2, fields: &FieldsUnnamed) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let mut schemas = Vec::new();
+    for field in &fields.unnamed {
+        schemas.push(field_schema(&field.ty, &Attrs::parse(&field.attrs)?));
+    }
+    let indices = 0..fields.unnamed.len();
+
+    let schema = quote! {
+        schema_validator::schema().tuple(vec![
+            #( schema_validator::schema::Schema::boxed(#schemas), )*
+        ])
+    };
+    let construct = quote! {
+        #path(
+            #( schema_validator::schema::mapping::element(&items, #indices)?, )*
+        )
+    };
+    Ok((schema, construct))
+}
+
+/// Implements `FromFields`, `FromAny` and `Validate` for a struct with named fields.
+fn derive_struct(target: &Target, fields: &FieldsNamed) -> syn::Result<TokenStream2> {
+    let (schema, construct) = named_fields(quote! { Self }, fields)?;
+    let from_fields = target.impl_for(quote! { schema_validator::schema::mapping::FromFields });
+    let from_any = target.impl_for(quote! { schema_validator::schema::mapping::FromAny });
+    let validate = target.impl_validate(
+        quote! { schema_validator::schema::object::TransformedObjectSchema<Self> },
+        quote! {
+            #schema.try_transform(|fields| {
+                <Self as schema_validator::schema::mapping::FromFields>::from_fields(&fields)
+            })
+        },
+    );
+
+    Ok(quote! {
+        #from_fields {
+            #[allow(clippy::result_large_err)]
+            fn from_fields(
+                fields: &std::collections::HashMap<String, Box<dyn std::any::Any>>,
+            ) -> schema_validator::ValidationResult<Self> {
+                Ok(#construct)
+            }
+        }
+
+        #from_any {
+            #[allow(clippy::result_large_err)]
+            fn from_any(value: &dyn std::any::Any) -> schema_validator::ValidationResult<Self> {
+                if let Some(value) = value.downcast_ref::<Self>() {
+                    return Ok(value.clone());
+                }
+                match value.downcast_ref::<std::collections::HashMap<String, Box<dyn std::any::Any>>>() {
+                    Some(fields) => <Self as schema_validator::schema::mapping::FromFields>::from_fields(fields),
+                    None => Err(schema_validator::schema::mapping::type_mismatch::<Self>(value)),
+                }
+            }
+        }
+
+        #validate
+    })
+}
+
+/// Implements `FromAny` and `Validate` for a struct with a single unnamed
+/// field, which is validated like the value it wraps.
+fn derive_newtype_struct(target: &Target, fields: &FieldsUnnamed) -> syn::Result<TokenStream2> {
+    let field = &fields.unnamed[0];
+    let ty = &field.ty;
+    let schema = field_schema(ty, &Attrs::parse(&field.attrs)?);
+    let from_any = target.impl_for(quote! { schema_validator::schema::mapping::FromAny });
+    let validate = target.impl_validate(
+        quote! { schema_validator::schema::tuple::NewtypeSchema<Self> },
+        quote! { schema_validator::schema::tuple::NewtypeSchema::new(#schema, |value| Self(value)) },
+    );
+
+    Ok(quote! {
+        #from_any {
+            #[allow(clippy::result_large_err)]
+            fn from_any(value: &dyn std::any::Any) -> schema_validator::ValidationResult<Self> {
+                if let Some(value) = value.downcast_ref::<Self>() {
+                    return Ok(value.clone());
+                }
+                <#ty as schema_validator::schema::mapping::FromAny>::from_any(value).map(|value| Self(value))
+            }
+        }
+
+        #validate
+    })
+}
+
+/// Implements `FromAny` and `Validate` for a struct with several unnamed
+/// fields, which is validated as a positional array.
+fn derive_tuple_struct(target: &Target, fields: &FieldsUnnamed) -> syn::Result<TokenStream2> {
+    let (schema, construct) = unnamed_fields(quote! { Self }, fields)?;
+    let from_any = target.impl_for(quote! { schema_validator::schema::mapping::FromAny });
+    let validate = target.impl_validate(
+        quote! { schema_validator::schema::tuple::TransformedTupleSchema<Self> },
+        quote! { #schema.try_transform(|items| Ok(#construct)) },
+    );
+
+    Ok(quote! {
+        #from_any {
+            #[allow(clippy::result_large_err)]
+            fn from_any(value: &dyn std::any::Any) -> schema_validator::ValidationResult<Self> {
+                if let Some(value) = value.downcast_ref::<Self>() {
+                    return Ok(value.clone());
+                }
+                match value.downcast_ref::<Vec<Box<dyn std::any::Any>>>() {
+                    Some(items) => Ok(#construct),
+                    None => Err(schema_validator::schema::mapping::type_mismatch::<Self>(value)),
+                }
+            }
+        }
+
+        #validate
+    })
+}
+
+/// Implements `EnumValue`, `UnitEnum` and `Validate` for an enum with unit
+/// variants only, matching each variant by its label.
+fn derive_unit_enum(target: &Target, data: &DataEnum) -> syn::Result<TokenStream2> {
+    let mut variants = Vec::new();
+    let mut labels = Vec::new();
+    for variant in &data.variants {
+        let attrs = Attrs::parse(&variant.attrs)?;
+        attrs.reject_calls()?;
+        attrs.reject_title()?;
+        variants.push(&variant.ident);
+        labels.push(key(&variant.ident, &attrs));
+    }
+    let enum_value = target.impl_for(quote! { schema_validator::schema::enumeration::EnumValue });
+    let unit_enum = target.impl_for(quote! { schema_validator::schema::enumeration::UnitEnum });
+    let validate = target.impl_validate(
+        quote! { schema_validator::schema::enumeration::EnumSchema<Self> },
+        quote! { schema_validator::schema().native_enum::<Self>() },
+    );
+
+    Ok(quote! {
+        #enum_value {
+            fn to_json(&self) -> schema_validator::__private::serde_json::Value {
+                match self {
+                    #( Self::#variants => schema_validator::__private::serde_json::Value::String(#labels.to_string()), )*
+                }
+            }
+        }
+
+        #unit_enum {
+            fn variants() -> Vec<Self> {
+                vec![#( Self::#variants ),*]
+            }
+        }
+
+        #validate
+    })
+}
+
+/// Implements `Validate` for an enum with data-carrying variants using a
+/// `TaggedEnumSchema` in the representation selected by the enum's attributes.
+fn derive_tagged_enum(target: &Target, data: &DataEnum, attrs: &Attrs) -> syn::Result<TokenStream2> {
+    let tagging = match (&attrs.tag, &attrs.content) {
+        (None, None) => quote! { schema_validator::schema::tagged::Tagging::External },
+        (Some(tag), None) => quote! {
+            schema_validator::schema::tagged::Tagging::Internal { tag: #tag.to_string() }
+        },
+        (Some(tag), Some(content)) => quote! {
+            schema_validator::schema::tagged::Tagging::Adjacent {
+                tag: #tag.to_string(),
+                content: #content.to_string(),
+            }
+        },
+        (None, Some(_)) => return Err(syn::Error::new(target.name.span(), "`content` requires `tag`")),
+    };
+
+    let internal = attrs.tag.is_some() && attrs.content.is_none();
+    let mut registrations = Vec::new();
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let attrs = Attrs::parse(&variant.attrs)?;
+        attrs.reject_calls()?;
+        attrs.reject_title()?;
+        let label = key(ident, &attrs);
+
+        registrations.push(match &variant.fields {
+            Fields::Unit => quote! { .unit(#label, Self::#ident) },
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let field = &fields.unnamed[0];
+                let schema = field_schema(&field.ty, &Attrs::parse(&field.attrs)?);
+                quote! {
+                    .variant(#label, #schema, |value| Ok(Self::#ident(value)))
+                }
+            }
+            Fields::Unnamed(_) if internal => {
+                return Err(syn::Error::new(ident.span(), "`tag` cannot be used with tuple variants"));
+            }
+            Fields::Unnamed(fields) => {
+                let (schema, construct) = unnamed_fields(quote! { Self::#ident }, fields)?;
+                quote! {
+                    .variant(#label, #schema, |items| Ok(#construct))
+                }
+            }
+            Fields::Named(fields) => {
+                let (schema, construct) = named_fields(quote! { Self::#ident }, fields)?;
+                quote! {
+                    .variant(#label, #schema, |fields| Ok(#construct))
+                }
+            }
+        });
+    }
+    Ok(target.impl_validate(
+        quote! { schema_validator::schema::tagged::TaggedEnumSchema<Self> },
+        quote! {
+            schema_validator::schema::tagged::TaggedEnumSchema::new(#tagging)
+                #( #registrations )*
+        },
+    ))
+}
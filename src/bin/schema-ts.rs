@@ -0,0 +1,83 @@
+//! Writes TypeScript declarations for JSON Schema documents.
+//!
+//! ```text
+//! schema-ts [--out-dir <dir>] <schema.json>...
+//! ```
+//!
+//! Every input `name.json` is written to `name.d.ts`, next to the input or in
+//! `--out-dir`. The declared type is named after the document's `title`, or
+//! after the file name when it has none. Export the documents with
+//! `schema_validator::schema::json_schema::document`.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::{env, fs};
+use schema_validator::schema::typescript;
+use serde_json::Value;
+
+const USAGE: &str = "Usage: schema-ts [--out-dir <dir>] <schema.json>...";
+
+fn main() -> ExitCode {
+    let mut out_dir = None;
+    let mut inputs = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--out-dir" => match args.next() {
+                Some(dir) => out_dir = Some(PathBuf::from(dir)),
+                None => return usage_error("missing directory after --out-dir"),
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ if arg.starts_with('-') => return usage_error(&format!("unknown option '{}'", arg)),
+            _ => inputs.push(PathBuf::from(arg)),
+        }
+    }
+    if inputs.is_empty() {
+        return usage_error("no input files");
+    }
+
+    for input in &inputs {
+        match write_declarations(input, out_dir.as_deref()) {
+            Ok(output) => println!("{}", output.display()),
+            Err(message) => {
+                eprintln!("schema-ts: {}: {}", input.display(), message);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn write_declarations(input: &Path, out_dir: Option<&Path>) -> Result<PathBuf, String> {
+    let source = fs::read_to_string(input).map_err(|err| err.to_string())?;
+    let document: Value = serde_json::from_str(&source).map_err(|err| err.to_string())?;
+
+    let stem = input.file_stem().and_then(|stem| stem.to_str()).unwrap_or("schema");
+    let name = match document.get("title").and_then(Value::as_str) {
+        Some(title) => typescript::type_name(title),
+        None => typescript::type_name(stem),
+    };
+    let file_name = input.file_name().and_then(|name| name.to_str()).unwrap_or("schema");
+
+    let declarations = format!(
+        "// Generated by schema-ts from {}. Do not edit.\n\n{}",
+        file_name,
+        typescript::declarations_from_json_schema(&name, &document),
+    );
+
+    let dir = match out_dir {
+        Some(dir) => dir,
+        None => input.parent().unwrap_or(Path::new("")),
+    };
+    let output = dir.join(format!("{}.d.ts", stem));
+    fs::write(&output, declarations).map_err(|err| err.to_string())?;
+    Ok(output)
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("schema-ts: {}\n{}", message, USAGE);
+    ExitCode::from(2)
+}
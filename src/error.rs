@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+use std::fmt;
+use serde_json::Value;
+
 /// Configuration for error messages.
 ///
 /// # Examples
@@ -28,10 +32,76 @@ pub enum ErrorType {
     Coercion { from: &'static str, to: &'static str },
     Missing { field: String },
     Object { errors: Vec<(String, ValidationError)> },
+    MinItems { min: usize, got: usize },
+    MaxItems { max: usize, got: usize },
+    Array { errors: Vec<(usize, ValidationError)> },
+    Multiple { errors: Vec<ValidationError> },
+    TooSmall { min: f64, inclusive: bool, got: f64 },
+    TooLarge { max: f64, inclusive: bool, got: f64 },
+    NotMultipleOf { step: f64, got: f64 },
+    NotInteger { got: f64 },
+    NotFinite { got: f64 },
+    Overflow { target: &'static str, got: String },
+    Union { errors: Vec<ValidationError> },
+    InvalidDiscriminator { field: String, expected: Vec<String>, got: String },
+    Enum { expected: Vec<String>, got: String },
+    FieldType { field: String, expected: &'static str, got: &'static str },
+    /// A failed domain rule, such as a refinement; `code` identifies the rule
+    /// and `params` carries the values the message was built from.
+    Custom { code: String, params: HashMap<String, Value> },
+}
+
+/// A single step in the location of a value: an object key or an array index.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::error::PathSegment;
+///
+/// assert_eq!(PathSegment::Key("email".to_string()).to_string(), "email");
+/// assert_eq!(PathSegment::Index(2).to_string(), "2");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Key(key) => write!(f, "{}", key),
+            PathSegment::Index(index) => write!(f, "{}", index),
+        }
+    }
+}
+
+impl From<&str> for PathSegment {
+    fn from(key: &str) -> Self {
+        PathSegment::Key(key.to_string())
+    }
+}
+
+impl From<String> for PathSegment {
+    fn from(key: String) -> Self {
+        PathSegment::Key(key)
+    }
+}
+
+impl From<usize> for PathSegment {
+    fn from(index: usize) -> Self {
+        PathSegment::Index(index)
+    }
 }
 
 /// A validation error with a code and message.
 ///
+/// Every error carries the `path` of the value that failed, relative to the
+/// value passed to `validate`. Errors produced by objects and arrays aggregate
+/// the errors of their fields and elements, and schemas configured with
+/// `abort_early(false)` aggregate every failing constraint; use [`ValidationError::flatten`]
+/// to get at the individual failures.
+///
 /// # Examples
 ///
 /// ```
@@ -47,77 +117,373 @@ pub enum ErrorType {
 ///
 /// assert_eq!(err.code, "TYPE_ERROR");
 /// assert_eq!(err.message, "Type error: expected String, got Integer");
+/// assert!(err.path.is_empty());
 /// ```
 #[derive(Debug, Clone)]
 pub struct ValidationError {
     pub code: String,
     pub message: String,
     pub error_type: ErrorType,
+    pub path: Vec<PathSegment>,
 }
 
 impl ValidationError {
     pub fn new(error_type: ErrorType, config: Option<ErrorConfig>) -> Self {
-        if let Some(config) = config {
-            ValidationError {
-                code: config.code,
-                message: config.message,
-                error_type,
-            }
+        let (code, message) = if let Some(config) = config {
+            (config.code, config.message)
         } else {
-            match &error_type {
-                ErrorType::Type { expected, got } => ValidationError {
-                    code: "TYPE_ERROR".to_string(),
-                    message: format!("Type error: expected {}, got {}", expected, got),
-                    error_type,
-                },
-                ErrorType::Pattern { pattern, got } => ValidationError {
-                    code: "PATTERN_ERROR".to_string(),
-                    message: format!("Pattern error: '{}' does not match pattern '{}'", got, pattern),
-                    error_type,
-                },
-                ErrorType::MinLength { min, got } => ValidationError {
-                    code: "MIN_LENGTH_ERROR".to_string(),
-                    message: format!("Length error: expected at least {} characters, got {}", min, got),
-                    error_type,
-                },
-                ErrorType::MaxLength { max, got } => ValidationError {
-                    code: "MAX_LENGTH_ERROR".to_string(),
-                    message: format!("Length error: expected at most {} characters, got {}", max, got),
-                    error_type,
-                },
-                ErrorType::UnknownField { field } => ValidationError {
-                    code: "UNKNOWN_FIELD".to_string(),
-                    message: format!("Unknown field: '{}'", field),
-                    error_type,
-                },
-                ErrorType::MissingField { field } => ValidationError {
-                    code: "MISSING_FIELD".to_string(),
-                    message: format!("Missing required field: '{}'", field),
-                    error_type,
-                },
-                ErrorType::Literal { expected, got } => ValidationError {
-                    code: "LITERAL_ERROR".to_string(),
-                    message: format!("Literal error: expected {}, got {}", expected, got),
-                    error_type,
-                },
-                ErrorType::Coercion { from, to } => ValidationError {
-                    code: "COERCION_ERROR".to_string(),
-                    message: format!("Coercion error: cannot convert {} to {}", from, to),
-                    error_type,
-                },
-                ErrorType::Missing { field } => ValidationError {
-                    code: "MISSING_FIELD".to_string(),
-                    message: format!("Missing required field: '{}'", field),
-                    error_type,
-                },
-                ErrorType::Object { errors } => ValidationError {
-                    code: "OBJECT_ERROR".to_string(),
-                    message: format!("Object validation failed: {:?}", errors),
-                    error_type,
-                },
+            let (code, message) = match &error_type {
+                ErrorType::Type { expected, got } => (
+                    "TYPE_ERROR",
+                    format!("Type error: expected {}, got {}", expected, got),
+                ),
+                ErrorType::Pattern { pattern, got } => (
+                    "PATTERN_ERROR",
+                    format!("Pattern error: '{}' does not match pattern '{}'", got, pattern),
+                ),
+                ErrorType::MinLength { min, got } => (
+                    "MIN_LENGTH_ERROR",
+                    format!("Length error: expected at least {} characters, got {}", min, got),
+                ),
+                ErrorType::MaxLength { max, got } => (
+                    "MAX_LENGTH_ERROR",
+                    format!("Length error: expected at most {} characters, got {}", max, got),
+                ),
+                ErrorType::UnknownField { field } => (
+                    "UNKNOWN_FIELD",
+                    format!("Unknown field: '{}'", field),
+                ),
+                ErrorType::MissingField { field } => (
+                    "MISSING_FIELD",
+                    format!("Missing required field: '{}'", field),
+                ),
+                ErrorType::Literal { expected, got } => (
+                    "LITERAL_ERROR",
+                    format!("Literal error: expected {}, got {}", expected, got),
+                ),
+                ErrorType::Coercion { from, to } => (
+                    "COERCION_ERROR",
+                    format!("Coercion error: cannot convert {} to {}", from, to),
+                ),
+                ErrorType::Missing { field } => (
+                    "MISSING_FIELD",
+                    format!("Missing required field: '{}'", field),
+                ),
+                ErrorType::Object { errors } => (
+                    "OBJECT_ERROR",
+                    format!("Object validation failed: {}", describe(errors.iter().map(|(_, err)| err))),
+                ),
+                ErrorType::MinItems { min, got } => (
+                    "MIN_ITEMS_ERROR",
+                    format!("Length error: expected at least {} items, got {}", min, got),
+                ),
+                ErrorType::MaxItems { max, got } => (
+                    "MAX_ITEMS_ERROR",
+                    format!("Length error: expected at most {} items, got {}", max, got),
+                ),
+                ErrorType::Array { errors } => (
+                    "ARRAY_ERROR",
+                    format!("Array validation failed: {}", describe(errors.iter().map(|(_, err)| err))),
+                ),
+                ErrorType::Multiple { errors } => (
+                    "MULTIPLE_ERRORS",
+                    format!("Multiple validation errors: {}", describe(errors.iter())),
+                ),
+                ErrorType::TooSmall { min, inclusive, got } => (
+                    "TOO_SMALL",
+                    if *inclusive {
+                        format!("Range error: expected a number greater than or equal to {}, got {}", min, got)
+                    } else {
+                        format!("Range error: expected a number greater than {}, got {}", min, got)
+                    },
+                ),
+                ErrorType::TooLarge { max, inclusive, got } => (
+                    "TOO_LARGE",
+                    if *inclusive {
+                        format!("Range error: expected a number less than or equal to {}, got {}", max, got)
+                    } else {
+                        format!("Range error: expected a number less than {}, got {}", max, got)
+                    },
+                ),
+                ErrorType::NotMultipleOf { step, got } => (
+                    "NOT_MULTIPLE_OF",
+                    format!("Number error: expected a multiple of {}, got {}", step, got),
+                ),
+                ErrorType::NotInteger { got } => (
+                    "NOT_INTEGER",
+                    format!("Number error: expected an integer, got {}", got),
+                ),
+                ErrorType::NotFinite { got } => (
+                    "NOT_FINITE",
+                    format!("Number error: expected a finite number, got {}", got),
+                ),
+                ErrorType::Union { errors } => (
+                    "UNION_ERROR",
+                    format!(
+                        "Union validation failed: no variant matched: {}",
+                        errors.iter()
+                            .enumerate()
+                            .map(|(index, err)| format!("variant {}: {}", index, describe(std::iter::once(err))))
+                            .collect::<Vec<_>>()
+                            .join(" | ")
+                    ),
+                ),
+                ErrorType::InvalidDiscriminator { field, expected, got } => (
+                    "INVALID_DISCRIMINATOR",
+                    format!(
+                        "Invalid discriminator: expected '{}' to be one of {}, got '{}'",
+                        field,
+                        expected.iter().map(|tag| format!("'{}'", tag)).collect::<Vec<_>>().join(", "),
+                        got
+                    ),
+                ),
+                ErrorType::Enum { expected, got } => (
+                    "ENUM_ERROR",
+                    format!(
+                        "Enum error: expected one of {}, got '{}'",
+                        expected.iter().map(|option| format!("'{}'", option)).collect::<Vec<_>>().join(", "),
+                        got
+                    ),
+                ),
+                ErrorType::Overflow { target, got } => (
+                    "INTEGER_OVERFLOW",
+                    format!("Range error: {} is out of range for {}", got, target),
+                ),
+                ErrorType::FieldType { field, expected, got } => (
+                    "FIELD_TYPE_ERROR",
+                    format!("Field type error: expected '{}' to be {}, got {}", field, expected, got),
+                ),
+                ErrorType::Custom { code, .. } => (
+                    code.as_str(),
+                    format!("Validation failed: {}", code),
+                ),
+            };
+            (code.to_string(), message)
+        };
+
+        ValidationError {
+            code,
+            message,
+            error_type,
+            path: Vec::new(),
+        }
+    }
+
+    /// Creates an `ErrorType::Custom` error with the given code and message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::error::{ValidationError, ErrorType};
+    ///
+    /// let err = ValidationError::custom("INVALID_SKU", "SKU checksum does not match")
+    ///     .with_param("expected", 7);
+    ///
+    /// assert_eq!(err.code, "INVALID_SKU");
+    /// assert_eq!(err.message, "SKU checksum does not match");
+    /// match err.error_type {
+    ///     ErrorType::Custom { code, params } => {
+    ///         assert_eq!(code, "INVALID_SKU");
+    ///         assert_eq!(params["expected"], 7);
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn custom<C, M>(code: C, message: M) -> Self
+    where
+        C: Into<String>,
+        M: Into<String>,
+    {
+        let code = code.into();
+        ValidationError::new(
+            ErrorType::Custom { code: code.clone(), params: HashMap::new() },
+            Some(ErrorConfig { code, message: message.into() }),
+        )
+    }
+
+    /// Adds a parameter to an `ErrorType::Custom` error. Other errors are
+    /// returned unchanged.
+    pub fn with_param<V: Into<Value>>(mut self, name: &str, value: V) -> Self {
+        if let ErrorType::Custom { params, .. } = &mut self.error_type {
+            params.insert(name.to_string(), value.into());
+        }
+        self
+    }
+
+    /// Combines the failures of several constraints on the same value.
+    ///
+    /// Returns `None` when there are no failures, the failure itself when there
+    /// is exactly one and an `ErrorType::Multiple` error otherwise.
+    pub(crate) fn from_errors(mut errors: Vec<ValidationError>, config: Option<ErrorConfig>) -> Option<Self> {
+        match errors.len() {
+            0 => None,
+            1 => errors.pop(),
+            _ => Some(ValidationError::new(ErrorType::Multiple { errors }, config)),
+        }
+    }
+
+    /// Prepends `segment` to the path of this error and of every error it aggregates.
+    ///
+    /// Schemas that validate nested values use this to record where a nested
+    /// failure happened.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::error::{ValidationError, ErrorType, PathSegment};
+    ///
+    /// let err = ValidationError::new(ErrorType::Missing { field: "email".to_string() }, None)
+    ///     .with_path_prefix("email")
+    ///     .with_path_prefix(2)
+    ///     .with_path_prefix("users");
+    ///
+    /// assert_eq!(err.path, vec![
+    ///     PathSegment::Key("users".to_string()),
+    ///     PathSegment::Index(2),
+    ///     PathSegment::Key("email".to_string()),
+    /// ]);
+    /// ```
+    pub fn with_path_prefix<P: Into<PathSegment>>(mut self, segment: P) -> Self {
+        self.prefix_path(&segment.into());
+        self
+    }
+
+    fn prefix_path(&mut self, segment: &PathSegment) {
+        self.path.insert(0, segment.clone());
+        for err in self.children_mut() {
+            err.prefix_path(segment);
+        }
+    }
+
+    // The failures of the branches of a union are alternatives rather than
+    // separate problems, so a union error is reported as a single leaf.
+    fn children(&self) -> Vec<&ValidationError> {
+        match &self.error_type {
+            ErrorType::Object { errors } => errors.iter().map(|(_, err)| err).collect(),
+            ErrorType::Array { errors } => errors.iter().map(|(_, err)| err).collect(),
+            ErrorType::Multiple { errors } => errors.iter().collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut ValidationError> {
+        match &mut self.error_type {
+            ErrorType::Object { errors } => errors.iter_mut().map(|(_, err)| err).collect(),
+            ErrorType::Array { errors } => errors.iter_mut().map(|(_, err)| err).collect(),
+            ErrorType::Multiple { errors } => errors.iter_mut().collect(),
+            ErrorType::Union { errors } => errors.iter_mut().collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns every leaf error together with its path.
+    ///
+    /// Errors aggregated by objects, arrays and schemas that collect several
+    /// failures are expanded recursively, so the
+    /// result only contains the failures of individual values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.object()
+    ///     .field("users", s.array(s.object().field("email", s.string().email())));
+    ///
+    /// let err = schema.validate(&json!({
+    ///     "users": [{ "email": "a@example.com" }, { "email": "b@example.com" }, { "email": "nope" }]
+    /// })).unwrap_err();
+    ///
+    /// let leaves = err.flatten();
+    /// assert_eq!(leaves.len(), 1);
+    /// assert_eq!(leaves[0].1.code, "INVALID_EMAIL");
+    /// assert_eq!(leaves[0].1.dotted_path(), "users[2].email");
+    /// assert_eq!(leaves[0].1.json_pointer(), "/users/2/email");
+    /// ```
+    pub fn flatten(&self) -> Vec<(&[PathSegment], &ValidationError)> {
+        let children = self.children();
+        if children.is_empty() {
+            return vec![(self.path.as_slice(), self)];
+        }
+        children.into_iter().flat_map(|err| err.flatten()).collect()
+    }
+
+    /// Renders the path as dotted keys with bracketed indices, e.g. `users[2].email`.
+    pub fn dotted_path(&self) -> String {
+        dotted_path(&self.path)
+    }
+
+    /// Renders the path as a JSON Pointer (RFC 6901), e.g. `/users/2/email`.
+    pub fn json_pointer(&self) -> String {
+        json_pointer(&self.path)
+    }
+}
+
+/// Lists every failure with its dotted path and code, e.g.
+/// `users[2].email: [INVALID_EMAIL] Invalid email format`.
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", describe(std::iter::once(self)))
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Renders a path as dotted keys with bracketed indices, e.g. `users[2].email`.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::error::{dotted_path, PathSegment};
+///
+/// let path = vec![PathSegment::from("users"), PathSegment::from(2), PathSegment::from("email")];
+/// assert_eq!(dotted_path(&path), "users[2].email");
+/// ```
+pub fn dotted_path(path: &[PathSegment]) -> String {
+    let mut rendered = String::new();
+    for segment in path {
+        match segment {
+            PathSegment::Key(key) => {
+                if !rendered.is_empty() {
+                    rendered.push('.');
+                }
+                rendered.push_str(key);
+            }
+            PathSegment::Index(index) => {
+                rendered.push_str(&format!("[{}]", index));
             }
         }
     }
+    rendered
+}
+
+/// Renders a path as a JSON Pointer (RFC 6901), e.g. `/users/2/email`.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::error::{json_pointer, PathSegment};
+///
+/// let path = vec![PathSegment::from("a/b"), PathSegment::from(0)];
+/// assert_eq!(json_pointer(&path), "/a~1b/0");
+/// ```
+pub fn json_pointer(path: &[PathSegment]) -> String {
+    path.iter()
+        .map(|segment| format!("/{}", segment.to_string().replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+fn describe<'a>(errors: impl Iterator<Item = &'a ValidationError>) -> String {
+    errors
+        .flat_map(|err| err.flatten())
+        .map(|(path, err)| match dotted_path(path) {
+            path if path.is_empty() => format!("[{}] {}", err.code, err.message),
+            path => format!("{}: [{}] {}", path, err.code, err.message),
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
-pub type ValidationResult<T> = Result<T, ValidationError>;
\ No newline at end of file
+pub type ValidationResult<T> = Result<T, ValidationError>;
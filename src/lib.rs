@@ -8,8 +8,13 @@
 //! - **Type Coercion**: Automatic conversion between compatible types
 //! - **Object Validation**: Validate complex objects with multiple fields
 //! - **Error Handling**: Detailed error messages with customizable codes
-//! - **JSON Support**: Direct validation of JSON values
+//! - **Custom Rules**: Attach domain rules to schemas, reported like built-in errors
+//! - **JSON Support**: Direct validation of any JSON document, including scalars and arrays at the root
 //! - **Derive Macro**: Automatically implement validation traits
+//! - **JSON Schema**: Export schemas as JSON Schema (draft 2020-12) documents and load schemas from them
+//! - **TypeScript Declarations**: Generate TypeScript types from schemas and derived structs
+//! - **OpenAPI**: Build OpenAPI 3.1 documents with reusable component schemas, as JSON or YAML
+//! - **Serde Integration**: Validate while deserializing with the optional `serde` feature
 //!
 //! # Quick Start
 //!
@@ -175,6 +180,56 @@
 //! assert!(schema.validate(&"hello123".to_string()).is_ok());
 //! ```
 //!
+//! # Number Validation
+//!
+//! `number()` accepts `f64` values and integers of any Rust type, such as `i64` or `u8`,
+//! without `coerce()`, since JSON integers are read as integers. Strings and booleans are
+//! only converted with `coerce()`.
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema};
+//!
+//! let s = schema();
+//!
+//! // Range constraints
+//! let schema = s.number().min(0.0).max(150.0);
+//! assert!(schema.validate(&42.0).is_ok());
+//! assert_eq!(schema.validate(&-1.0).unwrap_err().code, "TOO_SMALL");
+//! assert_eq!(schema.validate(&42_i64).unwrap(), 42.0);
+//!
+//! // Exclusive bounds, steps and integers
+//! let schema = s.number().positive().multiple_of(0.5);
+//! assert!(schema.validate(&2.5).is_ok());
+//! assert_eq!(schema.validate(&2.2).unwrap_err().code, "NOT_MULTIPLE_OF");
+//!
+//! let schema = s.number().int().finite();
+//! assert_eq!(schema.validate(&1.5).unwrap_err().code, "NOT_INTEGER");
+//! assert_eq!(schema.validate(&f64::NAN).unwrap_err().code, "NOT_FINITE");
+//! ```
+//!
+//! ## Integers
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema};
+//! use serde_json::json;
+//!
+//! let s = schema();
+//!
+//! // The output is the exact integer type, JSON integers keep their precision
+//! let schema = s.integer();
+//! assert_eq!(schema.validate(&json!(9007199254740993_i64)).unwrap(), 9007199254740993_i64);
+//! assert_eq!(schema.validate(&1.5).unwrap_err().code, "NOT_INTEGER");
+//!
+//! // Sized variants reject values that do not fit
+//! let schema = s.u8();
+//! assert_eq!(schema.validate(&200_i64).unwrap(), 200_u8);
+//! assert_eq!(schema.validate(&300_i64).unwrap_err().code, "INTEGER_OVERFLOW");
+//!
+//! // Strings are parsed when coercion is enabled
+//! let schema = s.coerce().i16().min(-10);
+//! assert_eq!(schema.validate(&"-5".to_string()).unwrap(), -5_i16);
+//! ```
+//!
 //! # Object Validation
 //!
 //! ```rust
@@ -222,6 +277,620 @@
 //! assert_eq!(user.age, Some(30.0));
 //! ```
 //!
+//! An optional field accepts `null`, a missing key, the bare value, or an `Option` of an
+//! input type such as `Some(30.0)`, `Option<String>` or `Option<Value>`, and runs the inner
+//! schema, transforms included, on the value inside. An `Option` of anything else, such as
+//! an `Option<usize>` already produced by a transform, is not unwrapped and fails like any
+//! other value of the wrong type.
+//!
+//! ## Nested Objects
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema};
+//! use std::collections::HashMap;
+//! use std::any::Any;
+//! use serde_json::json;
+//!
+//! let s = schema();
+//!
+//! let schema = s.object()
+//!     .field("name", s.string())
+//!     .field("address", s.object()
+//!         .field("city", s.string())
+//!         .field("geo", s.object()
+//!             .field("lat", s.number())
+//!             .field("lng", s.number())));
+//!
+//! let user = schema.validate(&json!({
+//!     "name": "John",
+//!     "address": { "city": "Berlin", "geo": { "lat": 52.52, "lng": 13.40 } }
+//! })).unwrap();
+//!
+//! // Nested objects are available as validated maps
+//! let address = user.get("address").unwrap()
+//!     .downcast_ref::<HashMap<String, Box<dyn Any>>>()
+//!     .unwrap();
+//! assert_eq!(address.get("city").unwrap().downcast_ref::<String>().unwrap(), "Berlin");
+//! ```
+//!
+//! ## Unknown Keys
+//!
+//! Keys that are not declared as fields are dropped from the output by default
+//! (`strip`). `strict` rejects them with one `UNKNOWN_FIELD` error per key, reported
+//! together with the errors of the fields, `passthrough` keeps them untouched, and
+//! `catchall` validates them with a schema.
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema};
+//! use serde_json::json;
+//!
+//! let s = schema();
+//! let input = json!({ "name": "Ann", "admin": true });
+//!
+//! let strict = s.object().field("name", s.string()).strict();
+//! let err = strict.validate(&input).unwrap_err();
+//! assert_eq!(err.flatten()[0].1.code, "UNKNOWN_FIELD");
+//! assert_eq!(err.flatten()[0].1.dotted_path(), "admin");
+//!
+//! let passthrough = s.object().field("name", s.string()).passthrough();
+//! assert_eq!(passthrough.validate_to_json(&input).unwrap(), json!({ "name": "Ann", "admin": true }));
+//!
+//! let catchall = s.object().field("name", s.string()).catchall(s.string());
+//! assert!(catchall.validate(&input).is_err());
+//! ```
+//!
+//! ## Default Values
+//!
+//! `default` substitutes a value for a field that is absent from the input, and
+//! `default_with` calls a function for it every time. The default is a JSON value that is
+//! validated and transformed like any input. `on_null` also replaces `null`. Fields with
+//! a default are not `required` in the exported JSON Schema, which carries the `default`
+//! keyword, and derived structs take `#[validate(default = ...)]`.
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema, Validate};
+//! use serde_json::json;
+//!
+//! let s = schema();
+//! let schema = s.object()
+//!     .field("query", s.string())
+//!     .field("page", s.integer().min(1).default(1).on_null())
+//!     .field("sort", s.enumeration(["asc", "desc"]).default("asc"));
+//!
+//! let cleaned = schema.validate_to_json(&json!({ "query": "rust", "page": null })).unwrap();
+//! assert_eq!(cleaned, json!({ "query": "rust", "page": 1, "sort": "asc" }));
+//!
+//! #[derive(Debug, Clone, PartialEq, Validate)]
+//! struct Settings {
+//!     #[validate(default = "light")]
+//!     theme: String,
+//!     #[validate(min = 1, default = 20)]
+//!     page_size: u32,
+//! }
+//!
+//! let settings = Settings::schema().validate(&json!({})).unwrap();
+//! assert_eq!(settings, Settings { theme: "light".to_string(), page_size: 20 });
+//! ```
+//!
+//! ## Cross-Field Checks
+//!
+//! `check` adds a rule over several fields. It runs after the fields are validated,
+//! with the fields that are valid, and returns every failure it finds. Failures are
+//! reported at the field named by their path, together with the field errors.
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema};
+//! use schema_validator::error::ValidationError;
+//! use serde_json::json;
+//!
+//! let s = schema();
+//! let schema = s.object()
+//!     .field("password", s.string().min_length(8))
+//!     .field("confirm_password", s.string())
+//!     .field("email", s.string().email())
+//!     .check(|fields| {
+//!         let password = fields.get("password").and_then(|value| value.downcast_ref::<String>());
+//!         let confirm = fields.get("confirm_password").and_then(|value| value.downcast_ref::<String>());
+//!         match (password, confirm) {
+//!             (Some(password), Some(confirm)) if password != confirm => vec![
+//!                 ValidationError::custom("PASSWORD_MISMATCH", "Passwords do not match")
+//!                     .with_path_prefix("confirm_password"),
+//!             ],
+//!             _ => Vec::new(),
+//!         }
+//!     });
+//!
+//! let err = schema.validate(&json!({
+//!     "password": "correct horse",
+//!     "confirm_password": "battery staple",
+//!     "email": "nope"
+//! })).unwrap_err();
+//! assert_eq!(
+//!     err.to_string(),
+//!     "confirm_password: [PASSWORD_MISMATCH] Passwords do not match; email: [INVALID_EMAIL] Invalid email format"
+//! );
+//! ```
+//!
+//! ## Records
+//!
+//! Maps with arbitrary keys are validated with `s.record(...)`, which applies one
+//! schema to every value. Nested objects, arrays, records and optional values
+//! produced by hand-built schemas convert into the matching fields of derived
+//! structs with `validate_as`. A value that does not fit its field fails with a
+//! `FIELD_TYPE_ERROR` naming the field, the expected Rust type and the produced one.
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema, ValidateAs, Validate};
+//! use std::collections::HashMap;
+//! use serde_json::json;
+//!
+//! #[derive(Debug, PartialEq, Clone, Validate)]
+//! struct Address {
+//!     city: String,
+//! }
+//!
+//! #[derive(Debug, PartialEq, Clone, Validate)]
+//! struct User {
+//!     name: String,
+//!     addresses: Vec<Address>,
+//!     scores: HashMap<String, i64>,
+//! }
+//!
+//! let s = schema();
+//!
+//! let schema = s.object()
+//!     .field("name", s.string())
+//!     .field("addresses", s.array(s.object().field("city", s.string())))
+//!     .field("scores", s.record(s.integer().min(0)));
+//!
+//! let user: User = schema.validate_as(&json!({
+//!     "name": "John",
+//!     "addresses": [{ "city": "Berlin" }],
+//!     "scores": { "math": 90 }
+//! })).unwrap();
+//! assert_eq!(user.addresses[0].city, "Berlin");
+//! assert_eq!(user.scores["math"], 90);
+//! ```
+//!
+//! ## Derived Schemas
+//!
+//! `#[derive(Validate)]` also generates the schema itself from `#[validate(...)]`
+//! attributes on the fields. Each option calls the schema method of the same name
+//! (`lowercase` and `uppercase` call `to_lowercase` and `to_uppercase`), and
+//! `message(code = "...", text = "...")` sets a custom error message. Options on an
+//! `Option<T>` field apply to the value when it is present. `default = ...` is used when
+//! the field is absent, and `title = "..."`, `description = "..."` and `example = ...`
+//! document the field in exported JSON Schema and OpenAPI documents. `title` can also be
+//! given on a struct or enum to title the schema of the type.
+//!
+//! ```rust
+//! use schema_validator::{Schema, Validate};
+//! use serde_json::json;
+//!
+//! #[derive(Debug, PartialEq, Clone, Validate)]
+//! struct User {
+//!     #[validate(trim, min_length = 2, max_length = 50)]
+//!     name: String,
+//!     #[validate(trim, lowercase, email)]
+//!     email: String,
+//!     #[validate(min = 0, max = 150)]
+//!     age: u8,
+//!     #[validate(pattern = r"^\+\d{7,15}$", message(code = "INVALID_PHONE", text = "Invalid phone number"))]
+//!     phone: Option<String>,
+//!     #[validate(rename = "isActive")]
+//!     is_active: bool,
+//! }
+//!
+//! let user = User::schema().validate(&json!({
+//!     "name": " John ",
+//!     "email": " User@Example.Com ",
+//!     "age": 30,
+//!     "phone": null,
+//!     "isActive": true
+//! })).unwrap();
+//! assert_eq!(user.email, "user@example.com");
+//! assert_eq!(user.phone, None);
+//!
+//! let err = User::schema().validate(&json!({
+//!     "name": "John",
+//!     "email": "john@example.com",
+//!     "age": 30,
+//!     "phone": "555",
+//!     "isActive": true
+//! })).unwrap_err();
+//! let leaves = err.flatten();
+//! assert_eq!(leaves[0].1.code, "INVALID_PHONE");
+//! assert_eq!(leaves[0].1.dotted_path(), "phone");
+//! ```
+//!
+//! Newtype structs are validated like the value they wrap, other tuple structs as
+//! fixed-length arrays, and generic structs get a schema for every instantiation
+//! whose type parameters implement `Validate`.
+//!
+//! ```rust
+//! use schema_validator::{Schema, Validate};
+//! use serde_json::json;
+//!
+//! #[derive(Debug, PartialEq, Clone, Validate)]
+//! struct Meters(#[validate(min = 0)] f64);
+//!
+//! #[derive(Debug, PartialEq, Clone, Validate)]
+//! struct Point(f64, f64);
+//!
+//! #[derive(Debug, PartialEq, Clone, Validate)]
+//! struct Page<T> {
+//!     items: Vec<T>,
+//!     #[validate(min = 1)]
+//!     page: u32,
+//! }
+//!
+//! assert_eq!(Meters::schema().validate(&12.5).unwrap(), Meters(12.5));
+//!
+//! let page = Page::<Point>::schema()
+//!     .validate(&json!({ "items": [[52.52, 13.40]], "page": 1 }))
+//!     .unwrap();
+//! assert_eq!(page.items, vec![Point(52.52, 13.40)]);
+//! ```
+//!
+//! Validation produces owned values, so types with lifetime parameters or
+//! borrowed fields cannot derive `Validate`; both are rejected at compile time:
+//!
+//! ```rust,compile_fail
+//! use schema_validator::Validate;
+//!
+//! #[derive(Debug, PartialEq, Clone, Validate)]
+//! struct Name<'a> {
+//!     first: &'a str,
+//! }
+//! ```
+//!
+//! ```rust,compile_fail
+//! use schema_validator::Validate;
+//!
+//! #[derive(Debug, PartialEq, Clone, Validate)]
+//! struct Name {
+//!     first: &'static str,
+//! }
+//! ```
+//!
+//! # Array Validation
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema};
+//! use serde_json::json;
+//!
+//! let s = schema();
+//!
+//! let schema = s.object()
+//!     .field("tags", s.array(s.string().min_length(2)).nonempty().max_items(5));
+//!
+//! assert!(schema.validate(&json!({ "tags": ["rust", "json"] })).is_ok());
+//!
+//! // Element errors are keyed by their index
+//! let err = s.array(s.string().min_length(2))
+//!     .validate(&json!(["ok", "x"]))
+//!     .unwrap_err();
+//! assert_eq!(err.code, "ARRAY_ERROR");
+//! ```
+//!
+//! # Enum Validation
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema, Validate};
+//!
+//! let s = schema();
+//!
+//! let schema = s.enumeration(["admin", "editor", "viewer"]).case_insensitive();
+//! assert_eq!(schema.validate(&"Editor".to_string()).unwrap(), "editor");
+//!
+//! let err = schema.validate(&"owner".to_string()).unwrap_err();
+//! assert_eq!(err.code, "ENUM_ERROR");
+//! assert_eq!(err.message, "Enum error: expected one of 'admin', 'editor', 'viewer', got 'owner'");
+//!
+//! // Map straight to a Rust enum
+//! #[derive(Debug, Clone, PartialEq, Validate)]
+//! enum Role {
+//!     Admin,
+//!     Editor,
+//!     Viewer,
+//! }
+//!
+//! let schema = s.native_enum::<Role>();
+//! assert_eq!(schema.validate(&"Admin".to_string()).unwrap(), Role::Admin);
+//! ```
+//!
+//! ## Data-Carrying Enums
+//!
+//! Enums with data use serde's representations:
+//! external tagging by default, `#[validate(tag = "...")]` for internal and
+//! `#[validate(tag = "...", content = "...")]` for adjacent tagging. With
+//! internal tagging, variants see the fields next to the tag, so newtype
+//! variants must hold a struct and tuple variants are not supported.
+//!
+//! ```rust
+//! use schema_validator::{Schema, Validate};
+//! use serde_json::json;
+//!
+//! #[derive(Debug, Clone, PartialEq, Validate)]
+//! #[validate(tag = "type")]
+//! enum Event {
+//!     #[validate(rename = "click")]
+//!     Click { x: i64, y: i64 },
+//!     #[validate(rename = "key")]
+//!     Key(KeyPress),
+//!     #[validate(rename = "blur")]
+//!     Blur,
+//! }
+//!
+//! #[derive(Debug, Clone, PartialEq, Validate)]
+//! struct KeyPress {
+//!     code: String,
+//! }
+//!
+//! let schema = Event::schema();
+//! assert_eq!(
+//!     schema.validate(&json!({ "type": "click", "x": 1, "y": 2 })).unwrap(),
+//!     Event::Click { x: 1, y: 2 },
+//! );
+//! assert_eq!(
+//!     schema.validate(&json!({ "type": "key", "code": "Enter" })).unwrap(),
+//!     Event::Key(KeyPress { code: "Enter".to_string() }),
+//! );
+//! assert_eq!(schema.validate(&json!({ "type": "blur" })).unwrap(), Event::Blur);
+//! ```
+//!
+//! # Union Validation
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema};
+//! use serde_json::json;
+//!
+//! let s = schema();
+//!
+//! // IDs may be strings or integers
+//! let schema = s.object()
+//!     .field("id", s.string().min_length(1).or(s.integer()));
+//!
+//! let result = schema.validate(&json!({ "id": 42 })).unwrap();
+//! let id = result.get("id").unwrap()
+//!     .downcast_ref::<schema_validator::schema::union::UnionValue>().unwrap();
+//! assert_eq!(id.index(), 1);
+//! assert_eq!(id.downcast_ref::<i64>(), Some(&42));
+//!
+//! // When nothing matches, the error lists the failure of every member
+//! let err = s.union(vec![s.string().boxed(), s.integer().boxed()])
+//!     .validate(&true)
+//!     .unwrap_err();
+//! assert_eq!(err.code, "UNION_ERROR");
+//! assert!(err.message.contains("variant 0: [TYPE_ERROR]"));
+//! ```
+//!
+//! ## Discriminated Unions
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema};
+//! use serde_json::json;
+//!
+//! let s = schema();
+//! let schema = s.discriminated_union("type")
+//!     .variant("click", s.object().field("x", s.integer()).field("y", s.integer()))
+//!     .variant("scroll", s.object().field("delta", s.number()));
+//!
+//! let event = schema.validate(&json!({ "type": "click", "x": 1, "y": 2 })).unwrap();
+//! assert_eq!(event.tag(), Some("click"));
+//!
+//! // Unknown tags are rejected without trying every variant
+//! let err = schema.validate(&json!({ "type": "hover" })).unwrap_err();
+//! assert_eq!(err.code, "INVALID_DISCRIMINATOR");
+//! assert_eq!(err.message, "Invalid discriminator: expected 'type' to be one of 'click', 'scroll', got 'hover'");
+//! ```
+//!
+//! # JSON Output
+//!
+//! `validate_to_json` returns the validated document as a `serde_json::Value`, with
+//! every transformation and coercion applied, so the validator can sit in front of
+//! other services as a sanitising gateway.
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema};
+//! use serde_json::json;
+//!
+//! let s = schema();
+//!
+//! let schema = s.object()
+//!     .field("email", s.string().trim().to_lowercase().email())
+//!     .field("age", s.coerce().integer())
+//!     .field("tags", s.array(s.string().trim()))
+//!     .field("nickname", s.string().optional());
+//!
+//! let cleaned = schema.validate_to_json(&json!({
+//!     "email": "  User@Example.COM ",
+//!     "age": "42",
+//!     "tags": [" rust "],
+//!     "nickname": null
+//! })).unwrap();
+//!
+//! assert_eq!(cleaned, json!({
+//!     "email": "user@example.com",
+//!     "age": 42,
+//!     "tags": ["rust"],
+//!     "nickname": null
+//! }));
+//! ```
+//!
+//! # JSON Schema
+//!
+//! `to_json_schema` describes a schema as a JSON Schema (draft 2020-12) `serde_json::Value`,
+//! so published API contracts can be generated from the same definitions that validate
+//! requests. `json_schema::document` adds the `$schema` keyword for a standalone document.
+//! Predefined patterns such as `email`, `url`, `date`, `uuid` and `ipv4` are exported with
+//! their JSON Schema `format` next to the `pattern`, optional values accept `null`, and
+//! derived structs and enums are exported like the schemas they generate. Coercions,
+//! transformations and custom error messages are not represented.
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema};
+//! use schema_validator::schema::json_schema;
+//! use serde_json::json;
+//!
+//! let s = schema();
+//!
+//! let schema = s.object()
+//!     .field("name", s.string().min_length(2).max_length(50))
+//!     .field("age", s.integer().min(0).max(150))
+//!     .field("role", s.literal("admin"))
+//!     .field("website", s.string().url().optional());
+//!
+//! let document = json_schema::document(&schema);
+//! assert_eq!(document["$schema"], "https://json-schema.org/draft/2020-12/schema");
+//! assert_eq!(document["properties"]["name"], json!({ "type": "string", "minLength": 2, "maxLength": 50 }));
+//! assert_eq!(document["properties"]["age"], json!({ "type": "integer", "minimum": 0, "maximum": 150 }));
+//! assert_eq!(document["properties"]["role"], json!({ "const": "admin" }));
+//! assert_eq!(document["properties"]["website"]["type"], json!(["string", "null"]));
+//! assert_eq!(document["properties"]["website"]["format"], "uri");
+//! ```
+//!
+//! ## Loading JSON Schema
+//!
+//! `json_schema::schema_from_json_schema` builds a schema from a JSON Schema document, so
+//! documents described by third-party schemas are validated with the same error codes and
+//! paths. Types, `properties`, `required`, `enum`, `const`, string lengths, `pattern`,
+//! `format`, numeric bounds, arrays, `anyOf` and `$ref` within the same document are
+//! supported; properties that are not `required` may be omitted or `null`. Any other
+//! keyword is reported as a `SchemaLoadError` instead of being silently ignored.
+//!
+//! ```rust
+//! use schema_validator::Schema;
+//! use schema_validator::schema::json_schema::{schema_from_json_schema, SchemaLoadError};
+//! use serde_json::json;
+//!
+//! let schema = schema_from_json_schema(&json!({
+//!     "type": "object",
+//!     "properties": {
+//!         "email": { "type": "string", "format": "email" },
+//!         "tags": { "type": "array", "items": { "$ref": "#/$defs/tag" }, "maxItems": 3 }
+//!     },
+//!     "required": ["email"],
+//!     "$defs": { "tag": { "type": "string", "minLength": 1 } }
+//! })).unwrap();
+//!
+//! let cleaned = schema.validate_to_json(&json!({ "email": "ann@example.com" })).unwrap();
+//! assert_eq!(cleaned, json!({ "email": "ann@example.com", "tags": null }));
+//!
+//! let err = schema.validate(&json!({ "email": "ann@example.com", "tags": [""] })).unwrap_err();
+//! assert!(err.to_string().starts_with("tags[0]: [MIN_LENGTH_ERROR]"));
+//!
+//! let err = schema_from_json_schema(&json!({ "type": "string", "contentEncoding": "base64" })).err().unwrap();
+//! assert_eq!(err, SchemaLoadError::UnsupportedKeyword {
+//!     keyword: "contentEncoding".to_string(),
+//!     path: "#".to_string(),
+//! });
+//! ```
+//!
+//! # TypeScript Declarations
+//!
+//! `typescript::declaration` turns a schema into a TypeScript declaration, so a frontend
+//! can share the request types of the backend instead of duplicating them. Objects become
+//! interfaces, optional fields become optional properties, literals become literal types,
+//! and enumerations and unions become union types. `typescript::declaration_of::<T>()`
+//! does the same for types implementing `Validate`, named after the type.
+//!
+//! The `schema-ts` binary writes `.d.ts` files for JSON Schema documents exported with
+//! `json_schema::document`: `schema-ts --out-dir web/types schemas/*.json`.
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema, Validate};
+//! use schema_validator::schema::typescript;
+//!
+//! let s = schema();
+//! let schema = s.object()
+//!     .field("name", s.string().min_length(2))
+//!     .field("role", s.literal("admin").or(s.literal("editor")))
+//!     .field("website", s.string().url().optional());
+//!
+//! assert_eq!(typescript::declaration("User", &schema), "\
+//! export interface User {
+//!   name: string;
+//!   role: \"admin\" | \"editor\";
+//!   website?: string | null;
+//! }
+//! ");
+//!
+//! #[derive(Debug, Clone, PartialEq, Validate)]
+//! enum Status {
+//!     Active,
+//!     Suspended,
+//! }
+//!
+//! assert_eq!(typescript::declaration_of::<Status>(), "export type Status = \"Active\" | \"Suspended\";\n");
+//! ```
+//!
+//! # OpenAPI
+//!
+//! `openapi::OpenApi` builds an OpenAPI 3.1 document from the same schemas that validate
+//! requests at runtime. Named schemas and derived types are registered as
+//! `components/schemas`. A schema whose `title` names a component, set with `title` or the
+//! `title` derive option, is replaced by a `$ref` wherever it appears in another component
+//! or in an operation; `openapi::schema_ref` writes such a `$ref` directly. Descriptions
+//! and examples attached with `describe` and `example`, or with the `description` and
+//! `example` derive options, are carried into the document. `to_json_string` writes the
+//! document as JSON, and `to_yaml` as YAML with the `yaml` cargo feature.
+//!
+//! ```rust
+//! use schema_validator::Validate;
+//! use schema_validator::schema::openapi::{self, OpenApi};
+//! use serde_json::json;
+//!
+//! #[derive(Debug, Clone, PartialEq, Validate)]
+//! #[validate(title = "Address")]
+//! struct Address {
+//!     #[validate(description = "City name", example = "Berlin")]
+//!     city: String,
+//! }
+//!
+//! #[derive(Debug, Clone, PartialEq, Validate)]
+//! struct User {
+//!     #[validate(email)]
+//!     email: String,
+//!     address: Address,
+//! }
+//!
+//! let document = OpenApi::new("Users", "1.0.0")
+//!     .component_of::<Address>()
+//!     .component_of::<User>()
+//!     .operation("post", "/users", json!({
+//!         "requestBody": {
+//!             "content": { "application/json": { "schema": openapi::schema_ref("User") } }
+//!         },
+//!         "responses": { "201": { "description": "Created" } }
+//!     }))
+//!     .to_json();
+//!
+//! let schemas = &document["components"]["schemas"];
+//! assert_eq!(schemas["User"]["properties"]["address"], openapi::schema_ref("Address"));
+//! assert_eq!(schemas["Address"]["properties"]["city"]["examples"], json!(["Berlin"]));
+//! assert_eq!(
+//!     document["paths"]["/users"]["post"]["requestBody"]["content"]["application/json"]["schema"],
+//!     openapi::schema_ref("User"),
+//! );
+//! ```
+//!
+//! # Serde Integration
+//!
+//! With the `serde` cargo feature, values can be validated while they are
+//! deserialized from any self-describing serde format. `Validated<T>` wraps a type
+//! implementing `Validate`, `schema_validator::serde::deserialize` does the same for
+//! a single field through `#[serde(deserialize_with = "...")]`, and
+//! `schema_validator::serde::validate_with` runs any schema before the type's own
+//! `Deserialize` implementation. Validation failures become serde errors listing
+//! the path and code of every failing value. See the [`serde`](crate::serde)
+//! module for examples.
+//!
+//! ```toml
+//! [dependencies]
+//! schema_validator = { version = "0.1", features = ["serde"] }
+//! ```
+//!
 //! # Error Handling
 //!
 //! ```rust
@@ -255,21 +924,102 @@
 //! assert_eq!(err.code, "INVALID_FORMAT");
 //! assert_eq!(err.message, "Only lowercase letters and numbers allowed");
 //! ```
+//!
+//! ## Error Paths
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema};
+//! use serde_json::json;
+//!
+//! let s = schema();
+//!
+//! let schema = s.object()
+//!     .field("users", s.array(s.object().field("email", s.string().email())));
+//!
+//! let err = schema.validate(&json!({
+//!     "users": [{ "email": "a@example.com" }, { "email": "nope" }]
+//! })).unwrap_err();
+//!
+//! // Every leaf error knows where the failing value lives
+//! for (_, leaf) in err.flatten() {
+//!     assert_eq!(leaf.dotted_path(), "users[1].email");
+//!     assert_eq!(leaf.json_pointer(), "/users/1/email");
+//!     assert_eq!(leaf.code, "INVALID_EMAIL");
+//! }
+//! ```
+//!
+//! ## Custom Rules
+//!
+//! Domain rules that the built-in constraints can't express are added with
+//! `refine`, which fails with an `ErrorType::Custom` error carrying the given code
+//! and message, or with `try_refine`, which returns the error itself. Refinements
+//! run once the value passed the other constraints and are reported like any
+//! other failure.
+//!
+//! ```rust
+//! use schema_validator::{schema, Schema};
+//! use schema_validator::error::ValidationError;
+//! use serde_json::json;
+//!
+//! fn checksum_valid(sku: &str) -> bool {
+//!     let digits: Vec<u32> = sku.chars().filter_map(|c| c.to_digit(10)).collect();
+//!     digits.len() > 1 && digits[..digits.len() - 1].iter().sum::<u32>() % 10 == digits[digits.len() - 1]
+//! }
+//!
+//! let s = schema();
+//!
+//! let schema = s.object()
+//!     .field("sku", s.string()
+//!         .pattern(r"^SKU-\d+$")
+//!         .refine(|sku| checksum_valid(sku), "INVALID_SKU", "SKU checksum is not valid"))
+//!     .field("quantity", s.integer().min(1))
+//!     .field("discount", s.number().try_refine(|discount| match *discount <= 0.5 {
+//!         true => Ok(()),
+//!         false => Err(ValidationError::custom("DISCOUNT_TOO_HIGH", "Discounts above 50% need approval")
+//!             .with_param("max", 0.5)),
+//!     }));
+//!
+//! let err = schema.validate(&json!({ "sku": "SKU-1234", "quantity": 2, "discount": 0.8 })).unwrap_err();
+//! assert_eq!(
+//!     err.to_string(),
+//!     "discount: [DISCOUNT_TOO_HIGH] Discounts above 50% need approval; sku: [INVALID_SKU] SKU checksum is not valid"
+//! );
+//! ```
+
+// `ValidationError` is returned by value from every schema; boxing it would
+// make the public API noticeably clumsier for little gain.
+#![allow(clippy::result_large_err)]
 
 pub mod error;
 pub mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
 
 pub use schema_validator_derive::Validate;
 
 pub use error::{ValidationError, ValidationResult};
 pub use schema::Schema;
-pub use schema::mapping::{FromFields, ValidateAs};
+pub use schema::mapping::{FromFields, FromAny, ValidateAs, Validate};
+
+// Used by the code generated by `#[derive(Validate)]`.
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_json;
+}
+
 use schema::clone::CloneAny;
 use schema::string::StringSchema;
 use schema::number::NumberSchema;
+use schema::integer::IntegerSchema;
 use schema::boolean::BooleanSchema;
 use schema::object::ObjectSchema;
 use schema::literal::LiteralSchema;
+use schema::enumeration::{EnumSchema, EnumValue, UnitEnum};
+use schema::array::ArraySchema;
+use schema::record::RecordSchema;
+use schema::tuple::TupleSchema;
+use schema::union::{UnionSchema, DiscriminatedUnionSchema};
+use schema::BoxedSchema;
 
 /// The main entry point for creating schemas.
 ///
@@ -291,11 +1041,15 @@ use schema::literal::LiteralSchema;
 #[derive(Debug)]
 pub struct SchemaBuilder {
     coerce: bool,
+    abort_early: bool,
 }
 
 impl Default for SchemaBuilder {
     fn default() -> Self {
-        Self { coerce: false }
+        Self {
+            coerce: false,
+            abort_early: true,
+        }
     }
 }
 
@@ -328,7 +1082,7 @@ impl SchemaBuilder {
     ///     .email();
     /// ```
     pub fn string(&self) -> StringSchema {
-        StringSchema::new(self.coerce)
+        StringSchema::new(self.coerce, self.abort_early)
     }
 
     /// Creates a number validation schema.
@@ -349,7 +1103,71 @@ impl SchemaBuilder {
     /// assert!(schema.validate(&"42".to_string()).is_ok());
     /// ```
     pub fn number(&self) -> NumberSchema {
-        NumberSchema::new(self.coerce)
+        NumberSchema::new(self.coerce).abort_early(self.abort_early)
+    }
+
+    /// Creates an integer validation schema producing `i64`.
+    ///
+    /// Unlike [`number`](Self::number), the output keeps the exact integer value
+    /// and fractional inputs are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    ///
+    /// let schema = s.integer();
+    /// assert_eq!(schema.validate(&42_i64).unwrap(), 42);
+    /// assert!(schema.validate(&42.5).is_err());
+    ///
+    /// // Sized variants reject values outside the target range
+    /// let schema = s.u32();
+    /// assert!(schema.validate(&-1_i64).is_err());
+    /// ```
+    pub fn integer(&self) -> IntegerSchema<i64> {
+        IntegerSchema::new(self.coerce, self.abort_early)
+    }
+
+    /// Creates an integer validation schema producing `i8`.
+    pub fn i8(&self) -> IntegerSchema<i8> {
+        IntegerSchema::new(self.coerce, self.abort_early)
+    }
+
+    /// Creates an integer validation schema producing `i16`.
+    pub fn i16(&self) -> IntegerSchema<i16> {
+        IntegerSchema::new(self.coerce, self.abort_early)
+    }
+
+    /// Creates an integer validation schema producing `i32`.
+    pub fn i32(&self) -> IntegerSchema<i32> {
+        IntegerSchema::new(self.coerce, self.abort_early)
+    }
+
+    /// Creates an integer validation schema producing `i64`.
+    pub fn i64(&self) -> IntegerSchema<i64> {
+        IntegerSchema::new(self.coerce, self.abort_early)
+    }
+
+    /// Creates an integer validation schema producing `u8`.
+    pub fn u8(&self) -> IntegerSchema<u8> {
+        IntegerSchema::new(self.coerce, self.abort_early)
+    }
+
+    /// Creates an integer validation schema producing `u16`.
+    pub fn u16(&self) -> IntegerSchema<u16> {
+        IntegerSchema::new(self.coerce, self.abort_early)
+    }
+
+    /// Creates an integer validation schema producing `u32`.
+    pub fn u32(&self) -> IntegerSchema<u32> {
+        IntegerSchema::new(self.coerce, self.abort_early)
+    }
+
+    /// Creates an integer validation schema producing `u64`.
+    pub fn u64(&self) -> IntegerSchema<u64> {
+        IntegerSchema::new(self.coerce, self.abort_early)
     }
 
     /// Creates a boolean validation schema.
@@ -410,6 +1228,115 @@ impl SchemaBuilder {
         ObjectSchema::new()
     }
 
+    /// Creates an array validation schema whose elements are validated by `item`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    ///
+    /// // Every element is validated by the item schema
+    /// let schema = s.array(s.string().email()).nonempty();
+    /// assert!(schema.validate(&json!(["user@example.com"])).is_ok());
+    /// assert!(schema.validate(&json!([])).is_err());
+    ///
+    /// // Arrays can be used as object fields
+    /// let schema = s.object()
+    ///     .field("tags", s.array(s.string()).max_items(5));
+    /// assert!(schema.validate(&json!({ "tags": ["rust", "json"] })).is_ok());
+    /// ```
+    pub fn array<S: Schema>(&self, item: S) -> ArraySchema<S> {
+        ArraySchema::new(item)
+    }
+
+    /// Creates a schema for maps with arbitrary string keys whose values are
+    /// validated by `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    ///
+    /// let schema = s.record(s.string().url());
+    /// let links = schema.validate(&json!({ "home": "https://example.com" })).unwrap();
+    /// assert_eq!(links["home"], "https://example.com");
+    ///
+    /// assert!(schema.validate(&json!({ "home": "not a url" })).is_err());
+    /// ```
+    pub fn record<S: Schema>(&self, value: S) -> RecordSchema<S> {
+        RecordSchema::new(value)
+    }
+
+    /// Creates a schema for fixed-length arrays whose elements are validated
+    /// by the schema at the same position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    ///
+    /// let schema = s.tuple(vec![s.number().boxed(), s.number().boxed()]);
+    /// assert!(schema.validate(&json!([52.52, 13.40])).is_ok());
+    /// assert!(schema.validate(&json!([52.52])).is_err());
+    /// ```
+    pub fn tuple(&self, items: Vec<BoxedSchema>) -> TupleSchema {
+        TupleSchema::new(items)
+    }
+
+    /// Creates a union schema accepting values that match any of `members`.
+    ///
+    /// Members are type-erased with [`Schema::boxed`] and tried in order; the
+    /// output records which member matched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.union(vec![s.string().boxed(), s.number().boxed()]);
+    ///
+    /// let value = schema.validate(&42.0).unwrap();
+    /// assert_eq!(value.index(), 1);
+    /// assert_eq!(value.downcast_ref::<f64>(), Some(&42.0));
+    ///
+    /// assert_eq!(schema.validate(&true).unwrap_err().code, "UNION_ERROR");
+    /// ```
+    pub fn union(&self, members: Vec<BoxedSchema>) -> UnionSchema {
+        UnionSchema::new(members)
+    }
+
+    /// Creates a discriminated union schema dispatching on the `discriminator` field.
+    ///
+    /// Variants are added with [`DiscriminatedUnionSchema::variant`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.discriminated_union("kind")
+    ///     .variant("circle", s.object().field("radius", s.number().positive()))
+    ///     .variant("square", s.object().field("side", s.number().positive()));
+    ///
+    /// assert!(schema.validate(&json!({ "kind": "circle", "radius": 2.0 })).is_ok());
+    /// assert!(schema.validate(&json!({ "kind": "circle", "side": 2.0 })).is_err());
+    /// ```
+    pub fn discriminated_union(&self, discriminator: &str) -> DiscriminatedUnionSchema {
+        DiscriminatedUnionSchema::new(discriminator)
+    }
+
     /// Creates a literal validation schema.
     ///
     /// # Examples
@@ -449,6 +1376,58 @@ impl SchemaBuilder {
         LiteralSchema::new(value)
     }
 
+    /// Creates a schema accepting one of a fixed set of options.
+    ///
+    /// Options can be string slices, `String`s, `i64`s or `f64`s; the output is
+    /// the matching option.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    ///
+    /// let schema = s.enumeration(["admin", "editor", "viewer"]);
+    /// assert_eq!(schema.validate(&"admin".to_string()).unwrap(), "admin");
+    /// assert_eq!(schema.validate(&"root".to_string()).unwrap_err().code, "ENUM_ERROR");
+    ///
+    /// // Case-insensitive matching returns the canonical option
+    /// let schema = s.enumeration(["admin", "editor", "viewer"]).case_insensitive();
+    /// assert_eq!(schema.validate(&"Viewer".to_string()).unwrap(), "viewer");
+    /// ```
+    pub fn enumeration<T, I>(&self, options: I) -> EnumSchema<T>
+    where
+        T: EnumValue,
+        I: IntoIterator<Item = T>,
+    {
+        EnumSchema::new(options.into_iter().collect())
+    }
+
+    /// Creates a schema accepting the variants of a Rust enum.
+    ///
+    /// The enum must derive `Validate` and have unit variants only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use schema_validator::{schema, Schema, Validate};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Validate)]
+    /// enum Status {
+    ///     Active,
+    ///     Suspended,
+    /// }
+    ///
+    /// let s = schema();
+    /// let schema = s.native_enum::<Status>().case_insensitive();
+    ///
+    /// assert_eq!(schema.validate(&"active".to_string()).unwrap(), Status::Active);
+    /// ```
+    pub fn native_enum<T: UnitEnum>(&self) -> EnumSchema<T> {
+        EnumSchema::new(T::variants())
+    }
+
     /// Enables type coercion for the schema.
     ///
     /// When type coercion is enabled, the schema will attempt to convert values
@@ -471,7 +1450,39 @@ impl SchemaBuilder {
     /// ```
     pub fn coerce(&self) -> CoerceBuilder {
         CoerceBuilder {
-            builder: SchemaBuilder { coerce: true },
+            builder: SchemaBuilder {
+                coerce: true,
+                abort_early: self.abort_early,
+            },
+        }
+    }
+
+    /// Sets whether schemas created by this builder stop at the first failing constraint.
+    ///
+    /// Validation aborts early by default. With `abort_early(false)` every
+    /// constraint is evaluated and all failures are reported together.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema().abort_early(false);
+    ///
+    /// let schema = s.string().min_length(8).pattern(r"^[a-z]+$");
+    /// let err = schema.validate(&"Nope".to_string()).unwrap_err();
+    /// assert_eq!(err.flatten().len(), 2);
+    ///
+    /// // The setting carries over to coercing schemas
+    /// let err = s.coerce().string().min_length(3).pattern(r"^[a-z]+$")
+    ///     .validate(&7_i64)
+    ///     .unwrap_err();
+    /// assert_eq!(err.code, "MULTIPLE_ERRORS");
+    /// ```
+    pub fn abort_early(&self, abort_early: bool) -> SchemaBuilder {
+        SchemaBuilder {
+            coerce: self.coerce,
+            abort_early,
         }
     }
 }
@@ -493,6 +1504,51 @@ impl CoerceBuilder {
         self.builder.number()
     }
 
+    /// Creates an integer validation schema with type coercion enabled.
+    pub fn integer(&self) -> IntegerSchema<i64> {
+        self.builder.integer()
+    }
+
+    /// Creates a `i8` validation schema with type coercion enabled.
+    pub fn i8(&self) -> IntegerSchema<i8> {
+        self.builder.i8()
+    }
+
+    /// Creates a `i16` validation schema with type coercion enabled.
+    pub fn i16(&self) -> IntegerSchema<i16> {
+        self.builder.i16()
+    }
+
+    /// Creates a `i32` validation schema with type coercion enabled.
+    pub fn i32(&self) -> IntegerSchema<i32> {
+        self.builder.i32()
+    }
+
+    /// Creates a `i64` validation schema with type coercion enabled.
+    pub fn i64(&self) -> IntegerSchema<i64> {
+        self.builder.i64()
+    }
+
+    /// Creates a `u8` validation schema with type coercion enabled.
+    pub fn u8(&self) -> IntegerSchema<u8> {
+        self.builder.u8()
+    }
+
+    /// Creates a `u16` validation schema with type coercion enabled.
+    pub fn u16(&self) -> IntegerSchema<u16> {
+        self.builder.u16()
+    }
+
+    /// Creates a `u32` validation schema with type coercion enabled.
+    pub fn u32(&self) -> IntegerSchema<u32> {
+        self.builder.u32()
+    }
+
+    /// Creates a `u64` validation schema with type coercion enabled.
+    pub fn u64(&self) -> IntegerSchema<u64> {
+        self.builder.u64()
+    }
+
     /// Creates a boolean validation schema with type coercion enabled.
     pub fn boolean(&self) -> BooleanSchema {
         self.builder.boolean()
@@ -502,6 +1558,31 @@ impl CoerceBuilder {
     pub fn object(&self) -> ObjectSchema {
         self.builder.object()
     }
+
+    /// Creates an array validation schema with type coercion enabled.
+    pub fn array<S: Schema>(&self, item: S) -> ArraySchema<S> {
+        self.builder.array(item)
+    }
+
+    /// Creates a record validation schema with type coercion enabled.
+    pub fn record<S: Schema>(&self, value: S) -> RecordSchema<S> {
+        self.builder.record(value)
+    }
+
+    /// Creates a tuple schema; coercion applies to the elements built from this builder.
+    pub fn tuple(&self, items: Vec<BoxedSchema>) -> TupleSchema {
+        self.builder.tuple(items)
+    }
+
+    /// Creates a union schema; coercion applies to the members built from this builder.
+    pub fn union(&self, members: Vec<BoxedSchema>) -> UnionSchema {
+        self.builder.union(members)
+    }
+
+    /// Creates a discriminated union schema dispatching on the `discriminator` field.
+    pub fn discriminated_union(&self, discriminator: &str) -> DiscriminatedUnionSchema {
+        self.builder.discriminated_union(discriminator)
+    }
 }
 
 /// Creates a new schema builder.
@@ -0,0 +1,103 @@
+use std::any::Any;
+use serde_json::Value;
+use crate::error::ValidationResult;
+use crate::schema::Schema;
+
+/// A schema carrying a title, a description and examples for generated
+/// documentation.
+///
+/// Created with [`Schema::title`], [`Schema::describe`] and
+/// [`Schema::example`]. Validation is left to the wrapped schema; the
+/// annotations are exported as the `title`, `description` and `examples`
+/// keywords of its JSON Schema.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use serde_json::json;
+///
+/// let s = schema();
+/// let schema = s.string().email()
+///     .describe("Where notifications are sent")
+///     .example(json!("ann@example.com"));
+///
+/// assert!(schema.validate(&json!("ann@example.com")).is_ok());
+/// assert!(schema.validate(&json!("nope")).is_err());
+///
+/// let exported = schema.to_json_schema();
+/// assert_eq!(exported["description"], "Where notifications are sent");
+/// assert_eq!(exported["examples"], json!(["ann@example.com"]));
+/// ```
+pub struct AnnotatedSchema<S> {
+    schema: S,
+    title: Option<String>,
+    description: Option<String>,
+    examples: Vec<Value>,
+}
+
+impl<S: Schema> AnnotatedSchema<S> {
+    pub fn new(schema: S) -> Self {
+        AnnotatedSchema {
+            schema,
+            title: None,
+            description: None,
+            examples: Vec::new(),
+        }
+    }
+
+    /// Sets the title, replacing any previous one.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Sets the description, replacing any previous one.
+    pub fn describe(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Adds an example value.
+    pub fn example(mut self, example: Value) -> Self {
+        self.examples.push(example);
+        self
+    }
+}
+
+impl<S: Schema> Schema for AnnotatedSchema<S> {
+    type Output = S::Output;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        self.schema.validate(value)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut schema = match self.schema.to_json_schema() {
+            Value::Object(schema) => schema,
+            other => [("allOf".to_string(), Value::Array(vec![other]))].into_iter().collect(),
+        };
+        if let Some(title) = &self.title {
+            schema.insert("title".to_string(), Value::String(title.clone()));
+        }
+        if let Some(description) = &self.description {
+            schema.insert("description".to_string(), Value::String(description.clone()));
+        }
+        if !self.examples.is_empty() {
+            schema.insert("examples".to_string(), Value::Array(self.examples.clone()));
+        }
+        Value::Object(schema)
+    }
+
+    fn validate_default(&self) -> Option<ValidationResult<Self::Output>> {
+        self.schema.validate_default()
+    }
+
+    fn accepts_absent(&self) -> bool {
+        self.schema.accepts_absent()
+    }
+
+    fn output_cloner(&self) -> fn(&dyn Any) -> Box<dyn Any> {
+        self.schema.output_cloner()
+    }
+}
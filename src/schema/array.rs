@@ -0,0 +1,244 @@
+use std::any::Any;
+use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig};
+use crate::schema::Schema;
+use crate::schema::json::{self, type_name};
+use serde_json::{json, Map, Value};
+
+/// A schema for validating lists whose elements all match an item schema.
+///
+/// Accepts `Vec<Box<dyn Any>>`, vectors of primitive values (`Vec<String>`,
+/// `Vec<f64>`, `Vec<i64>`, `Vec<bool>`), vectors of the item schema's output
+/// type and JSON arrays.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use serde_json::json;
+///
+/// let s = schema();
+/// let schema = s.array(s.string().min_length(2))
+///     .min_items(1)
+///     .max_items(3);
+///
+/// let tags = vec!["rust".to_string(), "json".to_string()];
+/// assert_eq!(schema.validate(&tags).unwrap(), vec!["rust", "json"]);
+///
+/// // JSON arrays are accepted as well
+/// assert!(schema.validate(&json!(["rust", "json"])).is_ok());
+///
+/// // Element failures are reported by index
+/// let err = schema.validate(&vec!["ok".to_string(), "x".to_string()]).unwrap_err();
+/// assert_eq!(err.code, "ARRAY_ERROR");
+/// ```
+pub struct ArraySchema<S: Schema> {
+    item: S,
+    error_config: Option<ErrorConfig>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+}
+
+impl<S: Schema> ArraySchema<S> {
+    pub(crate) fn new(item: S) -> Self {
+        ArraySchema {
+            item,
+            error_config: None,
+            min_items: None,
+            max_items: None,
+        }
+    }
+
+    /// Sets a custom error message for the array schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The error code to use
+    /// * `message` - The error message to use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.array(s.string())
+    ///     .nonempty()
+    ///     .set_message("INVALID_TAGS", "At least one tag is required");
+    ///
+    /// let err = schema.validate(&Vec::<String>::new()).unwrap_err();
+    /// assert_eq!(err.code, "INVALID_TAGS");
+    /// ```
+    pub fn set_message<C, M>(mut self, code: C, message: M) -> Self
+    where
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.error_config = Some(ErrorConfig {
+            code: code.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Sets the minimum number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.array(s.number()).min_items(2);
+    ///
+    /// assert!(schema.validate(&vec![1.0, 2.0]).is_ok());
+    /// assert!(schema.validate(&vec![1.0]).is_err());
+    /// ```
+    pub fn min_items(mut self, count: usize) -> Self {
+        self.min_items = Some(count);
+        self
+    }
+
+    /// Sets the maximum number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.array(s.number()).max_items(2);
+    ///
+    /// assert!(schema.validate(&vec![1.0, 2.0]).is_ok());
+    /// assert!(schema.validate(&vec![1.0, 2.0, 3.0]).is_err());
+    /// ```
+    pub fn max_items(mut self, count: usize) -> Self {
+        self.max_items = Some(count);
+        self
+    }
+
+    /// Requires exactly `count` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.array(s.number()).length(2);
+    ///
+    /// assert!(schema.validate(&vec![1.0, 2.0]).is_ok());
+    /// assert!(schema.validate(&vec![1.0]).is_err());
+    /// ```
+    pub fn length(self, count: usize) -> Self {
+        self.min_items(count).max_items(count)
+    }
+
+    /// Requires at least one element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.array(s.boolean()).nonempty();
+    ///
+    /// assert!(schema.validate(&vec![true]).is_ok());
+    /// assert!(schema.validate(&Vec::<bool>::new()).is_err());
+    /// ```
+    pub fn nonempty(self) -> Self {
+        self.min_items(1)
+    }
+}
+
+impl<S: Schema> Schema for ArraySchema<S> {
+    type Output = Vec<S::Output>;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let converted: Vec<Box<dyn Any>>;
+        let items: Vec<&dyn Any> = if let Some(items) = value.downcast_ref::<Vec<Box<dyn Any>>>() {
+            items.iter().map(|item| item.as_ref()).collect()
+        } else if let Some(Value::Array(values)) = value.downcast_ref::<Value>() {
+            converted = values.iter().map(json::to_any).collect();
+            converted.iter().map(|item| item.as_ref()).collect()
+        } else if let Some(items) = typed_items::<S::Output>(value)
+            .or_else(|| typed_items::<String>(value))
+            .or_else(|| typed_items::<f64>(value))
+            .or_else(|| typed_items::<i64>(value))
+            .or_else(|| typed_items::<bool>(value))
+        {
+            items
+        } else {
+            return Err(ValidationError::new(
+                ErrorType::Type {
+                    expected: "Array",
+                    got: type_name(value),
+                },
+                self.error_config.clone(),
+            ));
+        };
+
+        // Check minimum number of elements
+        if let Some(min_items) = self.min_items {
+            if items.len() < min_items {
+                return Err(ValidationError::new(
+                    ErrorType::MinItems {
+                        min: min_items,
+                        got: items.len(),
+                    },
+                    self.error_config.clone(),
+                ));
+            }
+        }
+
+        // Check maximum number of elements
+        if let Some(max_items) = self.max_items {
+            if items.len() > max_items {
+                return Err(ValidationError::new(
+                    ErrorType::MaxItems {
+                        max: max_items,
+                        got: items.len(),
+                    },
+                    self.error_config.clone(),
+                ));
+            }
+        }
+
+        let mut validated = Vec::with_capacity(items.len());
+        let mut errors = Vec::new();
+
+        for (index, item) in items.into_iter().enumerate() {
+            match self.item.validate(item) {
+                Ok(value) => validated.push(value),
+                Err(err) => errors.push((index, err.with_path_prefix(index))),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ValidationError::new(
+                ErrorType::Array { errors },
+                self.error_config.clone(),
+            ));
+        }
+
+        Ok(validated)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut keywords = Map::new();
+        keywords.insert("type".to_string(), json!("array"));
+        keywords.insert("items".to_string(), self.item.to_json_schema());
+        if let Some(min_items) = self.min_items {
+            keywords.insert("minItems".to_string(), json!(min_items));
+        }
+        if let Some(max_items) = self.max_items {
+            keywords.insert("maxItems".to_string(), json!(max_items));
+        }
+        Value::Object(keywords)
+    }
+}
+
+fn typed_items<T: 'static>(value: &dyn Any) -> Option<Vec<&dyn Any>> {
+    value.downcast_ref::<Vec<T>>()
+        .map(|items| items.iter().map(|item| item as &dyn Any).collect())
+}
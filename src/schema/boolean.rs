@@ -1,6 +1,9 @@
 use std::any::Any;
 use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig};
 use crate::schema::Schema;
+use crate::schema::json::{self, type_name};
+use crate::schema::refine::{self, Refinement};
+use serde_json::{json, Value};
 
 type TransformFn = Box<dyn Fn(bool) -> bool>;
 
@@ -8,6 +11,7 @@ pub struct BooleanSchema {
     coerce: bool,
     error_config: Option<ErrorConfig>,
     transforms: Vec<TransformFn>,
+    refinements: Vec<Refinement<bool>>,
 }
 
 impl std::fmt::Debug for BooleanSchema {
@@ -16,6 +20,7 @@ impl std::fmt::Debug for BooleanSchema {
             .field("coerce", &self.coerce)
             .field("error_config", &self.error_config)
             .field("transforms_count", &self.transforms.len())
+            .field("refinements_count", &self.refinements.len())
             .finish()
     }
 }
@@ -26,6 +31,7 @@ impl BooleanSchema {
             coerce,
             error_config: None,
             transforms: Vec::new(),
+            refinements: Vec::new(),
         }
     }
 
@@ -49,6 +55,40 @@ impl BooleanSchema {
         self
     }
 
+    /// Adds a rule the boolean must satisfy, failing with an
+    /// `ErrorType::Custom` error with `code` and `message` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.boolean().refine(|accepted| *accepted, "TERMS_NOT_ACCEPTED", "The terms must be accepted");
+    ///
+    /// assert!(schema.validate(&true).is_ok());
+    /// assert_eq!(schema.validate(&false).unwrap_err().code, "TERMS_NOT_ACCEPTED");
+    /// ```
+    pub fn refine<F, C, M>(mut self, f: F, code: C, message: M) -> Self
+    where
+        F: Fn(&bool) -> bool + 'static,
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.refinements.push(refine::predicate(f, code.into(), message.into()));
+        self
+    }
+
+    /// Adds a rule the boolean must satisfy, failing with the error returned
+    /// by `f`.
+    pub fn try_refine<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&bool) -> ValidationResult<()> + 'static,
+    {
+        self.refinements.push(Box::new(f));
+        self
+    }
+
     fn apply_transforms(&self, mut value: bool) -> bool {
         for transform in &self.transforms {
             value = transform(value);
@@ -61,12 +101,16 @@ impl Schema for BooleanSchema {
     type Output = bool;
 
     fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let mut converted = None;
+        let value = json::resolve(value, &mut converted);
         let result = if let Some(b) = value.downcast_ref::<bool>() {
             Ok(*b)
         } else if self.coerce {
             // Try to coerce different types to boolean
             if let Some(n) = value.downcast_ref::<i64>() {
                 Ok(*n != 0)
+            } else if let Some(n) = value.downcast_ref::<u64>() {
+                Ok(*n != 0)
             } else if let Some(n) = value.downcast_ref::<f64>() {
                 Ok(*n != 0.0)
             } else if let Some(s) = value.downcast_ref::<String>() {
@@ -98,14 +142,12 @@ impl Schema for BooleanSchema {
             ))
         };
 
-        result.map(|b| self.apply_transforms(b))
+        let value = self.apply_transforms(result?);
+        refine::check(&self.refinements, &value)?;
+        Ok(value)
     }
-}
 
-fn type_name(value: &dyn Any) -> &'static str {
-    if value.is::<String>() { "String" }
-    else if value.is::<i64>() { "Integer" }
-    else if value.is::<f64>() { "Float" }
-    else if value.is::<bool>() { "Boolean" }
-    else { "Unknown" }
-}
\ No newline at end of file
+    fn to_json_schema(&self) -> Value {
+        json!({ "type": "boolean" })
+    }
+}
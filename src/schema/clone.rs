@@ -19,17 +19,65 @@ impl CloneAny for &str {
 
 impl CloneAny for f64 {
     fn clone_any(&self) -> Box<dyn Any> {
-        Box::new(self.clone())
+        Box::new(*self)
     }
 }
 
 impl CloneAny for i64 {
     fn clone_any(&self) -> Box<dyn Any> {
-        Box::new(self.clone())
+        Box::new(*self)
+    }
+}
+
+impl CloneAny for i8 {
+    fn clone_any(&self) -> Box<dyn Any> {
+        Box::new(*self)
+    }
+}
+
+impl CloneAny for i16 {
+    fn clone_any(&self) -> Box<dyn Any> {
+        Box::new(*self)
+    }
+}
+
+impl CloneAny for i32 {
+    fn clone_any(&self) -> Box<dyn Any> {
+        Box::new(*self)
+    }
+}
+
+impl CloneAny for u8 {
+    fn clone_any(&self) -> Box<dyn Any> {
+        Box::new(*self)
+    }
+}
+
+impl CloneAny for u16 {
+    fn clone_any(&self) -> Box<dyn Any> {
+        Box::new(*self)
+    }
+}
+
+impl CloneAny for u32 {
+    fn clone_any(&self) -> Box<dyn Any> {
+        Box::new(*self)
+    }
+}
+
+impl CloneAny for u64 {
+    fn clone_any(&self) -> Box<dyn Any> {
+        Box::new(*self)
     }
 }
 
 impl CloneAny for bool {
+    fn clone_any(&self) -> Box<dyn Any> {
+        Box::new(*self)
+    }
+}
+
+impl CloneAny for serde_json::Value {
     fn clone_any(&self) -> Box<dyn Any> {
         Box::new(self.clone())
     }
@@ -37,7 +85,7 @@ impl CloneAny for bool {
 
 impl CloneAny for usize {
     fn clone_any(&self) -> Box<dyn Any> {
-        Box::new(self.clone())
+        Box::new(*self)
     }
 }
 
@@ -47,6 +95,12 @@ impl<T: CloneAny + 'static> CloneAny for Option<T> {
     }
 }
 
+impl<T: CloneAny + 'static> CloneAny for Vec<T> {
+    fn clone_any(&self) -> Box<dyn Any> {
+        Box::new(self.iter().map(|v| v.clone_any()).collect::<Vec<_>>())
+    }
+}
+
 impl<T: CloneAny + 'static> CloneAny for Box<T> {
     fn clone_any(&self) -> Box<dyn Any> {
         (**self).clone_any()
@@ -63,18 +117,29 @@ impl<K: Clone + 'static + std::hash::Hash + Eq, V: CloneAny + 'static> CloneAny
     }
 }
 
+/// Clones `value`, which must have type `T`, through its `CloneAny`
+/// implementation.
+pub(crate) fn clone_as<T: CloneAny + 'static>(value: &dyn Any) -> Box<dyn Any> {
+    match value.downcast_ref::<T>() {
+        Some(value) => value.clone_any(),
+        None => Box::new(()),
+    }
+}
+
 impl CloneAny for Box<dyn Any> {
     fn clone_any(&self) -> Box<dyn Any> {
         if let Some(s) = self.downcast_ref::<String>() {
             Box::new(s.clone())
         } else if let Some(n) = self.downcast_ref::<f64>() {
-            Box::new(n.clone())
+            Box::new(*n)
         } else if let Some(n) = self.downcast_ref::<i64>() {
-            Box::new(n.clone())
+            Box::new(*n)
+        } else if let Some(n) = self.downcast_ref::<u64>() {
+            Box::new(*n)
         } else if let Some(b) = self.downcast_ref::<bool>() {
-            Box::new(b.clone())
+            Box::new(*b)
         } else if let Some(n) = self.downcast_ref::<usize>() {
-            Box::new(n.clone())
+            Box::new(*n)
         } else {
             Box::new(())
         }
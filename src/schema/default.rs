@@ -0,0 +1,93 @@
+use std::any::Any;
+use serde_json::Value;
+use crate::error::ValidationResult;
+use crate::schema::{json, Schema};
+
+/// Returns true if `value` is `null` or `None`.
+fn is_null(value: &dyn Any) -> bool {
+    matches!(value.downcast_ref::<Option<()>>(), Some(None))
+        || matches!(value.downcast_ref::<Option<Box<dyn Any>>>(), Some(None))
+}
+
+/// A schema that substitutes a default value for an absent field.
+///
+/// Created with [`Schema::default`] and [`Schema::default_with`]. The default
+/// is a JSON value validated by the wrapped schema like any input, so
+/// transformations apply to it as well. With [`DefaultSchema::on_null`] the
+/// default also replaces `null`. Defaults are exported as the JSON Schema
+/// `default` keyword, and fields with a default are not `required`.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use serde_json::json;
+///
+/// let s = schema();
+/// let schema = s.object()
+///     .field("name", s.string())
+///     .field("role", s.string().to_uppercase().default("viewer"))
+///     .field("retries", s.integer().min(0).default(3).on_null());
+///
+/// let cleaned = schema.validate_to_json(&json!({ "name": "Ann", "retries": null })).unwrap();
+/// assert_eq!(cleaned, json!({ "name": "Ann", "role": "VIEWER", "retries": 3 }));
+///
+/// // Without `on_null`, null is validated as given
+/// assert!(schema.validate(&json!({ "name": "Ann", "role": null })).is_err());
+/// ```
+pub struct DefaultSchema<S> {
+    schema: S,
+    value: Box<dyn Fn() -> Value>,
+    on_null: bool,
+}
+
+impl<S: Schema> DefaultSchema<S> {
+    pub fn new<F: Fn() -> Value + 'static>(schema: S, value: F) -> Self {
+        DefaultSchema {
+            schema,
+            value: Box::new(value),
+            on_null: false,
+        }
+    }
+
+    /// Also substitutes the default when the input is `null`.
+    pub fn on_null(mut self) -> Self {
+        self.on_null = true;
+        self
+    }
+}
+
+impl<S: Schema> Schema for DefaultSchema<S> {
+    type Output = S::Output;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let mut converted = None;
+        let resolved = json::resolve(value, &mut converted);
+        if self.on_null && is_null(resolved) {
+            self.schema.validate(&(self.value)())
+        } else {
+            self.schema.validate(value)
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut schema = match self.schema.to_json_schema() {
+            Value::Object(schema) => schema,
+            other => [("allOf".to_string(), Value::Array(vec![other]))].into_iter().collect(),
+        };
+        schema.insert("default".to_string(), (self.value)());
+        Value::Object(schema)
+    }
+
+    fn validate_default(&self) -> Option<ValidationResult<Self::Output>> {
+        Some(self.schema.validate(&(self.value)()))
+    }
+
+    fn accepts_absent(&self) -> bool {
+        true
+    }
+
+    fn output_cloner(&self) -> fn(&dyn Any) -> Box<dyn Any> {
+        self.schema.output_cloner()
+    }
+}
@@ -0,0 +1,246 @@
+use std::any::Any;
+use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig};
+use crate::schema::Schema;
+use crate::schema::clone::CloneAny;
+use crate::schema::integer::native_integer;
+use crate::schema::json::{self, type_name};
+use serde_json::{json, Value};
+
+/// A value that can be one of the options of an [`EnumSchema`].
+///
+/// Implemented for `&'static str`, `String`, `i64` and `f64`, and by
+/// `#[derive(Validate)]` for enums with unit variants only.
+pub trait EnumValue: Clone + CloneAny + 'static {
+    /// Returns the JSON value this option is matched against.
+    fn to_json(&self) -> Value;
+}
+
+impl EnumValue for &'static str {
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl EnumValue for String {
+    fn to_json(&self) -> Value {
+        Value::String(self.clone())
+    }
+}
+
+impl EnumValue for i64 {
+    fn to_json(&self) -> Value {
+        Value::from(*self)
+    }
+}
+
+impl EnumValue for f64 {
+    fn to_json(&self) -> Value {
+        Value::from(*self)
+    }
+}
+
+impl EnumValue for Value {
+    fn to_json(&self) -> Value {
+        self.clone()
+    }
+}
+
+/// A Rust enum whose variants can all be listed, so it can be validated with
+/// [`SchemaBuilder::native_enum`](crate::SchemaBuilder::native_enum).
+///
+/// `#[derive(Validate)]` implements this for enums with unit variants only.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema, Validate};
+///
+/// #[derive(Debug, Clone, PartialEq, Validate)]
+/// enum Role {
+///     Admin,
+///     Editor,
+///     Viewer,
+/// }
+///
+/// let s = schema();
+/// let schema = s.native_enum::<Role>();
+///
+/// assert_eq!(schema.validate(&"Editor".to_string()).unwrap(), Role::Editor);
+/// assert!(schema.validate(&"Owner".to_string()).is_err());
+/// ```
+pub trait UnitEnum: EnumValue {
+    /// Returns every variant, in declaration order.
+    fn variants() -> Vec<Self>;
+}
+
+/// A schema accepting one value out of a fixed set of options.
+///
+/// The output is the matching option itself, so with
+/// [`case_insensitive`](EnumSchema::case_insensitive) matching the canonical
+/// spelling is returned. Numbers match regardless of their representation,
+/// e.g. `1_i64` matches the option `1.0`.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use serde_json::json;
+///
+/// let s = schema();
+/// let schema = s.enumeration(["admin", "editor", "viewer"]);
+///
+/// assert_eq!(schema.validate(&"editor".to_string()).unwrap(), "editor");
+/// assert_eq!(schema.validate(&json!("viewer")).unwrap(), "viewer");
+///
+/// let err = schema.validate(&"owner".to_string()).unwrap_err();
+/// assert_eq!(err.code, "ENUM_ERROR");
+/// assert_eq!(err.message, "Enum error: expected one of 'admin', 'editor', 'viewer', got 'owner'");
+///
+/// // Numeric options
+/// let schema = s.enumeration([1_i64, 2, 4, 8]);
+/// assert_eq!(schema.validate(&json!(4)).unwrap(), 4);
+/// assert!(schema.validate(&3_i64).is_err());
+/// ```
+pub struct EnumSchema<T: EnumValue> {
+    options: Vec<T>,
+    case_insensitive: bool,
+    error_config: Option<ErrorConfig>,
+}
+
+impl<T: EnumValue> EnumSchema<T> {
+    pub(crate) fn new(options: Vec<T>) -> Self {
+        EnumSchema {
+            options,
+            case_insensitive: false,
+            error_config: None,
+        }
+    }
+
+    /// Matches string options ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.enumeration(["admin", "editor"]).case_insensitive();
+    ///
+    /// assert_eq!(schema.validate(&"ADMIN".to_string()).unwrap(), "admin");
+    /// ```
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    /// Sets a custom error message for the enum schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The error code to use
+    /// * `message` - The error message to use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.enumeration(["admin", "editor"])
+    ///     .set_message("INVALID_ROLE", "Unknown role");
+    ///
+    /// let err = schema.validate(&"owner".to_string()).unwrap_err();
+    /// assert_eq!(err.code, "INVALID_ROLE");
+    /// ```
+    pub fn set_message<C, M>(mut self, code: C, message: M) -> Self
+    where
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.error_config = Some(ErrorConfig {
+            code: code.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Returns the allowed options.
+    pub fn options(&self) -> &[T] {
+        &self.options
+    }
+
+    fn matches(&self, option: &Value, input: &Value) -> bool {
+        match (option, input) {
+            (Value::String(option), Value::String(input)) if self.case_insensitive => {
+                option.to_lowercase() == input.to_lowercase()
+            }
+            (Value::Number(option), Value::Number(input)) => {
+                match (option.as_i64(), input.as_i64()) {
+                    (Some(option), Some(input)) => option == input,
+                    _ => option.as_f64() == input.as_f64(),
+                }
+            }
+            (option, input) => option == input,
+        }
+    }
+}
+
+impl<T: EnumValue> Schema for EnumSchema<T> {
+    type Output = T;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let input = input_value(value);
+
+        if let Some(input) = &input {
+            if let Some(option) = self.options.iter().find(|option| self.matches(&option.to_json(), input)) {
+                return Ok(option.clone());
+            }
+        }
+
+        Err(ValidationError::new(
+            ErrorType::Enum {
+                expected: self.options.iter().map(|option| label(&option.to_json())).collect(),
+                got: match &input {
+                    Some(input) => label(input),
+                    None => type_name(value).to_string(),
+                },
+            },
+            self.error_config.clone(),
+        ))
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let options: Vec<Value> = self.options.iter().map(EnumValue::to_json).collect();
+        json!({ "enum": options })
+    }
+}
+
+fn input_value(value: &dyn Any) -> Option<Value> {
+    if let Some(s) = value.downcast_ref::<String>() {
+        Some(Value::String(s.clone()))
+    } else if let Some(s) = value.downcast_ref::<&str>() {
+        Some(Value::String(s.to_string()))
+    } else if let Some(n) = value.downcast_ref::<f64>() {
+        Some(Value::from(*n))
+    } else if let Some(b) = value.downcast_ref::<bool>() {
+        Some(Value::Bool(*b))
+    } else if let Some(json) = value.downcast_ref::<Value>() {
+        Some(json.clone())
+    } else if let Some(n) = native_integer(value) {
+        i64::try_from(n).map(Value::from)
+            .or_else(|_| u64::try_from(n).map(Value::from))
+            .ok()
+    } else {
+        // Arrays, objects and nulls, as found in converted JSON documents
+        json::from_any(value).ok()
+    }
+}
+
+/// Renders an option the way it is written in error messages: strings as-is,
+/// everything else as JSON.
+fn label(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
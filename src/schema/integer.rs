@@ -0,0 +1,367 @@
+use std::any::Any;
+use std::fmt::{Debug, Display};
+use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig};
+use crate::schema::Schema;
+use crate::schema::clone::CloneAny;
+use crate::schema::json::{self, type_name};
+use serde_json::{json, Map, Number, Value};
+
+/// Integer types that can be produced by an [`IntegerSchema`].
+pub trait Integer: Copy + PartialOrd + Debug + Display + CloneAny + 'static {
+    /// The name of the type used in error messages.
+    const NAME: &'static str;
+
+    /// The smallest value of the type.
+    const MIN: Self;
+
+    /// The largest value of the type.
+    const MAX: Self;
+
+    /// Converts from the widest supported representation, returning `None` on overflow.
+    fn from_i128(value: i128) -> Option<Self>;
+
+    /// Converts into the widest supported representation.
+    fn to_i128(self) -> i128;
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Integer for $ty {
+                const NAME: &'static str = stringify!($ty);
+                const MIN: Self = <$ty>::MIN;
+                const MAX: Self = <$ty>::MAX;
+
+                fn from_i128(value: i128) -> Option<Self> {
+                    <$ty>::try_from(value).ok()
+                }
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// A schema for validating integers that produces the exact integer type `T`.
+///
+/// Native integers of any width, integral floats and JSON integers are accepted
+/// without a round-trip through `f64`, so identifiers above 2^53 keep their
+/// precision. Values that do not fit into `T` are rejected with an
+/// `ErrorType::Overflow` error, fractional values with `ErrorType::NotInteger`.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use serde_json::json;
+///
+/// let s = schema();
+///
+/// let schema = s.integer();
+/// assert_eq!(schema.validate(&json!(9007199254740993_i64)).unwrap(), 9007199254740993);
+/// assert_eq!(schema.validate(&1.5).unwrap_err().code, "NOT_INTEGER");
+///
+/// let schema = s.u8();
+/// assert_eq!(schema.validate(&255_i64).unwrap(), 255_u8);
+/// assert_eq!(schema.validate(&256_i64).unwrap_err().code, "INTEGER_OVERFLOW");
+///
+/// // Strings are parsed when coercion is enabled
+/// let schema = s.coerce().u64();
+/// assert_eq!(schema.validate(&"18446744073709551615".to_string()).unwrap(), u64::MAX);
+/// ```
+pub struct IntegerSchema<T: Integer> {
+    coerce: bool,
+    abort_early: bool,
+    error_config: Option<ErrorConfig>,
+    min: Option<T>,
+    max: Option<T>,
+    multiple_of: Option<T>,
+}
+
+impl<T: Integer> IntegerSchema<T> {
+    pub(crate) fn new(coerce: bool, abort_early: bool) -> Self {
+        IntegerSchema {
+            coerce,
+            abort_early,
+            error_config: None,
+            min: None,
+            max: None,
+            multiple_of: None,
+        }
+    }
+
+    /// Sets a custom error message for the integer schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The error code to use
+    /// * `message` - The error message to use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.u16().set_message("INVALID_PORT", "Invalid port number");
+    ///
+    /// let err = schema.validate(&70000_i64).unwrap_err();
+    /// assert_eq!(err.code, "INVALID_PORT");
+    /// ```
+    pub fn set_message<C, M>(mut self, code: C, message: M) -> Self
+    where
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.error_config = Some(ErrorConfig {
+            code: code.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Requires the integer to be greater than or equal to `min`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.u32().min(1).max(100);
+    ///
+    /// assert!(schema.validate(&50_i64).is_ok());
+    /// assert_eq!(schema.validate(&0_i64).unwrap_err().code, "TOO_SMALL");
+    /// assert_eq!(schema.validate(&101_i64).unwrap_err().code, "TOO_LARGE");
+    /// ```
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Requires the integer to be less than or equal to `max`.
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Requires the integer to be a multiple of `step`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.integer().multiple_of(5);
+    ///
+    /// assert!(schema.validate(&15_i64).is_ok());
+    /// assert_eq!(schema.validate(&12_i64).unwrap_err().code, "NOT_MULTIPLE_OF");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not greater than zero. The `multiple_of` derive
+    /// option rejects such steps at compile time instead.
+    pub fn multiple_of(mut self, step: T) -> Self {
+        assert!(step.to_i128() > 0, "multiple_of requires a positive step, got {}", step.to_i128());
+        self.multiple_of = Some(step);
+        self
+    }
+
+    /// Sets whether validation stops at the first failing constraint.
+    pub fn abort_early(mut self, abort_early: bool) -> Self {
+        self.abort_early = abort_early;
+        self
+    }
+
+    fn to_i128(&self, value: &dyn Any) -> ValidationResult<i128> {
+        let mut converted = None;
+        let value = json::resolve(value, &mut converted);
+        if let Some(n) = native_integer(value) {
+            return Ok(n);
+        }
+        if let Some(n) = value.downcast_ref::<f64>() {
+            return self.float_to_i128(*n);
+        }
+        if let Some(n) = value.downcast_ref::<Number>() {
+            return self.number_to_i128(n);
+        }
+
+        if self.coerce {
+            if let Some(s) = value.downcast_ref::<String>() {
+                let s = s.trim();
+                return match s.parse::<i128>() {
+                    Ok(n) => Ok(n),
+                    Err(_) => match s.parse::<f64>() {
+                        Ok(f) if f.is_finite() => self.float_to_i128(f),
+                        _ => Err(ValidationError::new(
+                            ErrorType::Coercion {
+                                from: "String",
+                                to: "Integer",
+                            },
+                            self.error_config.clone(),
+                        )),
+                    },
+                };
+            } else if let Some(b) = value.downcast_ref::<bool>() {
+                return Ok(if *b { 1 } else { 0 });
+            }
+            return Err(ValidationError::new(
+                ErrorType::Coercion {
+                    from: type_name(value),
+                    to: "Integer",
+                },
+                self.error_config.clone(),
+            ));
+        }
+
+        Err(ValidationError::new(
+            ErrorType::Type {
+                expected: "Integer",
+                got: type_name(value),
+            },
+            self.error_config.clone(),
+        ))
+    }
+
+    fn number_to_i128(&self, n: &Number) -> ValidationResult<i128> {
+        if let Some(i) = n.as_i64() {
+            Ok(i as i128)
+        } else if let Some(u) = n.as_u64() {
+            Ok(u as i128)
+        } else {
+            self.float_to_i128(n.as_f64().unwrap_or(f64::NAN))
+        }
+    }
+
+    fn float_to_i128(&self, value: f64) -> ValidationResult<i128> {
+        if !value.is_finite() || value.fract() != 0.0 {
+            return Err(ValidationError::new(
+                ErrorType::NotInteger { got: value },
+                self.error_config.clone(),
+            ));
+        }
+        if value < i128::MIN as f64 || value > i128::MAX as f64 {
+            return Err(ValidationError::new(
+                ErrorType::Overflow {
+                    target: T::NAME,
+                    got: value.to_string(),
+                },
+                self.error_config.clone(),
+            ));
+        }
+        Ok(value as i128)
+    }
+
+    fn check_constraints(&self, value: T) -> ValidationResult<()> {
+        let mut errors = Vec::new();
+
+        if let Some(min) = self.min {
+            if value < min {
+                errors.push(ValidationError::new(
+                    ErrorType::TooSmall {
+                        min: min.to_i128() as f64,
+                        inclusive: true,
+                        got: value.to_i128() as f64,
+                    },
+                    self.error_config.clone(),
+                ));
+            }
+        }
+
+        if let Some(max) = self.max {
+            if value > max && (errors.is_empty() || !self.abort_early) {
+                errors.push(ValidationError::new(
+                    ErrorType::TooLarge {
+                        max: max.to_i128() as f64,
+                        inclusive: true,
+                        got: value.to_i128() as f64,
+                    },
+                    self.error_config.clone(),
+                ));
+            }
+        }
+
+        if let Some(step) = self.multiple_of {
+            let step = step.to_i128();
+            if value.to_i128() % step != 0 && (errors.is_empty() || !self.abort_early) {
+                errors.push(ValidationError::new(
+                    ErrorType::NotMultipleOf {
+                        step: step as f64,
+                        got: value.to_i128() as f64,
+                    },
+                    self.error_config.clone(),
+                ));
+            }
+        }
+
+        match ValidationError::from_errors(errors, self.error_config.clone()) {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Integer> Schema for IntegerSchema<T> {
+    type Output = T;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let wide = self.to_i128(value)?;
+        let value = T::from_i128(wide).ok_or_else(|| ValidationError::new(
+            ErrorType::Overflow {
+                target: T::NAME,
+                got: wide.to_string(),
+            },
+            self.error_config.clone(),
+        ))?;
+
+        self.check_constraints(value)?;
+        Ok(value)
+    }
+
+    /// Bounds of the integer type are included when it is narrower than `i64`.
+    fn to_json_schema(&self) -> Value {
+        let mut keywords = Map::new();
+        keywords.insert("type".to_string(), json!("integer"));
+
+        let min = self.min.unwrap_or(T::MIN).to_i128();
+        if self.min.is_some() || min > i64::MIN as i128 {
+            keywords.insert("minimum".to_string(), integer_value(min));
+        }
+        let max = self.max.unwrap_or(T::MAX).to_i128();
+        if self.max.is_some() || max < i64::MAX as i128 {
+            keywords.insert("maximum".to_string(), integer_value(max));
+        }
+        if let Some(step) = self.multiple_of {
+            keywords.insert("multipleOf".to_string(), integer_value(step.to_i128()));
+        }
+
+        Value::Object(keywords)
+    }
+}
+
+fn integer_value(value: i128) -> Value {
+    match i64::try_from(value) {
+        Ok(value) => json!(value),
+        Err(_) => json!(value as u64),
+    }
+}
+
+/// Reads any native integer type as an `i128`.
+pub(crate) fn native_integer(value: &dyn Any) -> Option<i128> {
+    if let Some(n) = value.downcast_ref::<i64>() { Some(*n as i128) }
+    else if let Some(n) = value.downcast_ref::<u64>() { Some(*n as i128) }
+    else if let Some(n) = value.downcast_ref::<i32>() { Some(*n as i128) }
+    else if let Some(n) = value.downcast_ref::<u32>() { Some(*n as i128) }
+    else if let Some(n) = value.downcast_ref::<i16>() { Some(*n as i128) }
+    else if let Some(n) = value.downcast_ref::<u16>() { Some(*n as i128) }
+    else if let Some(n) = value.downcast_ref::<i8>() { Some(*n as i128) }
+    else if let Some(n) = value.downcast_ref::<u8>() { Some(*n as i128) }
+    else if let Some(n) = value.downcast_ref::<isize>() { Some(*n as i128) }
+    else { value.downcast_ref::<usize>().map(|n| *n as i128) }
+}
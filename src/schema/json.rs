@@ -0,0 +1,202 @@
+use std::any::Any;
+use std::collections::HashMap;
+use crate::error::{ValidationError, ValidationResult, ErrorType};
+use crate::schema::integer::native_integer;
+use crate::schema::union::UnionValue;
+use serde_json::{Map, Value};
+
+/// Converts a JSON value into the boxed representation used by the schemas.
+///
+/// Strings and booleans become `String` and `bool`. Integral numbers become
+/// `i64` (or `u64` above `i64::MAX`) so they keep their precision, all other
+/// numbers become `f64`. `null` becomes `None::<()>`, arrays become `Vec<Box<dyn Any>>` and objects become
+/// `HashMap<String, Box<dyn Any>>`.
+pub(crate) fn to_any(value: &Value) -> Box<dyn Any> {
+    match value {
+        Value::String(s) => Box::new(s.clone()),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Box::new(i)
+            } else if let Some(u) = n.as_u64() {
+                Box::new(u)
+            } else {
+                Box::new(n.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        Value::Bool(b) => Box::new(*b),
+        Value::Null => Box::new(None::<()>),
+        Value::Array(values) => Box::new(values.iter().map(to_any).collect::<Vec<_>>()),
+        Value::Object(map) => Box::new(
+            map.iter()
+                .map(|(key, value)| (key.clone(), to_any(value)))
+                .collect::<HashMap<_, _>>(),
+        ),
+    }
+}
+
+/// Names the type of a boxed value for `ErrorType::Type` errors.
+pub(crate) fn type_name(value: &dyn Any) -> &'static str {
+    if value.is::<String>() { "String" }
+    else if value.is::<i64>() || value.is::<u64>() { "Integer" }
+    else if value.is::<f64>() { "Float" }
+    else if value.is::<bool>() { "Boolean" }
+    else if value.is::<Vec<Box<dyn Any>>>() { "Array" }
+    else if value.is::<HashMap<String, Box<dyn Any>>>() { "Object" }
+    else if value.is::<Value>() { "JSON value" }
+    else { "Unknown" }
+}
+
+/// Returns `value` itself, or its boxed representation if it is a JSON value.
+///
+/// `converted` holds the boxed representation so the returned reference can
+/// borrow from it.
+pub(crate) fn resolve<'a>(value: &'a dyn Any, converted: &'a mut Option<Box<dyn Any>>) -> &'a dyn Any {
+    match value.downcast_ref::<Value>() {
+        Some(json) => &**converted.insert(to_any(json)),
+        None => value,
+    }
+}
+
+/// Converts the output of a schema back into a JSON value.
+///
+/// Handles everything the built-in schemas produce: strings, numbers,
+/// booleans, `None`, boxed values, union values, and options, vectors and
+/// string-keyed maps of those. Values of other types, such as the output of a
+/// custom `transform`, and non-finite numbers are rejected with a type error
+/// at their path.
+pub(crate) fn from_any(value: &dyn Any) -> ValidationResult<Value> {
+    if let Some(json) = value.downcast_ref::<Value>() {
+        return Ok(json.clone());
+    }
+    // JSON has no NaN or infinities, and `Value::from` would turn them into null
+    if let Some(n) = value.downcast_ref::<f64>().filter(|n| !n.is_finite()) {
+        return Err(ValidationError::new(
+            ErrorType::Type {
+                expected: "finite number",
+                got: if n.is_nan() { "NaN" } else if *n > 0.0 { "Infinity" } else { "-Infinity" },
+            },
+            None,
+        ));
+    }
+    if let Some(json) = scalar(value) {
+        return Ok(json);
+    }
+    if let Some(boxed) = value.downcast_ref::<Box<dyn Any>>() {
+        return from_any(boxed.as_ref());
+    }
+    if let Some(union) = value.downcast_ref::<UnionValue>() {
+        return from_any(union.value());
+    }
+    if value.is::<Option<()>>() {
+        return Ok(Value::Null);
+    }
+
+    macro_rules! containers {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(option) = value.downcast_ref::<Option<$ty>>() {
+                    return match option {
+                        None => Ok(Value::Null),
+                        Some(value) => from_any(value),
+                    };
+                }
+                if let Some(items) = value.downcast_ref::<Vec<$ty>>() {
+                    return items.iter()
+                        .enumerate()
+                        .map(|(index, item)| from_any(item).map_err(|err| err.with_path_prefix(index)))
+                        .collect::<ValidationResult<Vec<_>>>()
+                        .map(Value::Array);
+                }
+                if let Some(map) = value.downcast_ref::<HashMap<String, $ty>>() {
+                    return map.iter()
+                        .map(|(key, value)| {
+                            from_any(value)
+                                .map(|value| (key.clone(), value))
+                                .map_err(|err| err.with_path_prefix(key.as_str()))
+                        })
+                        .collect::<ValidationResult<Map<_, _>>>()
+                        .map(Value::Object);
+                }
+            )*
+        };
+    }
+
+    containers!(
+        Box<dyn Any>, String, &'static str, f64, bool, i8, i16, i32, i64, u8, u16, u32, u64,
+        HashMap<String, Box<dyn Any>>, Vec<Box<dyn Any>>, UnionValue, Value
+    );
+
+    Err(ValidationError::new(
+        ErrorType::Type {
+            expected: "JSON value",
+            got: "Unknown",
+        },
+        None,
+    ))
+}
+
+/// Copies a value of the boxed representation, such as a field of an input
+/// map.
+///
+/// Handles strings, numbers, booleans, `None`, JSON values, and boxed values,
+/// options, vectors and string-keyed maps of those. Returns `None` for values
+/// of any other type, which cannot be copied through `&dyn Any`.
+pub(crate) fn copy_any(value: &dyn Any) -> Option<Box<dyn Any>> {
+    if let Some(boxed) = value.downcast_ref::<Box<dyn Any>>() {
+        return copy_any(boxed.as_ref()).map(|value| Box::new(value) as Box<dyn Any>);
+    }
+    if let Some(option) = value.downcast_ref::<Option<Box<dyn Any>>>() {
+        return match option {
+            None => Some(Box::new(None::<Box<dyn Any>>)),
+            Some(value) => copy_any(value.as_ref()).map(|value| Box::new(Some(value)) as Box<dyn Any>),
+        };
+    }
+    if let Some(items) = value.downcast_ref::<Vec<Box<dyn Any>>>() {
+        return items.iter()
+            .map(|item| copy_any(item.as_ref()))
+            .collect::<Option<Vec<_>>>()
+            .map(|items| Box::new(items) as Box<dyn Any>);
+    }
+    if let Some(map) = value.downcast_ref::<HashMap<String, Box<dyn Any>>>() {
+        return map.iter()
+            .map(|(key, value)| copy_any(value.as_ref()).map(|value| (key.clone(), value)))
+            .collect::<Option<HashMap<_, _>>>()
+            .map(|map| Box::new(map) as Box<dyn Any>);
+    }
+
+    macro_rules! copies {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return Some(Box::new(value.clone()));
+                }
+                if let Some(option) = value.downcast_ref::<Option<$ty>>() {
+                    return Some(Box::new(option.clone()));
+                }
+            )*
+        };
+    }
+
+    copies!(
+        (), String, &'static str, bool, f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, Value
+    );
+
+    None
+}
+
+fn scalar(value: &dyn Any) -> Option<Value> {
+    if let Some(s) = value.downcast_ref::<String>() {
+        Some(Value::String(s.clone()))
+    } else if let Some(s) = value.downcast_ref::<&'static str>() {
+        Some(Value::String(s.to_string()))
+    } else if let Some(n) = value.downcast_ref::<f64>() {
+        Some(Value::from(*n))
+    } else if let Some(b) = value.downcast_ref::<bool>() {
+        Some(Value::Bool(*b))
+    } else {
+        let n = native_integer(value)?;
+        i64::try_from(n).map(Value::from)
+            .or_else(|_| u64::try_from(n).map(Value::from))
+            .ok()
+    }
+}
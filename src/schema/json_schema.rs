@@ -0,0 +1,652 @@
+//! JSON Schema (draft 2020-12) support.
+//!
+//! Every built-in schema describes itself with [`Schema::to_json_schema`];
+//! [`document`] turns that description into a standalone document.
+//! [`schema_from_json_schema`] goes the other way and builds a schema from a
+//! JSON Schema document.
+
+use std::any::Any;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+use crate::error::{json_pointer, ErrorType, PathSegment, ValidationError, ValidationResult};
+use crate::schema::{json, BoxedSchema, Schema};
+use crate::schema::patterns::Pattern;
+use crate::schema::string::StringSchema;
+
+/// The `$schema` URI of the JSON Schema dialect produced by [`Schema::to_json_schema`].
+pub const DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Returns the JSON Schema of `schema` as a standalone document declaring
+/// draft 2020-12 with `$schema`.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use schema_validator::schema::json_schema;
+/// use serde_json::json;
+///
+/// let s = schema();
+/// let schema = s.object()
+///     .field("email", s.string().email())
+///     .field("age", s.integer().min(0).optional());
+///
+/// assert_eq!(json_schema::document(&schema), json!({
+///     "$schema": "https://json-schema.org/draft/2020-12/schema",
+///     "type": "object",
+///     "properties": {
+///         "email": { "type": "string", "format": "email", "pattern": schema_validator::schema::patterns::EMAIL.as_str() },
+///         "age": { "type": ["integer", "null"], "minimum": 0 }
+///     },
+///     "required": ["age", "email"]
+/// }));
+/// ```
+pub fn document<S: Schema + ?Sized>(schema: &S) -> Value {
+    let mut document = Map::new();
+    document.insert("$schema".to_string(), json!(DRAFT_2020_12));
+    match schema.to_json_schema() {
+        Value::Object(keywords) => document.extend(keywords),
+        other => {
+            document.insert("allOf".to_string(), json!([other]));
+        }
+    }
+    Value::Object(document)
+}
+
+/// Widens a schema to also accept `null`.
+pub(crate) fn nullable(schema: Value) -> Value {
+    let types = match &schema {
+        Value::Object(keywords) if !keywords.contains_key("enum") && !keywords.contains_key("const") => {
+            match keywords.get("type") {
+                Some(Value::String(ty)) => Some(json!([ty, "null"])),
+                Some(Value::Array(types)) if types.contains(&json!("null")) => Some(Value::Array(types.clone())),
+                Some(Value::Array(types)) => {
+                    let mut types = types.clone();
+                    types.push(json!("null"));
+                    Some(Value::Array(types))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+
+    match (types, schema) {
+        (Some(types), Value::Object(mut keywords)) => {
+            keywords.insert("type".to_string(), types);
+            Value::Object(keywords)
+        }
+        (_, schema) => json!({ "anyOf": [schema, { "type": "null" }] }),
+    }
+}
+
+/// Adds a required `tag` property holding the constant `label` to an object schema.
+pub(crate) fn with_tag(schema: Value, tag: &str, label: &str) -> Value {
+    match schema {
+        Value::Object(mut keywords) if keywords.get("type") == Some(&json!("object")) => {
+            if let Value::Object(properties) = keywords.entry("properties").or_insert_with(|| json!({})) {
+                properties.insert(tag.to_string(), json!({ "const": label }));
+            }
+            if let Value::Array(required) = keywords.entry("required").or_insert_with(|| json!([])) {
+                if !required.contains(&json!(tag)) {
+                    required.insert(0, json!(tag));
+                }
+            }
+            Value::Object(keywords)
+        }
+        schema => json!({
+            "allOf": [
+                { "type": "object", "properties": { tag: { "const": label } }, "required": [tag] },
+                schema
+            ]
+        }),
+    }
+}
+
+/// An error building a schema from a JSON Schema document.
+///
+/// `path` is the location of the offending schema within the document, as a
+/// JSON Pointer fragment such as `#/properties/email`.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum SchemaLoadError {
+    /// The keyword has no equivalent in this library.
+    #[error("unsupported keyword '{keyword}' at '{path}'")]
+    UnsupportedKeyword { keyword: String, path: String },
+    /// The `format` does not map onto a predefined pattern.
+    #[error("unsupported format '{format}' at '{path}'")]
+    UnsupportedFormat { format: String, path: String },
+    /// The keyword has a value that is not valid for it.
+    #[error("invalid value for '{keyword}' at '{path}': {message}")]
+    InvalidKeyword { keyword: String, path: String, message: String },
+    /// The schema is neither an object nor a boolean.
+    #[error("invalid schema at '{path}': expected an object or a boolean")]
+    InvalidSchema { path: String },
+    /// A `$ref` does not point into the same document.
+    #[error("unresolved reference '{reference}' at '{path}'")]
+    UnresolvedRef { reference: String, path: String },
+    /// A `$ref` refers back to a schema that contains it.
+    #[error("recursive reference '{reference}' at '{path}'")]
+    RecursiveRef { reference: String, path: String },
+}
+
+/// Builds a schema from a JSON Schema document, so that documents described
+/// by third-party schemas are validated with this library's error model.
+///
+/// Supported keywords are `type` (including lists of types), `properties`,
+/// `required`, `additionalProperties`, `items`, `prefixItems` with a fixed
+/// length, `minItems`/`maxItems`, `enum`, `const`, `minLength`/`maxLength`,
+/// `pattern`, `format`, numeric bounds, `multipleOf`, `anyOf` and `$ref`
+/// within the same document. Annotations such as `title` and `description`
+/// are ignored; any other keyword is reported as
+/// [`SchemaLoadError::UnsupportedKeyword`].
+///
+/// Properties that are not `required` become optional fields, unless they
+/// have a `default`, which is substituted when they are absent. Undeclared keys
+/// are kept, rejected or validated as `additionalProperties` allows, using
+/// [`ObjectSchema::passthrough`](crate::schema::object::ObjectSchema::passthrough),
+/// `strict` and `catchall`. The validated
+/// value is returned as a `Box<dyn Any>`; use [`Schema::validate_to_json`] to
+/// get the cleaned document.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::Schema;
+/// use schema_validator::schema::json_schema::schema_from_json_schema;
+/// use serde_json::json;
+///
+/// let schema = schema_from_json_schema(&json!({
+///     "type": "object",
+///     "properties": {
+///         "email": { "type": "string", "format": "email" },
+///         "age": { "type": "integer", "minimum": 0 },
+///         "role": { "$ref": "#/$defs/role" }
+///     },
+///     "required": ["email", "role"],
+///     "$defs": {
+///         "role": { "enum": ["admin", "editor"] }
+///     }
+/// })).unwrap();
+///
+/// assert!(schema.validate(&json!({ "email": "ann@example.com", "role": "admin" })).is_ok());
+///
+/// let err = schema.validate(&json!({ "email": "nope", "age": -1, "role": "admin" })).unwrap_err();
+/// let mut codes: Vec<_> = err.flatten().iter().map(|(_, leaf)| leaf.code.clone()).collect();
+/// codes.sort();
+/// assert_eq!(codes, vec!["INVALID_EMAIL", "TOO_SMALL"]);
+/// ```
+pub fn schema_from_json_schema(document: &Value) -> Result<BoxedSchema, SchemaLoadError> {
+    Loader { root: document, resolving: Vec::new() }.load(document, &[])
+}
+
+/// Keywords that carry no validation and are ignored when loading.
+const ANNOTATIONS: &[&str] = &[
+    "$schema", "$id", "$comment", "$defs", "definitions", "title", "description",
+    "default", "examples", "deprecated", "readOnly", "writeOnly",
+];
+
+const STRING_KEYWORDS: &[&str] = &["minLength", "maxLength", "pattern", "format"];
+const NUMBER_KEYWORDS: &[&str] = &["minimum", "maximum", "exclusiveMinimum", "exclusiveMaximum", "multipleOf"];
+const OBJECT_KEYWORDS: &[&str] = &["properties", "required", "additionalProperties"];
+const ARRAY_KEYWORDS: &[&str] = &["items", "prefixItems", "minItems", "maxItems"];
+
+struct Loader<'a> {
+    root: &'a Value,
+    resolving: Vec<String>,
+}
+
+impl<'a> Loader<'a> {
+    fn load(&mut self, schema: &'a Value, path: &[PathSegment]) -> Result<BoxedSchema, SchemaLoadError> {
+        let loaded = self.load_schema(schema, path)?;
+        Ok(match schema.get("default") {
+            Some(default) => loaded.default(default.clone()).boxed(),
+            None => loaded,
+        })
+    }
+
+    fn load_schema(&mut self, schema: &'a Value, path: &[PathSegment]) -> Result<BoxedSchema, SchemaLoadError> {
+        let keywords = match schema {
+            Value::Bool(true) => return Ok(AnyValueSchema.boxed()),
+            Value::Bool(false) => return Err(unsupported("false", path)),
+            Value::Object(keywords) => keywords,
+            _ => return Err(SchemaLoadError::InvalidSchema { path: location(path) }),
+        };
+
+        let validating: Vec<&str> = keywords.keys()
+            .map(String::as_str)
+            .filter(|keyword| !ANNOTATIONS.contains(keyword))
+            .collect();
+        if let Some(keyword) = validating.iter().find(|keyword| !is_supported(keyword)) {
+            return Err(unsupported(keyword, path));
+        }
+
+        if let Some(reference) = keywords.get("$ref") {
+            if let Some(keyword) = validating.iter().find(|keyword| **keyword != "$ref") {
+                return Err(unsupported(keyword, path));
+            }
+            return self.load_ref(reference, path);
+        }
+
+        if let Some(members) = keywords.get("anyOf") {
+            if let Some(keyword) = validating.iter().find(|keyword| **keyword != "anyOf") {
+                return Err(unsupported(keyword, path));
+            }
+            let members = match members {
+                Value::Array(members) if !members.is_empty() => members,
+                _ => return Err(invalid("anyOf", path, "expected a non-empty array of schemas")),
+            };
+            let members = members.iter().enumerate()
+                .map(|(index, member)| self.load(member, &child(path, &["anyOf".into(), index.into()])))
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(crate::schema().union(members).boxed());
+        }
+
+        if keywords.contains_key("enum") || keywords.contains_key("const") {
+            if let Some(keyword) = validating.iter().find(|keyword| !["enum", "const", "type"].contains(keyword)) {
+                return Err(unsupported(keyword, path));
+            }
+            return match (keywords.get("enum"), keywords.get("const")) {
+                (Some(_), Some(_)) => Err(unsupported("const", path)),
+                (Some(Value::Array(options)), None) => Ok(crate::schema().enumeration(options.clone()).boxed()),
+                (Some(_), None) => Err(invalid("enum", path, "expected an array")),
+                (None, Some(value)) => Ok(constant(value)),
+                (None, None) => unreachable!(),
+            };
+        }
+
+        let types: Vec<&str> = match keywords.get("type") {
+            None => match inferred_type(keywords) {
+                Some(ty) => vec![ty],
+                None if validating.is_empty() => return Ok(AnyValueSchema.boxed()),
+                None => return Err(invalid("type", path, "keywords of several types require an explicit 'type'")),
+            },
+            Some(Value::String(ty)) => vec![ty.as_str()],
+            Some(Value::Array(types)) if !types.is_empty() => types.iter()
+                .map(|ty| ty.as_str().ok_or_else(|| invalid("type", path, "expected type names")))
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err(invalid("type", path, "expected a type name or a non-empty array of type names")),
+        };
+
+        let mut members = types.iter()
+            .map(|ty| self.load_type(ty, keywords, path).map(|schema| (*ty, schema)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(match members.len() {
+            1 => members.remove(0).1,
+            2 if members.iter().any(|(ty, _)| *ty == "null") => {
+                let (_, schema) = members.into_iter().find(|(ty, _)| *ty != "null").unwrap();
+                schema.optional().boxed()
+            }
+            _ => crate::schema().union(members.into_iter().map(|(_, schema)| schema).collect()).boxed(),
+        })
+    }
+
+    fn load_ref(&mut self, reference: &'a Value, path: &[PathSegment]) -> Result<BoxedSchema, SchemaLoadError> {
+        let reference = reference.as_str().ok_or_else(|| invalid("$ref", path, "expected a string"))?;
+        let unresolved = || SchemaLoadError::UnresolvedRef {
+            reference: reference.to_string(),
+            path: location(path),
+        };
+
+        let pointer = reference.strip_prefix('#').ok_or_else(unresolved)?;
+        let target = self.root.pointer(pointer).ok_or_else(unresolved)?;
+        if self.resolving.iter().any(|resolving| resolving == reference) {
+            return Err(SchemaLoadError::RecursiveRef {
+                reference: reference.to_string(),
+                path: location(path),
+            });
+        }
+
+        let target_path: Vec<PathSegment> = pointer.split('/').skip(1)
+            .map(|segment| PathSegment::from(segment.replace("~1", "/").replace("~0", "~")))
+            .collect();
+        self.resolving.push(reference.to_string());
+        let schema = self.load(target, &target_path);
+        self.resolving.pop();
+        schema
+    }
+
+    fn load_type(&mut self, ty: &str, keywords: &'a Map<String, Value>, path: &[PathSegment]) -> Result<BoxedSchema, SchemaLoadError> {
+        let s = crate::schema();
+        match ty {
+            "string" => load_string(keywords, path),
+            "number" => {
+                let mut schema = s.number();
+                if let Some(min) = number(keywords, "minimum", path)? {
+                    schema = schema.min(min);
+                }
+                if let Some(min) = number(keywords, "exclusiveMinimum", path)? {
+                    schema = schema.gt(min);
+                }
+                if let Some(max) = number(keywords, "maximum", path)? {
+                    schema = schema.max(max);
+                }
+                if let Some(max) = number(keywords, "exclusiveMaximum", path)? {
+                    schema = schema.lt(max);
+                }
+                if let Some(step) = number(keywords, "multipleOf", path)? {
+                    if step <= 0.0 {
+                        return Err(invalid("multipleOf", path, "expected a number greater than 0"));
+                    }
+                    schema = schema.multiple_of(step);
+                }
+                Ok(schema.boxed())
+            }
+            "integer" => {
+                let mut schema = s.integer();
+                let min = [
+                    number(keywords, "minimum", path)?.map(f64::ceil),
+                    number(keywords, "exclusiveMinimum", path)?.map(|min| min.floor() + 1.0),
+                ];
+                if let Some(min) = min.into_iter().flatten().reduce(f64::max) {
+                    schema = schema.min(min as i64);
+                }
+                let max = [
+                    number(keywords, "maximum", path)?.map(f64::floor),
+                    number(keywords, "exclusiveMaximum", path)?.map(|max| max.ceil() - 1.0),
+                ];
+                if let Some(max) = max.into_iter().flatten().reduce(f64::min) {
+                    schema = schema.max(max as i64);
+                }
+                if let Some(step) = number(keywords, "multipleOf", path)? {
+                    if step.fract() != 0.0 || step <= 0.0 {
+                        return Err(invalid("multipleOf", path, "expected a positive integer for integer schemas"));
+                    }
+                    schema = schema.multiple_of(step as i64);
+                }
+                Ok(schema.boxed())
+            }
+            "boolean" => Ok(s.boolean().boxed()),
+            "null" => Ok(NullSchema.boxed()),
+            "object" => self.load_object(keywords, path),
+            "array" => self.load_array(keywords, path),
+            other => Err(invalid("type", path, &format!("unknown type '{}'", other))),
+        }
+    }
+
+    fn load_object(&mut self, keywords: &'a Map<String, Value>, path: &[PathSegment]) -> Result<BoxedSchema, SchemaLoadError> {
+        let s = crate::schema();
+
+        let required: Vec<&str> = match keywords.get("required") {
+            None => Vec::new(),
+            Some(Value::Array(names)) => names.iter()
+                .map(|name| name.as_str().ok_or_else(|| invalid("required", path, "expected property names")))
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err(invalid("required", path, "expected an array of property names")),
+        };
+
+        let properties = match keywords.get("properties") {
+            None => None,
+            Some(Value::Object(properties)) => Some(properties),
+            Some(_) => return Err(invalid("properties", path, "expected an object")),
+        };
+
+        let additional = keywords.get("additionalProperties");
+        if properties.is_none() && required.is_empty() && additional != Some(&Value::Bool(false)) {
+            // Without declared properties every key is kept
+            let value = match additional {
+                None => AnyValueSchema.boxed(),
+                Some(schema) => self.load(schema, &child(path, &["additionalProperties".into()]))?,
+            };
+            return Ok(s.record(value).boxed());
+        }
+
+        // Undeclared keys are allowed unless `additionalProperties` says otherwise
+        let mut schema = match additional {
+            None | Some(Value::Bool(true)) => s.object().passthrough(),
+            Some(Value::Bool(false)) => s.object().strict(),
+            Some(values) => s.object().catchall(self.load(values, &child(path, &["additionalProperties".into()]))?),
+        };
+        for (name, property) in properties.into_iter().flatten() {
+            let has_default = property.get("default").is_some();
+            let property = self.load(property, &child(path, &["properties".into(), name.as_str().into()]))?;
+            schema = if required.contains(&name.as_str()) || has_default {
+                schema.field(name, property)
+            } else {
+                schema.field(name, OptionalProperty(property.optional().boxed()))
+            };
+        }
+        for name in required {
+            if !properties.is_some_and(|properties| properties.contains_key(name)) {
+                schema = schema.field(name, AnyValueSchema);
+            }
+        }
+        Ok(schema.boxed())
+    }
+
+    fn load_array(&mut self, keywords: &'a Map<String, Value>, path: &[PathSegment]) -> Result<BoxedSchema, SchemaLoadError> {
+        let s = crate::schema();
+        let min_items = count(keywords, "minItems", path)?;
+        let max_items = count(keywords, "maxItems", path)?;
+
+        if let Some(prefix) = keywords.get("prefixItems") {
+            let prefix = prefix.as_array().ok_or_else(|| invalid("prefixItems", path, "expected an array of schemas"))?;
+            let closed = max_items == Some(prefix.len()) || keywords.get("items") == Some(&Value::Bool(false));
+            if min_items != Some(prefix.len()) || !closed {
+                // Tuples always have exactly as many elements as schemas
+                return Err(unsupported("prefixItems", path));
+            }
+            let items = prefix.iter().enumerate()
+                .map(|(index, item)| self.load(item, &child(path, &["prefixItems".into(), index.into()])))
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(s.tuple(items).boxed());
+        }
+
+        let mut schema = match keywords.get("items") {
+            None => s.array(AnyValueSchema.boxed()),
+            Some(Value::Bool(false)) => s.array(AnyValueSchema.boxed()).max_items(0),
+            Some(item) => s.array(self.load(item, &child(path, &["items".into()]))?),
+        };
+        if let Some(min_items) = min_items {
+            schema = schema.min_items(min_items);
+        }
+        if let Some(max_items) = max_items {
+            schema = schema.max_items(max_items);
+        }
+        Ok(schema.boxed())
+    }
+}
+
+fn load_string(keywords: &Map<String, Value>, path: &[PathSegment]) -> Result<BoxedSchema, SchemaLoadError> {
+    let mut schema = crate::schema().string();
+    if let Some(min_length) = count(keywords, "minLength", path)? {
+        schema = schema.min_length(min_length);
+    }
+    if let Some(max_length) = count(keywords, "maxLength", path)? {
+        schema = schema.max_length(max_length);
+    }
+
+    let format = match keywords.get("format") {
+        None => None,
+        Some(Value::String(format)) => match Pattern::from_format(format) {
+            Some(pattern) => Some(pattern),
+            None => return Err(SchemaLoadError::UnsupportedFormat {
+                format: format.clone(),
+                path: location(path),
+            }),
+        },
+        Some(_) => return Err(invalid("format", path, "expected a string")),
+    };
+
+    let pattern = match keywords.get("pattern") {
+        None => None,
+        Some(Value::String(pattern)) => match Pattern::from_regex(pattern) {
+            Some(predefined) => Some(Ok(predefined)),
+            None => match Regex::new(pattern) {
+                Ok(_) => Some(Err(pattern.as_str())),
+                Err(err) => return Err(invalid("pattern", path, &err.to_string())),
+            },
+        },
+        Some(_) => return Err(invalid("pattern", path, "expected a string")),
+    };
+
+    Ok(match (format, pattern) {
+        (Some(format), None) | (None, Some(Ok(format))) => with_pattern(schema, format).boxed(),
+        (Some(format), Some(Ok(pattern))) if format.regex().as_str() == pattern.regex().as_str() => {
+            with_pattern(schema, format).boxed()
+        }
+        // A string schema holds a single pattern
+        (Some(_), Some(_)) => return Err(unsupported("pattern", path)),
+        (None, Some(Err(pattern))) => schema.pattern(pattern).boxed(),
+        (None, None) => schema.boxed(),
+    })
+}
+
+fn with_pattern(schema: StringSchema, pattern: Pattern) -> StringSchema {
+    match pattern {
+        Pattern::Email => schema.email(),
+        Pattern::Url => schema.url(),
+        Pattern::Date => schema.date(),
+        Pattern::Time => schema.time(),
+        Pattern::Uuid => schema.uuid(),
+        Pattern::Ipv4 => schema.ipv4(),
+        Pattern::Phone => schema.phone(),
+        Pattern::Username => schema.username(),
+        Pattern::StrongPassword => schema.password(),
+    }
+}
+
+fn constant(value: &Value) -> BoxedSchema {
+    let s = crate::schema();
+    match value {
+        Value::Null => NullSchema.boxed(),
+        Value::Bool(b) => s.literal(*b).boxed(),
+        Value::String(string) => s.literal(string.clone()).boxed(),
+        Value::Number(n) => match (n.as_i64(), n.as_f64()) {
+            (Some(n), _) => s.literal(n).boxed(),
+            (None, Some(n)) if !n.is_nan() && n.fract() != 0.0 => s.literal(n).boxed(),
+            _ => s.enumeration([value.clone()]).boxed(),
+        },
+        _ => s.enumeration([value.clone()]).boxed(),
+    }
+}
+
+fn inferred_type(keywords: &Map<String, Value>) -> Option<&'static str> {
+    let families = [
+        ("string", STRING_KEYWORDS),
+        ("number", NUMBER_KEYWORDS),
+        ("object", OBJECT_KEYWORDS),
+        ("array", ARRAY_KEYWORDS),
+    ];
+    let mut present = families.iter()
+        .filter(|(_, family)| family.iter().any(|keyword| keywords.contains_key(*keyword)))
+        .map(|(ty, _)| *ty);
+    match (present.next(), present.next()) {
+        (Some(ty), None) => Some(ty),
+        _ => None,
+    }
+}
+
+fn is_supported(keyword: &str) -> bool {
+    ["type", "enum", "const", "anyOf", "$ref"].contains(&keyword)
+        || STRING_KEYWORDS.contains(&keyword)
+        || NUMBER_KEYWORDS.contains(&keyword)
+        || OBJECT_KEYWORDS.contains(&keyword)
+        || ARRAY_KEYWORDS.contains(&keyword)
+}
+
+fn number(keywords: &Map<String, Value>, keyword: &str, path: &[PathSegment]) -> Result<Option<f64>, SchemaLoadError> {
+    match keywords.get(keyword) {
+        None => Ok(None),
+        Some(value) => value.as_f64().map(Some).ok_or_else(|| invalid(keyword, path, "expected a number")),
+    }
+}
+
+fn count(keywords: &Map<String, Value>, keyword: &str, path: &[PathSegment]) -> Result<Option<usize>, SchemaLoadError> {
+    match keywords.get(keyword) {
+        None => Ok(None),
+        Some(value) => value.as_u64()
+            .map(|count| Some(count as usize))
+            .ok_or_else(|| invalid(keyword, path, "expected a non-negative integer")),
+    }
+}
+
+fn child(path: &[PathSegment], segments: &[PathSegment]) -> Vec<PathSegment> {
+    path.iter().chain(segments).cloned().collect()
+}
+
+fn location(path: &[PathSegment]) -> String {
+    format!("#{}", json_pointer(path))
+}
+
+fn unsupported(keyword: &str, path: &[PathSegment]) -> SchemaLoadError {
+    SchemaLoadError::UnsupportedKeyword {
+        keyword: keyword.to_string(),
+        path: location(path),
+    }
+}
+
+fn invalid(keyword: &str, path: &[PathSegment], message: &str) -> SchemaLoadError {
+    SchemaLoadError::InvalidKeyword {
+        keyword: keyword.to_string(),
+        path: location(path),
+        message: message.to_string(),
+    }
+}
+
+/// Accepts any value and produces it as JSON; loaded from `true` and `{}`.
+struct AnyValueSchema;
+
+impl Schema for AnyValueSchema {
+    type Output = Value;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        json::from_any(value)
+    }
+}
+
+/// A property that is not `required`: it accepts `null`, and an absent value
+/// is validated like `null`.
+struct OptionalProperty(BoxedSchema);
+
+impl Schema for OptionalProperty {
+    type Output = Box<dyn Any>;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        self.0.validate(value)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        self.0.to_json_schema()
+    }
+
+    fn validate_default(&self) -> Option<ValidationResult<Self::Output>> {
+        Some(self.0.validate(&None::<()>))
+    }
+
+    fn accepts_absent(&self) -> bool {
+        true
+    }
+
+    fn output_cloner(&self) -> fn(&dyn Any) -> Box<dyn Any> {
+        self.0.output_cloner()
+    }
+
+    fn boxed(self) -> BoxedSchema {
+        Box::new(self)
+    }
+}
+
+/// Accepts only `null`; loaded from `"type": "null"` and `"const": null`.
+struct NullSchema;
+
+impl Schema for NullSchema {
+    type Output = Value;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        match json::from_any(value) {
+            Ok(Value::Null) => Ok(Value::Null),
+            _ => Err(ValidationError::new(
+                ErrorType::Type {
+                    expected: "null",
+                    got: "a non-null value",
+                },
+                None,
+            )),
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        json!({ "type": "null" })
+    }
+}
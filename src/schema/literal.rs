@@ -3,6 +3,9 @@ use std::fmt::Debug;
 use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig};
 use crate::schema::Schema;
 use crate::schema::clone::CloneAny;
+use crate::schema::json::{self, type_name};
+use crate::schema::integer::native_integer;
+use serde_json::{json, Value};
 
 pub struct LiteralSchema<T: 'static + Clone + PartialEq + Debug + CloneAny> {
     value: T,
@@ -50,17 +53,42 @@ impl<T: 'static + Clone + PartialEq + Debug + CloneAny> LiteralSchema<T> {
     }
 }
 
+impl<T: 'static + Clone + PartialEq + Debug + CloneAny> LiteralSchema<T> {
+    /// Reads values whose Rust type differs from the literal's but that can
+    /// still match it, such as a `String` for a `&str` literal or an integer
+    /// from JSON for an `f64` literal.
+    fn convert(&self, value: &dyn Any) -> Option<T> {
+        let literal = &self.value as &dyn Any;
+        if let (Some(literal), Some(s)) = (literal.downcast_ref::<&'static str>(), value.downcast_ref::<String>()) {
+            return (literal == s).then(|| self.value.clone());
+        }
+        if literal.is::<f64>() {
+            let n = native_integer(value)? as f64;
+            return (&n as &dyn Any).downcast_ref::<T>().cloned();
+        }
+        if literal.is::<i64>() {
+            let n = i64::try_from(native_integer(value)?).ok()?;
+            return (&n as &dyn Any).downcast_ref::<T>().cloned();
+        }
+        None
+    }
+}
+
 impl<T: 'static + Clone + PartialEq + Debug + CloneAny> Schema for LiteralSchema<T> {
     type Output = T;
 
     fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let mut converted = None;
+        let value = json::resolve(value, &mut converted);
         let value = if let Some(v) = value.downcast_ref::<T>() {
             v.clone()
+        } else if let Some(v) = self.convert(value) {
+            v
         } else {
             return Err(ValidationError::new(
                 ErrorType::Literal {
                     expected: format!("{:?}", self.value),
-                    got: format!("{:?}", value),
+                    got: type_name(value).to_string(),
                 },
                 self.error_config.clone(),
             ));
@@ -78,12 +106,11 @@ impl<T: 'static + Clone + PartialEq + Debug + CloneAny> Schema for LiteralSchema
             ))
         }
     }
-}
 
-fn type_name(value: &dyn Any) -> &'static str {
-    if value.is::<String>() { "String" }
-    else if value.is::<i64>() { "Integer" }
-    else if value.is::<f64>() { "Float" }
-    else if value.is::<bool>() { "Boolean" }
-    else { "Unknown" }
-}
\ No newline at end of file
+    fn to_json_schema(&self) -> Value {
+        match json::from_any(&self.value) {
+            Ok(value) => json!({ "const": value }),
+            Err(_) => json!({}),
+        }
+    }
+}
@@ -1,6 +1,15 @@
 use std::any::Any;
 use std::collections::HashMap;
-use crate::error::ValidationResult;
+use crate::error::{ValidationError, ValidationResult, ErrorType};
+use crate::schema::Schema;
+use crate::schema::string::StringSchema;
+use crate::schema::number::NumberSchema;
+use crate::schema::integer::IntegerSchema;
+use crate::schema::boolean::BooleanSchema;
+use crate::schema::optional::OptionalSchema;
+use crate::schema::array::ArraySchema;
+use crate::schema::record::RecordSchema;
+use crate::schema::integer::native_integer;
 
 /// A trait for types that can be created from validated object fields.
 ///
@@ -8,6 +17,7 @@ use crate::error::ValidationResult;
 ///
 /// ```
 /// use schema_validator::{schema, Schema, FromFields, ValidateAs, ValidationResult};
+/// use schema_validator::schema::mapping::field;
 /// use std::collections::HashMap;
 /// use std::any::Any;
 ///
@@ -18,10 +28,10 @@ use crate::error::ValidationResult;
 /// }
 ///
 /// impl FromFields for User {
-///     fn from_fields(fields: &HashMap<String, Box<dyn Any>>) -> Option<Self> {
-///         Some(User {
-///             name: fields.get("name")?.downcast_ref::<String>()?.clone(),
-///             age: *fields.get("age")?.downcast_ref::<f64>()?,
+///     fn from_fields(fields: &HashMap<String, Box<dyn Any>>) -> ValidationResult<Self> {
+///         Ok(User {
+///             name: field(fields, "name")?,
+///             age: field(fields, "age")?,
 ///         })
 ///     }
 /// }
@@ -51,9 +61,11 @@ pub trait FromFields: Sized {
     ///
     /// # Returns
     ///
-    /// Returns `Some(Self)` if all required fields are present and have the correct types,
-    /// or `None` if any field is missing or has an incorrect type.
-    fn from_fields(fields: &HashMap<String, Box<dyn Any>>) -> Option<Self>;
+    /// Returns `Ok(Self)` if all required fields are present and have the correct types.
+    /// Otherwise the error names the offending field in its path; a field of the
+    /// wrong type is reported as `ErrorType::FieldType` with the expected and the
+    /// produced Rust type.
+    fn from_fields(fields: &HashMap<String, Box<dyn Any>>) -> ValidationResult<Self>;
 }
 
 /// Extension trait for Schema to add validation with direct struct mapping.
@@ -75,25 +87,17 @@ pub trait ValidateAs {
     /// # Examples
     ///
     /// ```
-    /// use schema_validator::{schema, Schema, FromFields, ValidateAs, ValidationResult};
+    /// use schema_validator::{schema, Schema, ValidateAs, Validate};
+    /// use schema_validator::error::ErrorType;
     /// use std::collections::HashMap;
     /// use std::any::Any;
     ///
-    /// #[derive(Debug, PartialEq)]
+    /// #[derive(Debug, Clone, PartialEq, Validate)]
     /// struct User {
     ///     name: String,
     ///     age: f64,
     /// }
     ///
-    /// impl FromFields for User {
-    ///     fn from_fields(fields: &HashMap<String, Box<dyn Any>>) -> Option<Self> {
-    ///         Some(User {
-    ///             name: fields.get("name")?.downcast_ref::<String>()?.clone(),
-    ///             age: *fields.get("age")?.downcast_ref::<f64>()?,
-    ///         })
-    ///     }
-    /// }
-    ///
     /// let s = schema();
     /// let schema = s.object()
     ///     .field("name", s.string())
@@ -103,8 +107,336 @@ pub trait ValidateAs {
     /// obj.insert("name".to_string(), Box::new("John".to_string()) as Box<dyn Any>);
     /// obj.insert("age".to_string(), Box::new(30.0) as Box<dyn Any>);
     ///
-    /// let user: ValidationResult<User> = schema.validate_as(&obj);
-    /// assert_eq!(user.unwrap(), User { name: "John".to_string(), age: 30.0 });
+    /// let user: User = schema.validate_as(&obj).unwrap();
+    /// assert_eq!(user, User { name: "John".to_string(), age: 30.0 });
+    ///
+    /// // The schema produces a bool where the struct expects an f64
+    /// let schema = s.object()
+    ///     .field("name", s.string())
+    ///     .field("age", s.boolean());
+    ///
+    /// let mut obj = HashMap::new();
+    /// obj.insert("name".to_string(), Box::new("John".to_string()) as Box<dyn Any>);
+    /// obj.insert("age".to_string(), Box::new(true) as Box<dyn Any>);
+    ///
+    /// let err = schema.validate_as::<User>(&obj).unwrap_err();
+    /// assert_eq!(err.code, "FIELD_TYPE_ERROR");
+    /// assert_eq!(err.dotted_path(), "age");
+    /// if let ErrorType::FieldType { field, expected, got } = &err.error_type {
+    ///     assert_eq!(field, "age");
+    ///     assert_eq!(*expected, "f64");
+    ///     assert_eq!(*got, "bool");
+    /// }
     /// ```
     fn validate_as<T: FromFields>(&self, value: &dyn Any) -> ValidationResult<T>;
-}
\ No newline at end of file
+}
+
+/// Types that can be read back out of the type-erased output of a schema.
+///
+/// Hand-built object schemas produce nested objects as
+/// `HashMap<String, Box<dyn Any>>`, tuples as `Vec<Box<dyn Any>>`, arrays of
+/// them as `Vec<...>` and optional values as `Option<...>`; this trait
+/// converts such values into the typed fields of a struct. It is implemented
+/// for strings, numbers, booleans, `Option<T>`, `Vec<T>` and
+/// `HashMap<String, T>`, and by `#[derive(Validate)]`.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema, FromAny, Validate};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Clone, PartialEq, Validate)]
+/// struct Address {
+///     city: String,
+/// }
+///
+/// let s = schema();
+/// let schema = s.array(s.object().field("city", s.string()));
+/// let validated = schema.validate(&json!([{ "city": "Berlin" }])).unwrap();
+///
+/// let addresses = Vec::<Address>::from_any(&validated).unwrap();
+/// assert_eq!(addresses, vec![Address { city: "Berlin".to_string() }]);
+/// ```
+pub trait FromAny: Sized {
+    /// Converts `value`, failing with [`type_mismatch`] if it has an
+    /// incompatible type.
+    fn from_any(value: &dyn Any) -> ValidationResult<Self>;
+}
+
+impl FromAny for String {
+    fn from_any(value: &dyn Any) -> ValidationResult<Self> {
+        value.downcast_ref::<String>().cloned().ok_or_else(|| type_mismatch::<Self>(value))
+    }
+}
+
+impl FromAny for bool {
+    fn from_any(value: &dyn Any) -> ValidationResult<Self> {
+        value.downcast_ref::<bool>().copied().ok_or_else(|| type_mismatch::<Self>(value))
+    }
+}
+
+impl FromAny for f64 {
+    fn from_any(value: &dyn Any) -> ValidationResult<Self> {
+        value.downcast_ref::<f64>().copied()
+            .or_else(|| native_integer(value).map(|n| n as f64))
+            .ok_or_else(|| type_mismatch::<Self>(value))
+    }
+}
+
+macro_rules! impl_from_any_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl FromAny for $ty {
+                fn from_any(value: &dyn Any) -> ValidationResult<Self> {
+                    native_integer(value)
+                        .and_then(|n| <$ty>::try_from(n).ok())
+                        .ok_or_else(|| type_mismatch::<Self>(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_any_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl<T: FromAny + Clone + 'static> FromAny for Option<T> {
+    fn from_any(value: &dyn Any) -> ValidationResult<Self> {
+        if let Some(value) = value.downcast_ref::<Option<T>>() {
+            Ok(value.clone())
+        } else if let Some(value) = value.downcast_ref::<Option<Box<dyn Any>>>() {
+            optional(value.as_deref())
+        } else if let Some(value) = value.downcast_ref::<Option<HashMap<String, Box<dyn Any>>>>() {
+            optional(value.as_ref().map(|value| value as &dyn Any))
+        } else if let Some(value) = value.downcast_ref::<Option<Vec<Box<dyn Any>>>>() {
+            optional(value.as_ref().map(|value| value as &dyn Any))
+        } else if let Some(None) = value.downcast_ref::<Option<()>>() {
+            Ok(None)
+        } else {
+            T::from_any(value).map(Some)
+        }
+    }
+}
+
+fn optional<T: FromAny>(value: Option<&dyn Any>) -> ValidationResult<Option<T>> {
+    match value {
+        None => Ok(None),
+        Some(value) => T::from_any(value).map(Some),
+    }
+}
+
+impl<T: FromAny + Clone + 'static> FromAny for Vec<T> {
+    fn from_any(value: &dyn Any) -> ValidationResult<Self> {
+        if let Some(items) = value.downcast_ref::<Vec<T>>() {
+            Ok(items.clone())
+        } else if let Some(items) = value.downcast_ref::<Vec<Box<dyn Any>>>() {
+            items.iter()
+                .enumerate()
+                .map(|(index, item)| T::from_any(item.as_ref()).map_err(|err| err.with_path_prefix(index)))
+                .collect()
+        } else if let Some(items) = value.downcast_ref::<Vec<HashMap<String, Box<dyn Any>>>>() {
+            elements(items)
+        } else if let Some(items) = value.downcast_ref::<Vec<Vec<Box<dyn Any>>>>() {
+            elements(items)
+        } else {
+            Err(type_mismatch::<Self>(value))
+        }
+    }
+}
+
+fn elements<T: FromAny, I: Any>(items: &[I]) -> ValidationResult<Vec<T>> {
+    items.iter()
+        .enumerate()
+        .map(|(index, item)| T::from_any(item).map_err(|err| err.with_path_prefix(index)))
+        .collect()
+}
+
+impl<T: FromAny + Clone + 'static> FromAny for HashMap<String, T> {
+    fn from_any(value: &dyn Any) -> ValidationResult<Self> {
+        if let Some(map) = value.downcast_ref::<HashMap<String, T>>() {
+            Ok(map.clone())
+        } else if let Some(map) = value.downcast_ref::<HashMap<String, Box<dyn Any>>>() {
+            map.iter()
+                .map(|(key, value)| {
+                    T::from_any(value.as_ref())
+                        .map(|value| (key.clone(), value))
+                        .map_err(|err| err.with_path_prefix(key.as_str()))
+                })
+                .collect()
+        } else {
+            Err(type_mismatch::<Self>(value))
+        }
+    }
+}
+
+/// Reads a validated field out of an object schema's output.
+///
+/// Used by `FromFields` implementations, including the ones generated by
+/// `#[derive(Validate)]`. A missing field is reported as `ErrorType::Missing`
+/// and a value of another type as `ErrorType::FieldType`, both at the field's
+/// path.
+pub fn field<T: FromAny>(fields: &HashMap<String, Box<dyn Any>>, name: &str) -> ValidationResult<T> {
+    let value = fields.get(name).ok_or_else(|| ValidationError::new(
+        ErrorType::Missing { field: name.to_string() },
+        None,
+    ).with_path_prefix(name))?;
+
+    T::from_any(value.as_ref()).map_err(|err| {
+        let err = match err.error_type {
+            // A mismatch of the field itself rather than of a nested value
+            ErrorType::Type { expected, got } if err.path.is_empty() => ValidationError::new(
+                ErrorType::FieldType { field: name.to_string(), expected, got },
+                None,
+            ),
+            _ => err,
+        };
+        err.with_path_prefix(name)
+    })
+}
+
+/// Reads a validated element out of a tuple schema's output.
+///
+/// The positional counterpart of [`field`], used for tuple structs and tuple
+/// variants; errors are reported at the element's index.
+pub fn element<T: FromAny>(items: &[Box<dyn Any>], index: usize) -> ValidationResult<T> {
+    let value = items.get(index).ok_or_else(|| ValidationError::new(
+        ErrorType::MinItems { min: index + 1, got: items.len() },
+        None,
+    ))?;
+
+    T::from_any(value.as_ref()).map_err(|err| err.with_path_prefix(index))
+}
+
+/// Creates the error for a value that cannot be converted into `T`, naming
+/// both Rust types.
+///
+/// Used by `FromAny` implementations, including the ones generated by
+/// `#[derive(Validate)]`.
+pub fn type_mismatch<T: 'static>(value: &dyn Any) -> ValidationError {
+    ValidationError::new(
+        ErrorType::Type {
+            expected: std::any::type_name::<T>(),
+            got: produced_type(value),
+        },
+        None,
+    )
+}
+
+/// Names the Rust type of a value produced by the built-in schemas.
+fn produced_type(value: &dyn Any) -> &'static str {
+    macro_rules! check {
+        ($($ty:ty),*) => {
+            $(
+                if value.is::<$ty>() {
+                    return std::any::type_name::<$ty>();
+                }
+            )*
+        };
+    }
+
+    check!(
+        String, &str, f64, bool, i8, i16, i32, i64, u8, u16, u32, u64,
+        Option<()>, Option<String>, Option<f64>, Option<bool>, Option<i64>, Option<Box<dyn Any>>,
+        Option<HashMap<String, Box<dyn Any>>>, Option<Vec<Box<dyn Any>>>,
+        Vec<String>, Vec<f64>, Vec<bool>, Vec<i64>, Vec<Box<dyn Any>>,
+        Vec<HashMap<String, Box<dyn Any>>>, Vec<Vec<Box<dyn Any>>>,
+        HashMap<String, Box<dyn Any>>, serde_json::Value,
+        crate::schema::union::UnionValue
+    );
+    "an unknown type"
+}
+
+/// Types with a canonical schema producing values of the type itself.
+///
+/// Implemented for strings, numbers, booleans, `Option<T>`, `Vec<T>` and
+/// `HashMap<String, T>`, and by `#[derive(Validate)]`. The derive uses the
+/// schemas of the field types to build the schema of a struct or enum.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{Schema, Validate};
+///
+/// #[derive(Debug, Clone, PartialEq, Validate)]
+/// enum Status {
+///     #[validate(rename = "active")]
+///     Active,
+///     #[validate(rename = "suspended")]
+///     Suspended,
+/// }
+///
+/// let schema = <Vec<Status> as Validate>::schema();
+/// let statuses = schema.validate(&serde_json::json!(["active", "suspended"])).unwrap();
+/// assert_eq!(statuses, vec![Status::Active, Status::Suspended]);
+/// ```
+pub trait Validate: Sized + 'static {
+    /// The schema returned by [`Validate::schema`].
+    type Schema: Schema<Output = Self> + 'static;
+
+    /// Returns the schema validating values of this type.
+    fn schema() -> Self::Schema;
+}
+
+impl Validate for String {
+    type Schema = StringSchema;
+
+    fn schema() -> Self::Schema {
+        StringSchema::new(false, true)
+    }
+}
+
+impl Validate for f64 {
+    type Schema = NumberSchema;
+
+    fn schema() -> Self::Schema {
+        NumberSchema::new(false)
+    }
+}
+
+impl Validate for bool {
+    type Schema = BooleanSchema;
+
+    fn schema() -> Self::Schema {
+        BooleanSchema::new(false)
+    }
+}
+
+macro_rules! impl_validate_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Validate for $ty {
+                type Schema = IntegerSchema<$ty>;
+
+                fn schema() -> Self::Schema {
+                    IntegerSchema::new(false, true)
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl<T: Validate> Validate for Option<T> {
+    type Schema = OptionalSchema<T::Schema>;
+
+    fn schema() -> Self::Schema {
+        OptionalSchema::new(T::schema())
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    type Schema = ArraySchema<T::Schema>;
+
+    fn schema() -> Self::Schema {
+        ArraySchema::new(T::schema())
+    }
+}
+
+impl<T: Validate> Validate for HashMap<String, T> {
+    type Schema = RecordSchema<T::Schema>;
+
+    fn schema() -> Self::Schema {
+        RecordSchema::new(T::schema())
+    }
+}
@@ -4,6 +4,7 @@ use crate::schema::clone::CloneAny;
 
 pub mod string;
 pub mod number;
+pub mod integer;
 pub mod boolean;
 pub mod object;
 pub mod optional;
@@ -11,6 +12,19 @@ pub mod clone;
 pub mod mapping;
 pub mod patterns;
 pub mod literal;
+pub mod array;
+pub mod record;
+pub mod tuple;
+pub mod union;
+pub mod enumeration;
+pub mod tagged;
+pub mod json_schema;
+pub mod annotated;
+pub mod default;
+pub mod typescript;
+pub mod openapi;
+pub(crate) mod json;
+pub(crate) mod refine;
 
 /// A schema for validating values.
 ///
@@ -95,4 +109,310 @@ pub trait Schema {
     {
         optional::OptionalSchema::new(self)
     }
+
+    /// Validates a value and returns the validated output as JSON.
+    ///
+    /// The result is the cleaned document: transformations and coercions are
+    /// applied, and nested objects, arrays and nulls are preserved. Outputs
+    /// that have no JSON representation, such as custom types produced by a
+    /// `transform` or NaN, fail with a type error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.array(s.object()
+    ///     .field("name", s.string().trim())
+    ///     .field("score", s.coerce().number()));
+    ///
+    /// let cleaned = schema.validate_to_json(&json!([{ "name": " Ann ", "score": "9.5" }])).unwrap();
+    /// assert_eq!(cleaned, json!([{ "name": "Ann", "score": 9.5 }]));
+    /// ```
+    fn validate_to_json(&self, value: &dyn Any) -> ValidationResult<serde_json::Value> {
+        let output = self.validate(value)?;
+        json::from_any(&output)
+    }
+
+    /// Describes the values accepted by this schema as a JSON Schema
+    /// (draft 2020-12).
+    ///
+    /// The result is a fragment without a `$schema` keyword, so it can be
+    /// embedded in other documents; use [`json_schema::document`] for a
+    /// standalone document. Coercions, transformations and custom error
+    /// messages have no JSON Schema equivalent and are not represented.
+    /// Schemas that do not override this method accept any value (`{}`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.array(s.string().min_length(2)).max_items(10);
+    ///
+    /// assert_eq!(schema.to_json_schema(), json!({
+    ///     "type": "array",
+    ///     "items": { "type": "string", "minLength": 2 },
+    ///     "maxItems": 10
+    /// }));
+    /// ```
+    fn to_json_schema(&self) -> serde_json::Value {
+        serde_json::json!({})
+    }
+
+    /// Validates the default of this schema, for an input that is absent.
+    ///
+    /// Returns `None` for schemas without a default, which is every schema
+    /// except those created with [`Schema::default`] and
+    /// [`Schema::default_with`] and the wrappers around them.
+    fn validate_default(&self) -> Option<ValidationResult<Self::Output>> {
+        None
+    }
+
+    /// Returns `true` if objects may omit a field validated by this schema.
+    ///
+    /// This is decided by the structure of the schema, without validating
+    /// anything: it holds for schemas with a default and the wrappers around
+    /// them. Such fields are not listed in `required` when exported.
+    fn accepts_absent(&self) -> bool {
+        false
+    }
+
+    /// Returns a function cloning outputs of this schema seen as `&dyn Any`.
+    ///
+    /// Type-erased schemas such as [`BoxedSchema`] return the function of the
+    /// schema they wrap, so their outputs are cloned with their real type.
+    #[doc(hidden)]
+    fn output_cloner(&self) -> fn(&dyn Any) -> Box<dyn Any> {
+        clone::clone_as::<Self::Output>
+    }
+
+    /// Substitutes `value` when the input is absent, such as a missing field
+    /// of an object.
+    ///
+    /// The default is validated like any other input. See
+    /// [`default::DefaultSchema`], which can also replace `null`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.object().field("tags", s.array(s.string()).default(json!(["new"])));
+    ///
+    /// let cleaned = schema.validate_to_json(&json!({})).unwrap();
+    /// assert_eq!(cleaned, json!({ "tags": ["new"] }));
+    /// ```
+    fn default<V: Into<serde_json::Value>>(self, value: V) -> default::DefaultSchema<Self>
+    where
+        Self: Sized,
+    {
+        let value = value.into();
+        default::DefaultSchema::new(self, move || value.clone())
+    }
+
+    /// Substitutes the value returned by `f` when the input is absent, calling
+    /// it for every absent input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.object().field("created", s.string().date().default_with(|| "2024-01-01"));
+    ///
+    /// let fields = schema.validate(&json!({})).unwrap();
+    /// assert_eq!(fields["created"].downcast_ref::<String>().unwrap(), "2024-01-01");
+    /// ```
+    fn default_with<F, V>(self, f: F) -> default::DefaultSchema<Self>
+    where
+        Self: Sized,
+        F: Fn() -> V + 'static,
+        V: Into<serde_json::Value>,
+    {
+        default::DefaultSchema::new(self, move || f().into())
+    }
+
+    /// Attaches a title, exported as the JSON Schema `title`.
+    ///
+    /// Validation is unchanged. [`openapi::OpenApi`] links schemas to the
+    /// component named by their title.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.object().field("city", s.string()).title("Address");
+    ///
+    /// assert_eq!(schema.to_json_schema()["title"], "Address");
+    /// ```
+    fn title(self, title: &str) -> annotated::AnnotatedSchema<Self>
+    where
+        Self: Sized,
+    {
+        annotated::AnnotatedSchema::new(self).title(title)
+    }
+
+    /// Attaches a description, exported as the JSON Schema `description`.
+    ///
+    /// Validation is unchanged. Call it after the other methods of the
+    /// schema, since the result is an [`annotated::AnnotatedSchema`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.integer().min(0).describe("Age in years");
+    ///
+    /// assert_eq!(schema.validate(&30_i64).unwrap(), 30);
+    /// assert_eq!(schema.to_json_schema()["description"], "Age in years");
+    /// ```
+    fn describe(self, description: &str) -> annotated::AnnotatedSchema<Self>
+    where
+        Self: Sized,
+    {
+        annotated::AnnotatedSchema::new(self).describe(description)
+    }
+
+    /// Attaches an example value, exported in the JSON Schema `examples`.
+    ///
+    /// Validation is unchanged, and the example is not validated either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.string().uuid()
+    ///     .example(json!("123e4567-e89b-42d3-a456-426614174000"));
+    ///
+    /// assert_eq!(schema.to_json_schema()["examples"], json!(["123e4567-e89b-42d3-a456-426614174000"]));
+    /// ```
+    fn example(self, example: serde_json::Value) -> annotated::AnnotatedSchema<Self>
+    where
+        Self: Sized,
+    {
+        annotated::AnnotatedSchema::new(self).example(example)
+    }
+
+    /// Erases the output type of this schema so it can be stored next to
+    /// schemas of other types.
+    ///
+    /// The validated value is returned as a `Box<dyn Any>` holding `Self::Output`.
+    /// Boxing a [`BoxedSchema`] again returns it unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use schema_validator::schema::BoxedSchema;
+    ///
+    /// let s = schema();
+    /// let schemas: Vec<BoxedSchema> = vec![s.string().boxed(), s.integer().boxed()];
+    ///
+    /// let value = schemas[1].validate(&42_i64).unwrap();
+    /// assert_eq!(value.downcast_ref::<i64>(), Some(&42));
+    /// ```
+    fn boxed(self) -> BoxedSchema
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(AnySchema { schema: self })
+    }
+
+    /// Accepts values matching either this schema or `other`.
+    ///
+    /// Chaining `or` on a union adds another member instead of nesting unions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.string().or(s.integer()).or(s.boolean());
+    ///
+    /// assert_eq!(schema.validate(&"abc".to_string()).unwrap().index(), 0);
+    /// assert_eq!(schema.validate(&42_i64).unwrap().downcast_ref::<i64>(), Some(&42));
+    /// assert!(schema.validate(&1.5).is_err());
+    /// ```
+    fn or<S: Schema + 'static>(self, other: S) -> union::UnionSchema
+    where
+        Self: Sized + 'static,
+    {
+        union::UnionSchema::new(vec![self.boxed(), other.boxed()])
+    }
+}
+
+/// A schema whose output type has been erased with [`Schema::boxed`].
+pub type BoxedSchema = Box<dyn Schema<Output = Box<dyn Any>>>;
+
+impl Schema for BoxedSchema {
+    type Output = Box<dyn Any>;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        (**self).validate(value)
+    }
+
+    fn to_json_schema(&self) -> serde_json::Value {
+        (**self).to_json_schema()
+    }
+
+    fn validate_default(&self) -> Option<ValidationResult<Self::Output>> {
+        (**self).validate_default()
+    }
+
+    fn accepts_absent(&self) -> bool {
+        (**self).accepts_absent()
+    }
+
+    fn output_cloner(&self) -> fn(&dyn Any) -> Box<dyn Any> {
+        (**self).output_cloner()
+    }
+
+    fn boxed(self) -> BoxedSchema {
+        self
+    }
+}
+
+struct AnySchema<S> {
+    schema: S,
+}
+
+impl<S: Schema> Schema for AnySchema<S> {
+    type Output = Box<dyn Any>;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        self.schema.validate(value).map(|v| Box::new(v) as Box<dyn Any>)
+    }
+
+    fn to_json_schema(&self) -> serde_json::Value {
+        self.schema.to_json_schema()
+    }
+
+    fn validate_default(&self) -> Option<ValidationResult<Self::Output>> {
+        self.schema.validate_default().map(|result| result.map(|v| Box::new(v) as Box<dyn Any>))
+    }
+
+    fn accepts_absent(&self) -> bool {
+        self.schema.accepts_absent()
+    }
+
+    fn output_cloner(&self) -> fn(&dyn Any) -> Box<dyn Any> {
+        self.schema.output_cloner()
+    }
 }
\ No newline at end of file
@@ -1,21 +1,45 @@
 use std::any::Any;
 use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig};
 use crate::schema::Schema;
+use crate::schema::json::{self, type_name};
+use crate::schema::refine::{self, Refinement};
+use crate::schema::integer::native_integer;
+use serde_json::{json, Map, Value};
 
 type TransformFn = Box<dyn Fn(f64) -> f64>;
 
 pub struct NumberSchema {
     coerce: bool,
+    abort_early: bool,
     error_config: Option<ErrorConfig>,
     transforms: Vec<TransformFn>,
+    min: Option<Bound>,
+    max: Option<Bound>,
+    multiple_of: Option<f64>,
+    finite: bool,
+    integer: bool,
+    refinements: Vec<Refinement<f64>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bound {
+    value: f64,
+    inclusive: bool,
 }
 
 impl std::fmt::Debug for NumberSchema {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("NumberSchema")
             .field("coerce", &self.coerce)
+            .field("abort_early", &self.abort_early)
             .field("error_config", &self.error_config)
             .field("transforms_count", &self.transforms.len())
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("multiple_of", &self.multiple_of)
+            .field("finite", &self.finite)
+            .field("integer", &self.integer)
+            .field("refinements_count", &self.refinements.len())
             .finish()
     }
 }
@@ -24,8 +48,15 @@ impl NumberSchema {
     pub fn new(coerce: bool) -> Self {
         NumberSchema {
             coerce,
+            abort_early: true,
             error_config: None,
             transforms: Vec::new(),
+            min: None,
+            max: None,
+            multiple_of: None,
+            finite: false,
+            integer: false,
+            refinements: Vec::new(),
         }
     }
 
@@ -49,6 +80,258 @@ impl NumberSchema {
         self
     }
 
+    /// Requires the number to be greater than or equal to `min`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.number().min(0.0).max(150.0);
+    ///
+    /// assert!(schema.validate(&0.0).is_ok());
+    /// assert_eq!(schema.validate(&-1.0).unwrap_err().code, "TOO_SMALL");
+    /// assert_eq!(schema.validate(&151.0).unwrap_err().code, "TOO_LARGE");
+    /// ```
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(Bound { value: min, inclusive: true });
+        self
+    }
+
+    /// Requires the number to be less than or equal to `max`.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(Bound { value: max, inclusive: true });
+        self
+    }
+
+    /// Requires the number to be strictly greater than `min`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.number().gt(0.0).lt(1.0);
+    ///
+    /// assert!(schema.validate(&0.5).is_ok());
+    /// assert!(schema.validate(&0.0).is_err());
+    /// assert!(schema.validate(&1.0).is_err());
+    /// ```
+    pub fn gt(mut self, min: f64) -> Self {
+        self.min = Some(Bound { value: min, inclusive: false });
+        self
+    }
+
+    /// Requires the number to be strictly less than `max`.
+    pub fn lt(mut self, max: f64) -> Self {
+        self.max = Some(Bound { value: max, inclusive: false });
+        self
+    }
+
+    /// Requires the number to be greater than zero.
+    pub fn positive(self) -> Self {
+        self.gt(0.0)
+    }
+
+    /// Requires the number to be less than zero.
+    pub fn negative(self) -> Self {
+        self.lt(0.0)
+    }
+
+    /// Requires the number to be greater than or equal to zero.
+    pub fn nonnegative(self) -> Self {
+        self.min(0.0)
+    }
+
+    /// Requires the number to be less than or equal to zero.
+    pub fn nonpositive(self) -> Self {
+        self.max(0.0)
+    }
+
+    /// Requires the number to be a multiple of `step`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.number().multiple_of(0.25);
+    ///
+    /// assert!(schema.validate(&1.75).is_ok());
+    /// assert_eq!(schema.validate(&1.3).unwrap_err().code, "NOT_MULTIPLE_OF");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not a finite number greater than zero, since no
+    /// value would be a multiple of it. The `multiple_of` derive option
+    /// rejects such steps at compile time instead:
+    ///
+    /// ```compile_fail
+    /// use schema_validator::Validate;
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Validate)]
+    /// struct Price {
+    ///     #[validate(multiple_of = 0)]
+    ///     amount: f64,
+    /// }
+    /// ```
+    pub fn multiple_of(mut self, step: f64) -> Self {
+        assert!(step > 0.0 && step.is_finite(), "multiple_of requires a positive step, got {}", step);
+        self.multiple_of = Some(step);
+        self
+    }
+
+    /// Rejects `NaN` and infinite values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.number().finite();
+    ///
+    /// assert!(schema.validate(&1.0).is_ok());
+    /// assert_eq!(schema.validate(&f64::NAN).unwrap_err().code, "NOT_FINITE");
+    /// assert!(schema.validate(&f64::INFINITY).is_err());
+    /// ```
+    pub fn finite(mut self) -> Self {
+        self.finite = true;
+        self
+    }
+
+    /// Requires the number to have no fractional part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.number().int();
+    ///
+    /// assert!(schema.validate(&3.0).is_ok());
+    /// assert_eq!(schema.validate(&3.5).unwrap_err().code, "NOT_INTEGER");
+    /// ```
+    pub fn int(mut self) -> Self {
+        self.integer = true;
+        self
+    }
+
+    /// Adds a rule the number must satisfy, failing with an
+    /// `ErrorType::Custom` error with `code` and `message` otherwise.
+    /// Refinements run once the number passed the other constraints.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.number()
+    ///     .int()
+    ///     .max(65535.0)
+    ///     .refine(|port| *port >= 1024.0 || [80.0, 443.0].contains(port), "RESERVED_PORT", "Port is reserved");
+    ///
+    /// assert!(schema.validate(&8080.0).is_ok());
+    /// assert!(schema.validate(&443.0).is_ok());
+    /// assert_eq!(schema.validate(&22.0).unwrap_err().code, "RESERVED_PORT");
+    /// ```
+    pub fn refine<F, C, M>(mut self, f: F, code: C, message: M) -> Self
+    where
+        F: Fn(&f64) -> bool + 'static,
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.refinements.push(refine::predicate(f, code.into(), message.into()));
+        self
+    }
+
+    /// Adds a rule the number must satisfy, failing with the error returned
+    /// by `f`.
+    pub fn try_refine<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&f64) -> ValidationResult<()> + 'static,
+    {
+        self.refinements.push(Box::new(f));
+        self
+    }
+
+    /// Sets whether validation stops at the first failing constraint.
+    ///
+    /// With `abort_early(false)` every constraint is evaluated and multiple
+    /// failures are aggregated into an `ErrorType::Multiple` error.
+    pub fn abort_early(mut self, abort_early: bool) -> Self {
+        self.abort_early = abort_early;
+        self
+    }
+
+    fn check_constraints(&self, value: f64) -> ValidationResult<()> {
+        let mut errors = Vec::new();
+
+        // Check finiteness first, the remaining checks are meaningless for NaN
+        if self.finite && !value.is_finite() {
+            errors.push(ValidationError::new(
+                ErrorType::NotFinite { got: value },
+                self.error_config.clone(),
+            ));
+        }
+
+        if self.integer && value.fract() != 0.0 && (errors.is_empty() || !self.abort_early) {
+            errors.push(ValidationError::new(
+                ErrorType::NotInteger { got: value },
+                self.error_config.clone(),
+            ));
+        }
+
+        if let Some(min) = self.min {
+            // NaN compares false with everything, so it would pass both bounds
+            let too_small = value.is_nan() || if min.inclusive { value < min.value } else { value <= min.value };
+            if too_small && (errors.is_empty() || !self.abort_early) {
+                errors.push(ValidationError::new(
+                    ErrorType::TooSmall {
+                        min: min.value,
+                        inclusive: min.inclusive,
+                        got: value,
+                    },
+                    self.error_config.clone(),
+                ));
+            }
+        }
+
+        if let Some(max) = self.max {
+            let too_large = value.is_nan() || if max.inclusive { value > max.value } else { value >= max.value };
+            if too_large && (errors.is_empty() || !self.abort_early) {
+                errors.push(ValidationError::new(
+                    ErrorType::TooLarge {
+                        max: max.value,
+                        inclusive: max.inclusive,
+                        got: value,
+                    },
+                    self.error_config.clone(),
+                ));
+            }
+        }
+
+        if let Some(step) = self.multiple_of {
+            if !is_multiple_of(value, step) && (errors.is_empty() || !self.abort_early) {
+                errors.push(ValidationError::new(
+                    ErrorType::NotMultipleOf { step, got: value },
+                    self.error_config.clone(),
+                ));
+            }
+        }
+
+        match ValidationError::from_errors(errors, self.error_config.clone()) {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
     fn apply_transforms(&self, mut value: f64) -> f64 {
         for transform in &self.transforms {
             value = transform(value);
@@ -61,13 +344,15 @@ impl Schema for NumberSchema {
     type Output = f64;
 
     fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let mut converted = None;
+        let value = json::resolve(value, &mut converted);
         let result = if let Some(n) = value.downcast_ref::<f64>() {
             Ok(*n)
+        } else if let Some(n) = native_integer(value) {
+            Ok(n as f64)
         } else if self.coerce {
             // Try to coerce different types to number
-            if let Some(n) = value.downcast_ref::<i64>() {
-                Ok(*n as f64)
-            } else if let Some(s) = value.downcast_ref::<String>() {
+            if let Some(s) = value.downcast_ref::<String>() {
                 s.parse::<f64>().map_err(|_| ValidationError::new(
                     ErrorType::Coercion {
                         from: "String",
@@ -96,14 +381,31 @@ impl Schema for NumberSchema {
             ))
         };
 
-        result.map(|n| self.apply_transforms(n))
+        let value = self.apply_transforms(result?);
+        self.check_constraints(value)?;
+        refine::check(&self.refinements, &value)?;
+        Ok(value)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let mut keywords = Map::new();
+        keywords.insert("type".to_string(), json!(if self.integer { "integer" } else { "number" }));
+        if let Some(min) = self.min {
+            let keyword = if min.inclusive { "minimum" } else { "exclusiveMinimum" };
+            keywords.insert(keyword.to_string(), json!(min.value));
+        }
+        if let Some(max) = self.max {
+            let keyword = if max.inclusive { "maximum" } else { "exclusiveMaximum" };
+            keywords.insert(keyword.to_string(), json!(max.value));
+        }
+        if let Some(step) = self.multiple_of {
+            keywords.insert("multipleOf".to_string(), json!(step));
+        }
+        Value::Object(keywords)
     }
 }
 
-fn type_name(value: &dyn Any) -> &'static str {
-    if value.is::<String>() { "String" }
-    else if value.is::<i64>() { "Integer" }
-    else if value.is::<f64>() { "Float" }
-    else if value.is::<bool>() { "Boolean" }
-    else { "Unknown" }
-}
\ No newline at end of file
+fn is_multiple_of(value: f64, step: f64) -> bool {
+    let quotient = value / step;
+    (quotient - quotient.round()).abs() < 1e-9
+}
@@ -1,10 +1,12 @@
 use std::any::Any;
 use std::collections::HashMap;
-use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig};
-use crate::schema::Schema;
+use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig, PathSegment};
+use crate::schema::{Schema, BoxedSchema};
 use crate::schema::mapping::{FromFields, ValidateAs};
 use crate::schema::clone::CloneAny;
-use serde_json::Value;
+use crate::schema::json::{self, type_name};
+use crate::schema::refine::{self, Refinement};
+use serde_json::{json, Map, Value};
 
 /// A schema for validating objects (HashMaps) with typed fields.
 ///
@@ -79,15 +81,38 @@ use serde_json::Value;
 /// ```
 pub struct ObjectSchema {
     error_config: Option<ErrorConfig>,
-    fields: HashMap<String, Box<dyn Schema<Output = Box<dyn Any>> + 'static>>,
+    fields: HashMap<String, BoxedSchema>,
+    unknown_keys: UnknownKeys,
+    /// Keys read by an enclosing schema, such as the discriminator of a
+    /// discriminated union, which are neither fields nor unknown keys.
+    tag_keys: Vec<String>,
+    refinements: Vec<Refinement<HashMap<String, Box<dyn Any>>>>,
+    checks: Vec<ObjectCheckFn>,
+}
+
+type ObjectCheckFn = Box<dyn Fn(&HashMap<String, Box<dyn Any>>) -> Vec<ValidationError>>;
+
+/// What an [`ObjectSchema`] does with keys that are not declared as fields.
+enum UnknownKeys {
+    Strip,
+    Strict,
+    Passthrough,
+    Catchall(BoxedSchema),
 }
 
 impl ObjectSchema {
     /// Creates a new object schema.
+    // No `Default` impl: `ObjectSchema::default()` would clash with the
+    // `Schema::default` builder method
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         ObjectSchema {
             error_config: None,
             fields: HashMap::new(),
+            unknown_keys: UnknownKeys::Strip,
+            tag_keys: Vec::new(),
+            refinements: Vec::new(),
+            checks: Vec::new(),
         }
     }
 
@@ -114,7 +139,108 @@ impl ObjectSchema {
         name: &str,
         schema: S,
     ) -> Self {
-        self.fields.insert(name.to_string(), Box::new(AnySchema::new(schema)));
+        self.fields.insert(name.to_string(), schema.boxed());
+        self
+    }
+
+    /// Rejects keys that are not declared as fields, with one
+    /// `ErrorType::UnknownField` error per key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.object()
+    ///     .field("name", s.string())
+    ///     .strict();
+    ///
+    /// assert!(schema.validate(&json!({ "name": "Ann" })).is_ok());
+    ///
+    /// let err = schema.validate(&json!({ "name": "Ann", "admin": true, "role": "x" })).unwrap_err();
+    /// let leaves = err.flatten();
+    /// assert_eq!(leaves.len(), 2);
+    /// assert_eq!(leaves[0].1.code, "UNKNOWN_FIELD");
+    /// assert_eq!(leaves[0].1.dotted_path(), "admin");
+    /// ```
+    pub fn strict(mut self) -> Self {
+        self.unknown_keys = UnknownKeys::Strict;
+        self
+    }
+
+    /// Drops keys that are not declared as fields from the output. This is
+    /// the default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.object()
+    ///     .field("name", s.string())
+    ///     .strip();
+    ///
+    /// let cleaned = schema.validate_to_json(&json!({ "name": "Ann", "admin": true })).unwrap();
+    /// assert_eq!(cleaned, json!({ "name": "Ann" }));
+    /// ```
+    pub fn strip(mut self) -> Self {
+        self.unknown_keys = UnknownKeys::Strip;
+        self
+    }
+
+    /// Keeps keys that are not declared as fields in the output, with their
+    /// values untouched.
+    ///
+    /// Values of an input map are copied into the output, which is possible
+    /// for strings, numbers, booleans, `None`, JSON values, and options,
+    /// vectors and maps of those. Other values are reported with an
+    /// `ErrorType::Type` error at their key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.object()
+    ///     .field("name", s.string().trim())
+    ///     .passthrough();
+    ///
+    /// let cleaned = schema.validate_to_json(&json!({ "name": " Ann ", "meta": { "source": "import" } })).unwrap();
+    /// assert_eq!(cleaned, json!({ "name": "Ann", "meta": { "source": "import" } }));
+    /// ```
+    pub fn passthrough(mut self) -> Self {
+        self.unknown_keys = UnknownKeys::Passthrough;
+        self
+    }
+
+    /// Validates every key that is not declared as a field with `schema`, and
+    /// keeps the validated values in the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.object()
+    ///     .field("name", s.string())
+    ///     .catchall(s.coerce().number());
+    ///
+    /// let cleaned = schema.validate_to_json(&json!({ "name": "Ann", "math": "90", "art": 75 })).unwrap();
+    /// assert_eq!(cleaned, json!({ "name": "Ann", "math": 90.0, "art": 75.0 }));
+    ///
+    /// let err = schema.validate(&json!({ "name": "Ann", "math": "A+" })).unwrap_err();
+    /// assert_eq!(err.flatten()[0].1.dotted_path(), "math");
+    /// ```
+    pub fn catchall<S: Schema + 'static>(mut self, schema: S) -> Self {
+        self.unknown_keys = UnknownKeys::Catchall(schema.boxed());
         self
     }
 
@@ -148,6 +274,141 @@ impl ObjectSchema {
         self
     }
 
+    /// Adds a rule the validated fields must satisfy, failing with an
+    /// `ErrorType::Custom` error with `code` and `message` otherwise.
+    ///
+    /// Refinements run once every field is valid, so they can read the
+    /// validated values of the fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.object()
+    ///     .field("password", s.string().min_length(8))
+    ///     .field("confirm", s.string())
+    ///     .refine(
+    ///         |fields| fields["password"].downcast_ref::<String>() == fields["confirm"].downcast_ref::<String>(),
+    ///         "PASSWORD_MISMATCH",
+    ///         "Passwords do not match",
+    ///     );
+    ///
+    /// assert!(schema.validate(&json!({ "password": "hunter2!!", "confirm": "hunter2!!" })).is_ok());
+    ///
+    /// let err = schema.validate(&json!({ "password": "hunter2!!", "confirm": "hunter3!!" })).unwrap_err();
+    /// assert_eq!(err.code, "PASSWORD_MISMATCH");
+    /// ```
+    pub fn refine<F, C, M>(mut self, f: F, code: C, message: M) -> Self
+    where
+        F: Fn(&HashMap<String, Box<dyn Any>>) -> bool + 'static,
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.refinements.push(refine::predicate(f, code.into(), message.into()));
+        self
+    }
+
+    /// Adds a rule the validated fields must satisfy, failing with the error
+    /// returned by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use schema_validator::error::ValidationError;
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.object()
+    ///     .field("start", s.integer())
+    ///     .field("end", s.integer())
+    ///     .try_refine(|fields| {
+    ///         let start = *fields["start"].downcast_ref::<i64>().unwrap();
+    ///         let end = *fields["end"].downcast_ref::<i64>().unwrap();
+    ///         match start <= end {
+    ///             true => Ok(()),
+    ///             false => Err(ValidationError::custom("INVALID_RANGE", "end must not be before start")
+    ///                 .with_param("start", start)
+    ///                 .with_path_prefix("end")),
+    ///         }
+    ///     });
+    ///
+    /// let err = schema.validate(&json!({ "start": 5, "end": 3 })).unwrap_err();
+    /// assert_eq!(err.to_string(), "end: [INVALID_RANGE] end must not be before start");
+    /// ```
+    pub fn try_refine<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&HashMap<String, Box<dyn Any>>) -> ValidationResult<()> + 'static,
+    {
+        self.refinements.push(Box::new(f));
+        self
+    }
+
+    /// Adds a cross-field check returning every failure it finds.
+    ///
+    /// Checks run after the fields are validated, with the fields that are
+    /// valid, so they also run when other fields failed and have to skip
+    /// rules whose fields are absent. A failure is reported at the field named
+    /// by the first segment of its path, next to the errors of that field in
+    /// the `ErrorType::Object` error; failures without a path are reported
+    /// under the empty key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use schema_validator::error::ValidationError;
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.object()
+    ///     .field("password", s.string().min_length(8))
+    ///     .field("confirm_password", s.string())
+    ///     .field("start_date", s.string().date())
+    ///     .field("end_date", s.string().date())
+    ///     .check(|fields| {
+    ///         let field = |name: &str| fields.get(name).and_then(|value| value.downcast_ref::<String>());
+    ///         let mut errors = Vec::new();
+    ///         if let (Some(password), Some(confirm)) = (field("password"), field("confirm_password")) {
+    ///             if password != confirm {
+    ///                 errors.push(ValidationError::custom("PASSWORD_MISMATCH", "Passwords do not match")
+    ///                     .with_path_prefix("confirm_password"));
+    ///             }
+    ///         }
+    ///         if let (Some(start), Some(end)) = (field("start_date"), field("end_date")) {
+    ///             if end <= start {
+    ///                 errors.push(ValidationError::custom("INVALID_DATE_RANGE", "End date must be after start date")
+    ///                     .with_path_prefix("end_date"));
+    ///             }
+    ///         }
+    ///         errors
+    ///     });
+    ///
+    /// let err = schema.validate(&json!({
+    ///     "password": "short",
+    ///     "confirm_password": "shorter",
+    ///     "start_date": "2024-03-01",
+    ///     "end_date": "2024-02-01"
+    /// })).unwrap_err();
+    ///
+    /// assert_eq!(err.code, "OBJECT_ERROR");
+    /// let leaves: Vec<_> = err.flatten().iter().map(|(_, leaf)| (leaf.dotted_path(), leaf.code.clone())).collect();
+    /// assert_eq!(leaves, vec![
+    ///     ("end_date".to_string(), "INVALID_DATE_RANGE".to_string()),
+    ///     ("password".to_string(), "MIN_LENGTH_ERROR".to_string()),
+    /// ]);
+    /// ```
+    pub fn check<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&HashMap<String, Box<dyn Any>>) -> Vec<ValidationError> + 'static,
+    {
+        self.checks.push(Box::new(f));
+        self
+    }
+
     /// Transforms the validated object into a custom type.
     ///
     /// # Arguments
@@ -190,47 +451,57 @@ impl ObjectSchema {
     where
         F: Fn(HashMap<String, Box<dyn Any>>) -> T + 'static,
         T: 'static + CloneAny,
+    {
+        self.try_transform(move |fields| Ok(f(fields)))
+    }
+
+    /// Transforms the validated fields into a custom type, failing validation
+    /// when the transform returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use schema_validator::error::{ValidationError, ErrorType};
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.object()
+    ///     .field("min", s.number())
+    ///     .field("max", s.number())
+    ///     .try_transform(|fields| {
+    ///         let min = *fields.get("min").unwrap().downcast_ref::<f64>().unwrap();
+    ///         let max = *fields.get("max").unwrap().downcast_ref::<f64>().unwrap();
+    ///         if min > max {
+    ///             return Err(ValidationError::new(
+    ///                 ErrorType::TooLarge { max, inclusive: true, got: min },
+    ///                 None,
+    ///             ).with_path_prefix("min"));
+    ///         }
+    ///         Ok(max - min)
+    ///     });
+    ///
+    /// assert_eq!(schema.validate(&json!({ "min": 1.0, "max": 3.0 })).unwrap(), 2.0);
+    /// assert_eq!(schema.validate(&json!({ "min": 5.0, "max": 3.0 })).unwrap_err().dotted_path(), "min");
+    /// ```
+    pub fn try_transform<F, T>(self, f: F) -> TransformedObjectSchema<T>
+    where
+        F: Fn(HashMap<String, Box<dyn Any>>) -> ValidationResult<T> + 'static,
+        T: 'static + CloneAny,
     {
         TransformedObjectSchema {
             schema: self,
             transform: Box::new(f),
+            refinements: Vec::new(),
             _phantom: std::marker::PhantomData,
         }
     }
 
     fn validate_json(&self, json: &Value) -> ValidationResult<HashMap<String, Box<dyn Any>>> {
         match json {
-            Value::Object(obj) => {
-                let mut fields = HashMap::new();
-                for (field_name, field_value) in obj {
-                    match field_value {
-                        Value::String(s) => {
-                            fields.insert(field_name.clone(), Box::new(s.clone()) as Box<dyn Any>);
-                        }
-                        Value::Number(n) => {
-                            if let Some(f) = n.as_f64() {
-                                fields.insert(field_name.clone(), Box::new(f) as Box<dyn Any>);
-                            }
-                        }
-                        Value::Bool(b) => {
-                            fields.insert(field_name.clone(), Box::new(*b) as Box<dyn Any>);
-                        }
-                        Value::Null => {
-                            fields.insert(field_name.clone(), Box::new(None::<()>) as Box<dyn Any>);
-                        }
-                        _ => {
-                            return Err(ValidationError::new(
-                                ErrorType::Type {
-                                    expected: "String, Number, Boolean or Null",
-                                    got: "Array or Object",
-                                },
-                                self.error_config.clone(),
-                            ));
-                        }
-                    }
-                }
-                Ok(fields)
-            }
+            Value::Object(obj) => Ok(obj.iter()
+                .map(|(field_name, field_value)| (field_name.clone(), json::to_any(field_value)))
+                .collect()),
             _ => Err(ValidationError::new(
                 ErrorType::Type {
                     expected: "Object",
@@ -246,7 +517,8 @@ impl Schema for ObjectSchema {
     type Output = HashMap<String, Box<dyn Any>>;
 
     fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
-        let raw_fields: HashMap<String, Box<dyn Any>> = if let Some(map) = value.downcast_ref::<HashMap<String, Box<dyn Any>>>() {
+        let input = value.downcast_ref::<HashMap<String, Box<dyn Any>>>();
+        let raw_fields: HashMap<String, Box<dyn Any>> = if let Some(map) = input {
             map.iter().map(|(k, v)| (k.clone(), Self::wrap_value(v.as_ref()))).collect()
         } else if let Some(json) = value.downcast_ref::<Value>() {
             self.validate_json(json)?
@@ -263,36 +535,24 @@ impl Schema for ObjectSchema {
         let mut validated_fields = HashMap::new();
         let mut errors = HashMap::new();
 
-        // Clone the fields to avoid lifetime issues
-        let fields: Vec<_> = self.fields.iter().map(|(k, v)| (k.clone(), v.as_ref())).collect();
-
-        for (field_name, field_schema) in fields {
-            if let Some(field_value) = raw_fields.get(&field_name) {
-                let wrapped = Self::wrap_value(field_value.as_ref());
-
-                let wrapped_val = if let Some(opt) = wrapped.downcast_ref::<Option<Box<dyn Any>>>() {
-                    match opt {
-                        None => None,
-                        Some(val) => Some(val.as_ref()),
+        for (field_name, field_schema) in &self.fields {
+            if let Some(field_value) = raw_fields.get(field_name) {
+                match Self::validate_field(field_schema, field_value.as_ref()) {
+                    Ok(value) => {
+                        validated_fields.insert(field_name.clone(), value);
                     }
-                } else if let Some(opt) = wrapped.downcast_ref::<Option<()>>() {
-                    if opt.is_none() {
-                        None
-                    } else {
-                        Some(wrapped.as_ref())
+                    Err(err) => {
+                        errors.insert(field_name.clone(), err.with_path_prefix(field_name.as_str()));
+                    }
+                }
+            } else if let Some(result) = field_schema.validate_default() {
+                match result {
+                    Ok(value) => {
+                        validated_fields.insert(field_name.clone(), value);
+                    }
+                    Err(err) => {
+                        errors.insert(field_name.clone(), err.with_path_prefix(field_name.as_str()));
                     }
-                } else {
-                    Some(wrapped.as_ref())
-                };
-
-                if let Err(err) = match wrapped_val {
-                    None => field_schema.validate(&None::<()>),
-                    Some(val) => field_schema.validate(val),
-                }.and_then(|value| {
-                    validated_fields.insert(field_name.clone(), value);
-                    Ok(())
-                }) {
-                    errors.insert(field_name.clone(), err);
                 }
             } else {
                 errors.insert(
@@ -300,80 +560,221 @@ impl Schema for ObjectSchema {
                     ValidationError::new(
                         ErrorType::Missing { field: field_name.clone() },
                         self.error_config.clone(),
-                    ),
+                    ).with_path_prefix(field_name.as_str()),
                 );
             }
         }
 
+        let unknown = raw_fields.into_iter()
+            .filter(|(key, _)| !self.fields.contains_key(key) && !self.tag_keys.contains(key));
+        for (key, value) in unknown {
+            match &self.unknown_keys {
+                UnknownKeys::Strip => {}
+                UnknownKeys::Strict => {
+                    let err = ValidationError::new(
+                        ErrorType::UnknownField { field: key.clone() },
+                        self.error_config.clone(),
+                    ).with_path_prefix(key.as_str());
+                    errors.insert(key, err);
+                }
+                UnknownKeys::Passthrough => {
+                    // Map values are copied from the input, since `wrap_value`
+                    // only keeps the types schemas read; JSON is already converted
+                    let value = match input {
+                        Some(map) => json::copy_any(map[&key].as_ref()),
+                        None => Some(value),
+                    };
+                    match value {
+                        Some(value) => {
+                            validated_fields.insert(key, value);
+                        }
+                        None => {
+                            let err = ValidationError::new(
+                                ErrorType::Type {
+                                    expected: "JSON value",
+                                    got: "Unknown",
+                                },
+                                self.error_config.clone(),
+                            ).with_path_prefix(key.as_str());
+                            errors.insert(key, err);
+                        }
+                    }
+                }
+                UnknownKeys::Catchall(schema) => match Self::validate_field(schema, value.as_ref()) {
+                    Ok(value) => {
+                        validated_fields.insert(key, value);
+                    }
+                    Err(err) => {
+                        let err = err.with_path_prefix(key.as_str());
+                        errors.insert(key, err);
+                    }
+                },
+            }
+        }
+
+        for check in &self.checks {
+            for err in check(&validated_fields) {
+                let key = err.path.first().map(PathSegment::to_string).unwrap_or_default();
+                let err = match errors.remove(&key) {
+                    Some(existing) => Self::combine(existing, err),
+                    None => err,
+                };
+                errors.insert(key, err);
+            }
+        }
+
         if !errors.is_empty() {
+            let mut errors: Vec<_> = errors.into_iter().collect();
+            errors.sort_by(|(a, _), (b, _)| a.cmp(b));
             return Err(ValidationError::new(
-                ErrorType::Object { errors: errors.into_iter().collect() },
+                ErrorType::Object { errors },
                 self.error_config.clone(),
             ));
         }
 
+        refine::check(&self.refinements, &validated_fields)?;
         Ok(validated_fields)
     }
+
+    /// Fields that may be absent, such as fields with a default, are not
+    /// listed in `required`.
+    fn to_json_schema(&self) -> Value {
+        let properties: Map<String, Value> = self.fields.iter()
+            .map(|(name, schema)| (name.clone(), schema.to_json_schema()))
+            .collect();
+        let mut required: Vec<&String> = self.fields.iter()
+            .filter(|(_, schema)| !schema.accepts_absent())
+            .map(|(name, _)| name)
+            .collect();
+        required.sort();
+
+        let mut schema = json!({ "type": "object", "properties": properties, "required": required });
+        match &self.unknown_keys {
+            UnknownKeys::Strip | UnknownKeys::Passthrough => {}
+            UnknownKeys::Strict => schema["additionalProperties"] = json!(false),
+            UnknownKeys::Catchall(values) => schema["additionalProperties"] = values.to_json_schema(),
+        }
+        schema
+    }
 }
 
+type ObjectTransformFn<T> = Box<dyn Fn(HashMap<String, Box<dyn Any>>) -> ValidationResult<T>>;
+
 pub struct TransformedObjectSchema<T: 'static + CloneAny> {
     schema: ObjectSchema,
-    transform: Box<dyn Fn(HashMap<String, Box<dyn Any>>) -> T>,
+    transform: ObjectTransformFn<T>,
+    refinements: Vec<Refinement<T>>,
     _phantom: std::marker::PhantomData<T>,
 }
 
+impl<T: 'static + CloneAny> TransformedObjectSchema<T> {
+    /// Adds a rule the transformed value must satisfy, failing with an
+    /// `ErrorType::Custom` error with `code` and `message` otherwise.
+    pub fn refine<F, C, M>(mut self, f: F, code: C, message: M) -> Self
+    where
+        F: Fn(&T) -> bool + 'static,
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.refinements.push(refine::predicate(f, code.into(), message.into()));
+        self
+    }
+
+    /// Adds a rule the transformed value must satisfy, failing with the error
+    /// returned by `f`.
+    pub fn try_refine<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&T) -> ValidationResult<()> + 'static,
+    {
+        self.refinements.push(Box::new(f));
+        self
+    }
+
+    /// See [`ObjectSchema::tag_key`].
+    pub(crate) fn tag_key(mut self, key: &str) -> Self {
+        self.schema = self.schema.tag_key(key);
+        self
+    }
+}
+
 impl<T: 'static + CloneAny> Schema for TransformedObjectSchema<T> {
     type Output = T;
 
     fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
         let fields = self.schema.validate(value)?;
-        Ok((self.transform)(fields))
+        let value = (self.transform)(fields)?;
+        refine::check(&self.refinements, &value)?;
+        Ok(value)
     }
-}
 
-struct AnySchema<S> {
-    schema: S,
+    fn to_json_schema(&self) -> Value {
+        self.schema.to_json_schema()
+    }
 }
 
-impl<S: Schema> AnySchema<S> {
-    fn new(schema: S) -> Self {
-        AnySchema { schema }
+impl ObjectSchema {
+    /// Declares `key` as read by an enclosing schema, so it is not treated as
+    /// an unknown key.
+    pub(crate) fn tag_key(mut self, key: &str) -> Self {
+        if !self.tag_keys.iter().any(|existing| existing == key) {
+            self.tag_keys.push(key.to_string());
+        }
+        self
+    }
+
+    /// Combines a check failure with the errors already reported for the same
+    /// field, keeping the path of the field.
+    fn combine(existing: ValidationError, err: ValidationError) -> ValidationError {
+        let path = existing.path.clone();
+        let mut combined = ValidationError::new(ErrorType::Multiple { errors: vec![existing, err] }, None);
+        combined.path = path;
+        combined
     }
-}
 
-impl<S: Schema> Schema for AnySchema<S> {
-    type Output = Box<dyn Any>;
+    /// Validates the value of a field, passing `null` to the schema as `None::<()>`.
+    fn validate_field(schema: &BoxedSchema, value: &dyn Any) -> ValidationResult<Box<dyn Any>> {
+        let wrapped = Self::wrap_value(value);
+        let wrapped_val = if let Some(opt) = wrapped.downcast_ref::<Option<Box<dyn Any>>>() {
+            opt.as_ref().map(|val| val.as_ref())
+        } else if let Some(opt) = wrapped.downcast_ref::<Option<()>>() {
+            if opt.is_none() {
+                None
+            } else {
+                Some(wrapped.as_ref())
+            }
+        } else {
+            Some(wrapped.as_ref())
+        };
 
-    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
-        self.schema.validate(value).map(|v| Box::new(v) as Box<dyn Any>)
+        match wrapped_val {
+            None => schema.validate(&None::<()>),
+            Some(val) => schema.validate(val),
+        }
     }
-}
 
-impl ObjectSchema {
     fn wrap_value(value: &dyn Any) -> Box<dyn Any> {
-        if let Some(s) = value.downcast_ref::<String>() {
-            Box::new(s.clone())
-        } else if let Some(n) = value.downcast_ref::<i64>() {
-            Box::new(*n)
-        } else if let Some(n) = value.downcast_ref::<f64>() {
-            Box::new(*n)
-        } else if let Some(b) = value.downcast_ref::<bool>() {
-            Box::new(*b)
-        } else if let Some(opt) = value.downcast_ref::<Option<f64>>() {
-            Box::new(opt.clone())
-        } else if let Some(opt) = value.downcast_ref::<Option<String>>() {
-            Box::new(opt.clone())
-        } else if let Some(opt) = value.downcast_ref::<Option<bool>>() {
-            Box::new(opt.clone())
-        } else if let Some(opt) = value.downcast_ref::<Option<Box<dyn Any>>>() {
+        if let Some(opt) = value.downcast_ref::<Option<Box<dyn Any>>>() {
             match opt {
                 None => Box::new(None::<()>),
                 Some(val) => Box::new(Some(Self::wrap_value(val.as_ref()))),
             }
-        } else if let Some(opt) = value.downcast_ref::<Option<()>>() {
-            Box::new(opt.clone())
+        } else if let Some(map) = value.downcast_ref::<HashMap<String, Box<dyn Any>>>() {
+            Box::new(map.iter()
+                .map(|(k, v)| (k.clone(), Self::wrap_value(v.as_ref())))
+                .collect::<HashMap<_, _>>())
+        } else if let Some(items) = value.downcast_ref::<Vec<Box<dyn Any>>>() {
+            Box::new(items.iter().map(|item| Self::wrap_value(item.as_ref())).collect::<Vec<_>>())
+        } else if let Some(items) = value.downcast_ref::<Vec<String>>() {
+            Box::new(items.clone())
+        } else if let Some(items) = value.downcast_ref::<Vec<f64>>() {
+            Box::new(items.clone())
+        } else if let Some(items) = value.downcast_ref::<Vec<i64>>() {
+            Box::new(items.clone())
+        } else if let Some(items) = value.downcast_ref::<Vec<bool>>() {
+            Box::new(items.clone())
         } else {
-            Box::new(())
+            // Scalars of every size and their options are copied as they are
+            json::copy_any(value).unwrap_or_else(|| Box::new(()))
         }
     }
 }
@@ -381,18 +782,6 @@ impl ObjectSchema {
 impl ValidateAs for ObjectSchema {
     fn validate_as<T: FromFields>(&self, value: &dyn Any) -> ValidationResult<T> {
         let fields = self.validate(value)?;
-        T::from_fields(&fields).ok_or_else(|| ValidationError::new(
-            ErrorType::Type {
-                expected: "Object with required fields",
-                got: "Object with missing or invalid fields",
-            },
-            self.error_config.clone(),
-        ))
+        T::from_fields(&fields)
     }
 }
-
-fn type_name(value: &dyn Any) -> &'static str {
-    if value.is::<HashMap<String, Box<dyn Any>>>() { "Object" }
-    else if value.is::<Value>() { "JSON value" }
-    else { "Unknown" }
-}
\ No newline at end of file
@@ -0,0 +1,287 @@
+//! OpenAPI 3.1 documents generated from schemas.
+//!
+//! [`OpenApi`] collects named schemas as `components/schemas` and operations
+//! as `paths`. Since OpenAPI 3.1 uses JSON Schema draft 2020-12, components
+//! are the [`Schema::to_json_schema`] descriptions of the schemas.
+//!
+//! Schemas are linked explicitly: a schema whose `title` names a registered
+//! component, set with [`Schema::title`] or the `title` derive option, is
+//! replaced by a `$ref` to that component. Schemas without a title are kept
+//! as they are, even if they look like a component, and [`schema_ref`] can be
+//! written directly into operations.
+
+use serde_json::{json, Map, Value};
+use crate::schema::Schema;
+use crate::schema::mapping::Validate;
+
+/// The OpenAPI version of the generated documents.
+pub const OPENAPI_VERSION: &str = "3.1.0";
+
+/// Returns a `$ref` to the component schema registered as `name`.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::schema::openapi;
+/// use serde_json::json;
+///
+/// assert_eq!(openapi::schema_ref("User"), json!({ "$ref": "#/components/schemas/User" }));
+/// ```
+pub fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+/// Builds an OpenAPI 3.1 document.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use schema_validator::schema::openapi::{self, OpenApi};
+/// use serde_json::json;
+///
+/// let s = schema();
+/// let address = || s.object()
+///     .field("city", s.string().describe("City name").example(json!("Berlin")))
+///     .title("Address");
+/// let user = s.object()
+///     .field("name", s.string().min_length(2))
+///     .field("address", address());
+///
+/// let document = OpenApi::new("Users", "1.0.0")
+///     .component("Address", &address())
+///     .component("User", &user)
+///     .operation("get", "/users/{id}", json!({
+///         "responses": {
+///             "200": {
+///                 "description": "The user",
+///                 "content": { "application/json": { "schema": openapi::schema_ref("User") } }
+///             }
+///         }
+///     }))
+///     .to_json();
+///
+/// assert_eq!(document["openapi"], "3.1.0");
+/// assert_eq!(document["components"]["schemas"]["User"]["properties"]["address"], openapi::schema_ref("Address"));
+/// assert_eq!(document["components"]["schemas"]["Address"]["title"], "Address");
+/// assert_eq!(document["components"]["schemas"]["Address"]["properties"]["city"], json!({
+///     "type": "string",
+///     "description": "City name",
+///     "examples": ["Berlin"]
+/// }));
+/// ```
+pub struct OpenApi {
+    info: Map<String, Value>,
+    paths: Map<String, Value>,
+    components: Vec<(String, Value)>,
+}
+
+impl OpenApi {
+    /// Creates a document for the API with the given `title` and `version`.
+    pub fn new(title: &str, version: &str) -> Self {
+        let mut info = Map::new();
+        info.insert("title".to_string(), Value::String(title.to_string()));
+        info.insert("version".to_string(), Value::String(version.to_string()));
+        OpenApi {
+            info,
+            paths: Map::new(),
+            components: Vec::new(),
+        }
+    }
+
+    /// Sets the description of the API.
+    pub fn description(mut self, description: &str) -> Self {
+        self.info.insert("description".to_string(), Value::String(description.to_string()));
+        self
+    }
+
+    /// Registers `schema` as the component schema `name`, replacing any
+    /// component registered under the same name.
+    pub fn component<S: Schema + ?Sized>(mut self, name: &str, schema: &S) -> Self {
+        let schema = schema.to_json_schema();
+        match self.components.iter_mut().find(|(existing, _)| existing == name) {
+            Some((_, existing)) => *existing = schema,
+            None => self.components.push((name.to_string(), schema)),
+        }
+        self
+    }
+
+    /// Registers the schema of a type implementing [`Validate`] as a
+    /// component named after its `title`, or after the type if it has none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::Validate;
+    /// use schema_validator::schema::openapi::{self, OpenApi};
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Validate)]
+    /// #[validate(title = "Address")]
+    /// struct Address {
+    ///     city: String,
+    /// }
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Validate)]
+    /// struct User {
+    ///     #[validate(description = "Full name", example = "Ann Smith")]
+    ///     name: String,
+    ///     addresses: Vec<Address>,
+    /// }
+    ///
+    /// let document = OpenApi::new("Users", "1.0.0")
+    ///     .component_of::<Address>()
+    ///     .component_of::<User>()
+    ///     .to_json();
+    ///
+    /// let user = &document["components"]["schemas"]["User"];
+    /// assert_eq!(user["properties"]["addresses"]["items"], openapi::schema_ref("Address"));
+    /// assert_eq!(user["properties"]["name"]["description"], "Full name");
+    /// ```
+    pub fn component_of<T: Validate>(self) -> Self {
+        let schema = T::schema();
+        let title = schema.to_json_schema().get("title").and_then(Value::as_str).map(str::to_string);
+        let name = std::any::type_name::<T>();
+        let name = name.split('<').next().unwrap_or(name);
+        let name = name.rsplit("::").next().unwrap_or(name);
+        self.component(title.as_deref().unwrap_or(name), &schema)
+    }
+
+    /// Adds the operation for `method` (such as `get` or `post`) on `path`.
+    ///
+    /// `operation` is an OpenAPI operation object; the schemas given under its
+    /// `schema` keys are linked to the registered components like those of
+    /// other components, so [`Schema::to_json_schema`] can be used for request
+    /// and response bodies next to [`schema_ref`]. Examples are left as they
+    /// are.
+    pub fn operation(mut self, method: &str, path: &str, operation: Value) -> Self {
+        let item = self.paths.entry(path.to_string()).or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(item) = item {
+            item.insert(method.to_lowercase(), operation);
+        }
+        self
+    }
+
+    /// Returns the document as JSON.
+    pub fn to_json(&self) -> Value {
+        let mut schemas = Map::new();
+        for (name, schema) in &self.components {
+            let mut schema = schema.clone();
+            self.link_subschemas(&mut schema);
+            schemas.insert(name.clone(), schema);
+        }
+
+        let mut paths = Value::Object(self.paths.clone());
+        self.link_operations(&mut paths);
+
+        let mut document = Map::new();
+        document.insert("openapi".to_string(), Value::String(OPENAPI_VERSION.to_string()));
+        document.insert("info".to_string(), Value::Object(self.info.clone()));
+        if !self.paths.is_empty() {
+            document.insert("paths".to_string(), paths);
+        }
+        if !schemas.is_empty() {
+            document.insert("components".to_string(), json!({ "schemas": schemas }));
+        }
+        Value::Object(document)
+    }
+
+    /// Returns the document as pretty-printed JSON text.
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string_pretty(&self.to_json()).expect("JSON values always serialize")
+    }
+
+    /// Returns the document as YAML text.
+    ///
+    /// Available with the `yaml` cargo feature.
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> String {
+        serde_yaml::to_string(&self.to_json()).expect("JSON values always serialize")
+    }
+
+    /// Links the `schema` of every operation, parameter, media type and
+    /// header in `value`. Examples are data and are kept as they are.
+    fn link_operations(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map.iter_mut() {
+                    match key.as_str() {
+                        "schema" => self.link(child),
+                        "example" | "examples" => {}
+                        _ => self.link_operations(child),
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.link_operations(item)),
+            _ => {}
+        }
+    }
+
+    /// Replaces `schema` by a `$ref` if its title names a component, or links
+    /// its subschemas otherwise.
+    fn link(&self, schema: &mut Value) {
+        match self.reference(schema) {
+            Some(reference) => *schema = reference,
+            None => self.link_subschemas(schema),
+        }
+    }
+
+    /// Links the subschemas of `schema`, found by the keyword holding them, so
+    /// that properties named like keywords are linked too.
+    fn link_subschemas(&self, schema: &mut Value) {
+        if let Value::Object(map) = schema {
+            for (keyword, child) in map.iter_mut() {
+                match (keyword.as_str(), child) {
+                    ("properties" | "patternProperties" | "dependentSchemas" | "$defs" | "definitions", Value::Object(schemas)) => {
+                        schemas.values_mut().for_each(|schema| self.link(schema));
+                    }
+                    ("allOf" | "anyOf" | "oneOf" | "prefixItems", Value::Array(schemas)) => {
+                        schemas.iter_mut().for_each(|schema| self.link(schema));
+                    }
+                    (
+                        "items" | "additionalProperties" | "unevaluatedItems" | "unevaluatedProperties" | "contains"
+                        | "propertyNames" | "not" | "if" | "then" | "else",
+                        child,
+                    ) => self.link(child),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Returns the `$ref` replacing `schema` if its `title` names a registered
+    /// component, keeping its other annotations, or a nullable `$ref` if it
+    /// accepts `null` while the component does not.
+    fn reference(&self, schema: &Value) -> Option<Value> {
+        let map = schema.as_object()?;
+        let name = map.get("title")?.as_str()?;
+        let (name, component) = self.components.iter().find(|(component, _)| component == name)?;
+
+        let mut reference = match accepts_null(map) && !component.as_object().is_some_and(accepts_null) {
+            false => schema_ref(name),
+            true => json!({ "anyOf": [schema_ref(name), { "type": "null" }] }),
+        };
+        if let Value::Object(reference) = &mut reference {
+            reference.extend(map.iter()
+                .filter(|(keyword, _)| is_annotation(keyword))
+                .map(|(keyword, value)| (keyword.clone(), value.clone())));
+        }
+        Some(reference)
+    }
+}
+
+/// Annotations of a linked schema kept next to its `$ref`. The `title` names
+/// the component and is left out.
+fn is_annotation(keyword: &str) -> bool {
+    ["description", "examples", "default", "deprecated", "readOnly", "writeOnly"].contains(&keyword)
+}
+
+/// Returns `true` if `schema` accepts `null` in either form produced by
+/// `json_schema::nullable`: a `null` type, or an `anyOf` with a `null` member.
+fn accepts_null(schema: &Map<String, Value>) -> bool {
+    let null = json!({ "type": "null" });
+    match (schema.get("type"), schema.get("anyOf")) {
+        (Some(Value::Array(types)), _) => types.contains(&json!("null")),
+        (_, Some(Value::Array(members))) => members.contains(&null),
+        _ => false,
+    }
+}
@@ -1,7 +1,9 @@
 use std::any::Any;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use crate::error::{ValidationError, ValidationResult, ErrorType};
-use crate::schema::{Schema, clone};
+use crate::schema::{Schema, clone, json, json_schema};
+use serde_json::Value;
 
 /// A schema that makes another schema optional.
 ///
@@ -53,19 +55,55 @@ pub struct TransformedOptionalSchema<S: Schema, T> {
     _phantom: PhantomData<T>,
 }
 
-impl<S: Schema, T: clone::CloneAny + 'static> Schema for TransformedOptionalSchema<S, T> where S::Output: Clone {
+impl<S: Schema, T: clone::CloneAny + 'static> Schema for TransformedOptionalSchema<S, T> {
     type Output = T;
 
     fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
         let opt = self.schema.validate(value)?;
         Ok((self.transform)(opt))
     }
+
+    fn to_json_schema(&self) -> Value {
+        self.schema.to_json_schema()
+    }
+
+    fn validate_default(&self) -> Option<ValidationResult<Self::Output>> {
+        self.schema.validate_default().map(|result| result.map(&self.transform))
+    }
+
+    fn accepts_absent(&self) -> bool {
+        self.schema.accepts_absent()
+    }
+}
+
+/// Unwraps an `Option` of an input type, such as `Option<String>` or
+/// `Option<Value>`.
+///
+/// Only raw inputs are unwrapped: an `Option` of anything else, such as the
+/// output of a transform, is not an input and is validated as given.
+fn raw_option(value: &dyn Any) -> Option<Option<&dyn Any>> {
+    macro_rules! raw_options {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(option) = value.downcast_ref::<Option<$ty>>() {
+                    return Some(option.as_ref().map(|value| value as &dyn Any));
+                }
+            )*
+        };
+    }
+    raw_options!(
+        String, &'static str, i8, i16, i32, i64, u8, u16, u32, u64, f64, bool, Value,
+        HashMap<String, Box<dyn Any>>, Vec<Box<dyn Any>>
+    );
+    None
 }
 
-impl<S: Schema> Schema for OptionalSchema<S> where S::Output: Clone {
+impl<S: Schema> Schema for OptionalSchema<S> {
     type Output = Option<S::Output>;
 
     fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let mut converted = None;
+        let value = json::resolve(value, &mut converted);
         if let Some(none) = value.downcast_ref::<Option<()>>() {
             if none.is_none() {
                 Ok(None)
@@ -78,8 +116,6 @@ impl<S: Schema> Schema for OptionalSchema<S> where S::Output: Clone {
                     None,
                 ))
             }
-        } else if let Some(option) = value.downcast_ref::<Option<S::Output>>() {
-            Ok(option.clone())
         } else if let Some(option) = value.downcast_ref::<Option<Box<dyn Any>>>() {
             match option {
                 None => Ok(None),
@@ -88,6 +124,11 @@ impl<S: Schema> Schema for OptionalSchema<S> where S::Output: Clone {
                     Ok(Some(val))
                 }
             }
+        } else if let Some(option) = raw_option(value) {
+            match option {
+                None => Ok(None),
+                Some(val) => Ok(Some(self.schema.validate(val)?)),
+            }
         } else {
             // Try to validate the value directly
             match self.schema.validate(value) {
@@ -96,4 +137,16 @@ impl<S: Schema> Schema for OptionalSchema<S> where S::Output: Clone {
             }
         }
     }
+
+    fn to_json_schema(&self) -> Value {
+        json_schema::nullable(self.schema.to_json_schema())
+    }
+
+    fn validate_default(&self) -> Option<ValidationResult<Self::Output>> {
+        self.schema.validate_default().map(|result| result.map(Some))
+    }
+
+    fn accepts_absent(&self) -> bool {
+        self.schema.accepts_absent()
+    }
 }
\ No newline at end of file
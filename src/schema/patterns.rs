@@ -84,6 +84,30 @@ impl Pattern {
         }
     }
 
+    /// Get the JSON Schema `format` name of this pattern, if there is one
+    pub fn format(&self) -> Option<&'static str> {
+        match self {
+            Pattern::Email => Some("email"),
+            Pattern::Url => Some("uri"),
+            Pattern::Date => Some("date"),
+            Pattern::Uuid => Some("uuid"),
+            Pattern::Ipv4 => Some("ipv4"),
+            _ => None,
+        }
+    }
+
+    /// Find the pattern for a JSON Schema `format` name
+    pub fn from_format(format: &str) -> Option<Pattern> {
+        match format {
+            "email" => Some(Pattern::Email),
+            "uri" => Some(Pattern::Url),
+            "date" => Some(Pattern::Date),
+            "uuid" => Some(Pattern::Uuid),
+            "ipv4" => Some(Pattern::Ipv4),
+            _ => None,
+        }
+    }
+
     /// Try to find a predefined pattern that matches the given regex string
     pub fn from_regex(pattern: &str) -> Option<Pattern> {
         let patterns = [
@@ -0,0 +1,122 @@
+use std::any::Any;
+use std::collections::HashMap;
+use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig};
+use crate::schema::Schema;
+use crate::schema::json::{self, type_name};
+use serde_json::{json, Value};
+
+/// A schema for maps with arbitrary string keys whose values all match a
+/// value schema.
+///
+/// Accepts `HashMap<String, Box<dyn Any>>`, maps of primitive values
+/// (`HashMap<String, String>`, `HashMap<String, f64>`, `HashMap<String, i64>`,
+/// `HashMap<String, bool>`), maps of the value schema's output type and JSON
+/// objects. Failing values are reported under their key.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use serde_json::json;
+///
+/// let s = schema();
+/// let schema = s.record(s.integer().min(0));
+///
+/// let stock = schema.validate(&json!({ "apples": 3, "pears": 0 })).unwrap();
+/// assert_eq!(stock["apples"], 3);
+///
+/// let err = schema.validate(&json!({ "apples": 3, "pears": -1 })).unwrap_err();
+/// assert_eq!(err.code, "OBJECT_ERROR");
+/// assert_eq!(err.flatten()[0].1.dotted_path(), "pears");
+/// ```
+pub struct RecordSchema<S: Schema> {
+    value: S,
+    error_config: Option<ErrorConfig>,
+}
+
+impl<S: Schema> RecordSchema<S> {
+    pub(crate) fn new(value: S) -> Self {
+        RecordSchema {
+            value,
+            error_config: None,
+        }
+    }
+
+    /// Sets a custom error message for the record schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The error code to use
+    /// * `message` - The error message to use
+    pub fn set_message<C, M>(mut self, code: C, message: M) -> Self
+    where
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.error_config = Some(ErrorConfig {
+            code: code.into(),
+            message: message.into(),
+        });
+        self
+    }
+}
+
+impl<S: Schema> Schema for RecordSchema<S> {
+    type Output = HashMap<String, S::Output>;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let converted: HashMap<String, Box<dyn Any>>;
+        let entries: Vec<(&String, &dyn Any)> = if let Some(map) = value.downcast_ref::<HashMap<String, Box<dyn Any>>>() {
+            map.iter().map(|(key, value)| (key, value.as_ref())).collect()
+        } else if let Some(Value::Object(map)) = value.downcast_ref::<Value>() {
+            converted = map.iter().map(|(key, value)| (key.clone(), json::to_any(value))).collect();
+            converted.iter().map(|(key, value)| (key, value.as_ref())).collect()
+        } else if let Some(entries) = typed_entries::<S::Output>(value)
+            .or_else(|| typed_entries::<String>(value))
+            .or_else(|| typed_entries::<f64>(value))
+            .or_else(|| typed_entries::<i64>(value))
+            .or_else(|| typed_entries::<bool>(value))
+        {
+            entries
+        } else {
+            return Err(ValidationError::new(
+                ErrorType::Type {
+                    expected: "Object",
+                    got: type_name(value),
+                },
+                self.error_config.clone(),
+            ));
+        };
+
+        let mut validated = HashMap::with_capacity(entries.len());
+        let mut errors = Vec::new();
+
+        for (key, value) in entries {
+            match self.value.validate(value) {
+                Ok(value) => {
+                    validated.insert(key.clone(), value);
+                }
+                Err(err) => errors.push((key.clone(), err.with_path_prefix(key.as_str()))),
+            }
+        }
+
+        if !errors.is_empty() {
+            errors.sort_by(|(a, _), (b, _)| a.cmp(b));
+            return Err(ValidationError::new(
+                ErrorType::Object { errors },
+                self.error_config.clone(),
+            ));
+        }
+
+        Ok(validated)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        json!({ "type": "object", "additionalProperties": self.value.to_json_schema() })
+    }
+}
+
+fn typed_entries<T: 'static>(value: &dyn Any) -> Option<Vec<(&String, &dyn Any)>> {
+    value.downcast_ref::<HashMap<String, T>>()
+        .map(|map| map.iter().map(|(key, value)| (key, value as &dyn Any)).collect())
+}
@@ -0,0 +1,29 @@
+use crate::error::{ValidationError, ValidationResult};
+
+/// A domain rule checked on a validated value, added with `refine` or
+/// `try_refine`.
+pub(crate) type Refinement<T> = Box<dyn Fn(&T) -> ValidationResult<()>>;
+
+/// Turns a predicate into a refinement failing with an `ErrorType::Custom`
+/// error.
+pub(crate) fn predicate<T, F>(f: F, code: String, message: String) -> Refinement<T>
+where
+    F: Fn(&T) -> bool + 'static,
+{
+    Box::new(move |value| match f(value) {
+        true => Ok(()),
+        false => Err(ValidationError::custom(code.clone(), message.clone())),
+    })
+}
+
+/// Runs every refinement on `value`, in the order they were added.
+///
+/// All refinements run even if one fails, and several failures are combined
+/// into an `ErrorType::Multiple` error.
+pub(crate) fn check<T>(refinements: &[Refinement<T>], value: &T) -> ValidationResult<()> {
+    let errors = refinements.iter().filter_map(|refinement| refinement(value).err()).collect();
+    match ValidationError::from_errors(errors, None) {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
@@ -3,11 +3,17 @@ use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig};
 use crate::schema::Schema;
 use crate::schema::clone::CloneAny;
 use crate::schema::patterns;
+use crate::schema::json::{self, type_name};
+use crate::schema::refine::{self, Refinement};
 use regex::Regex;
+use serde_json::{json, Map, Value};
+
+type StringTransformFn<T> = Box<dyn Fn(String) -> ValidationResult<T>>;
 
 pub struct TransformedSchema<T: 'static + CloneAny> {
     schema: StringSchema,
-    transform: Box<dyn Fn(String) -> T>,
+    transform: StringTransformFn<T>,
+    refinements: Vec<Refinement<T>>,
     _phantom: std::marker::PhantomData<T>,
 }
 
@@ -63,13 +69,55 @@ impl<T: 'static + CloneAny + Clone> TransformedSchema<T> {
         U: 'static + CloneAny,
     {
         let old_transform = self.transform;
+        let refinements = self.refinements;
         TransformedSchema {
             schema: self.schema,
-            transform: Box::new(move |s| f((old_transform)(s))),
+            transform: Box::new(move |s| {
+                let value = (old_transform)(s)?;
+                refine::check(&refinements, &value)?;
+                Ok(f(value))
+            }),
+            refinements: Vec::new(),
             _phantom: std::marker::PhantomData,
         }
     }
 
+    /// Adds a rule the transformed value must satisfy, failing with an
+    /// `ErrorType::Custom` error with `code` and `message` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.string()
+    ///     .trim()
+    ///     .refine(|s| !s.contains("  "), "DOUBLE_SPACE", "Must not contain double spaces");
+    ///
+    /// assert_eq!(schema.validate(&" Ann Lee ".to_string()).unwrap(), "Ann Lee");
+    /// assert_eq!(schema.validate(&"Ann  Lee".to_string()).unwrap_err().code, "DOUBLE_SPACE");
+    /// ```
+    pub fn refine<F, C, M>(mut self, f: F, code: C, message: M) -> Self
+    where
+        F: Fn(&T) -> bool + 'static,
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.refinements.push(refine::predicate(f, code.into(), message.into()));
+        self
+    }
+
+    /// Adds a rule the transformed value must satisfy, failing with the
+    /// error returned by `f`.
+    pub fn try_refine<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&T) -> ValidationResult<()> + 'static,
+    {
+        self.refinements.push(Box::new(f));
+        self
+    }
+
     /// Trims whitespace from both ends of the string.
     ///
     /// # Examples
@@ -243,12 +291,20 @@ impl<T: 'static + CloneAny + Clone> TransformedSchema<T> {
         self.schema = self.schema.max_length(length);
         self
     }
+
+    /// Sets whether validation stops at the first failing constraint.
+    pub fn abort_early(mut self, abort_early: bool) -> Self {
+        self.schema = self.schema.abort_early(abort_early);
+        self
+    }
 }
 
 impl<T: 'static + CloneAny + Clone> Schema for TransformedSchema<T> {
     type Output = T;
 
     fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let mut converted = None;
+        let value = json::resolve(value, &mut converted);
         let string = if let Some(s) = value.downcast_ref::<String>() {
             s.clone()
         } else if let Some(s) = self.schema.coerce_to_string(value) {
@@ -263,42 +319,49 @@ impl<T: 'static + CloneAny + Clone> Schema for TransformedSchema<T> {
             ));
         };
 
-        let transformed = (self.transform)(string);
-        if let Some(pattern) = &self.schema.pattern {
-            // Only validate pattern if T can be converted to String
-            if let Some(string) = transformed_to_string(&transformed) {
-                if !pattern.is_match(&string) {
-                    return Err(ValidationError::new(
-                        ErrorType::Pattern {
-                            pattern: pattern.as_str().to_string(),
-                            got: string,
-                        },
-                        self.schema.error_config.clone(),
-                    ));
-                }
+        // Refinements of the string schema see the input, but run after the
+        // constraints like in `StringSchema::validate`
+        let input = string.clone();
+        let transformed = (self.transform)(string)?;
+        if let Some(string) = (&transformed as &dyn Any).downcast_ref::<String>() {
+            self.schema.check_constraints(string)?;
+        } else if let Some(string) = transformed_to_string(&transformed) {
+            // Only the pattern applies to values that merely convert to a string
+            if let Some(err) = self.schema.check_pattern(&string) {
+                return Err(err);
             }
         }
+        refine::check(&self.schema.refinements, &input)?;
+        refine::check(&self.refinements, &transformed)?;
 
         Ok(transformed)
     }
+
+    fn to_json_schema(&self) -> Value {
+        self.schema.to_json_schema()
+    }
 }
 
 pub struct StringSchema {
     coerce: bool,
+    abort_early: bool,
     error_config: Option<ErrorConfig>,
     pattern: Option<Regex>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    refinements: Vec<Refinement<String>>,
 }
 
 impl StringSchema {
-    pub(crate) fn new(coerce: bool) -> Self {
+    pub(crate) fn new(coerce: bool, abort_early: bool) -> Self {
         StringSchema {
             coerce,
+            abort_early,
             error_config: None,
             pattern: None,
             min_length: None,
             max_length: None,
+            refinements: Vec::new(),
         }
     }
 
@@ -600,6 +663,104 @@ impl StringSchema {
         self
     }
 
+    /// Sets whether validation stops at the first failing constraint.
+    ///
+    /// By default the first failing check (minimum length, then maximum length,
+    /// then pattern) is returned. With `abort_early(false)` every constraint is
+    /// evaluated and, when more than one fails, the failures are aggregated into
+    /// an `ErrorType::Multiple` error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use schema_validator::error::ErrorType;
+    ///
+    /// let s = schema();
+    /// let schema = s.string()
+    ///     .min_length(5)
+    ///     .pattern(r"^[a-z]+$")
+    ///     .abort_early(false);
+    ///
+    /// let err = schema.validate(&"A1".to_string()).unwrap_err();
+    /// assert_eq!(err.code, "MULTIPLE_ERRORS");
+    ///
+    /// let codes: Vec<_> = err.flatten().iter().map(|(_, leaf)| leaf.code.clone()).collect();
+    /// assert_eq!(codes, vec!["MIN_LENGTH_ERROR", "PATTERN_ERROR"]);
+    /// ```
+    pub fn abort_early(mut self, abort_early: bool) -> Self {
+        self.abort_early = abort_early;
+        self
+    }
+
+    /// Adds a rule the string must satisfy, failing with an
+    /// `ErrorType::Custom` error with `code` and `message` otherwise.
+    ///
+    /// Refinements run in the order they were added, once the string passed
+    /// the other constraints. Every refinement runs, and several failures are
+    /// aggregated into an `ErrorType::Multiple` error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// fn checksum_valid(sku: &str) -> bool {
+    ///     let digits: Vec<u32> = sku.chars().filter_map(|c| c.to_digit(10)).collect();
+    ///     digits.len() > 1 && digits[..digits.len() - 1].iter().sum::<u32>() % 10 == digits[digits.len() - 1]
+    /// }
+    ///
+    /// let s = schema();
+    /// let schema = s.string()
+    ///     .pattern(r"^SKU-\d+$")
+    ///     .refine(|sku| checksum_valid(sku), "INVALID_SKU", "SKU checksum is not valid");
+    ///
+    /// assert!(schema.validate(&"SKU-1236".to_string()).is_ok());
+    ///
+    /// let err = schema.validate(&"SKU-1234".to_string()).unwrap_err();
+    /// assert_eq!(err.code, "INVALID_SKU");
+    /// assert_eq!(err.message, "SKU checksum is not valid");
+    ///
+    /// // Refinements only run on strings passing the other constraints
+    /// assert_eq!(schema.validate(&"1234".to_string()).unwrap_err().code, "PATTERN_ERROR");
+    /// ```
+    pub fn refine<F, C, M>(mut self, f: F, code: C, message: M) -> Self
+    where
+        F: Fn(&String) -> bool + 'static,
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.refinements.push(refine::predicate(f, code.into(), message.into()));
+        self
+    }
+
+    /// Adds a rule the string must satisfy, failing with the error returned
+    /// by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use schema_validator::error::ValidationError;
+    ///
+    /// let s = schema();
+    /// let schema = s.string().try_refine(|slug| match slug.find("--") {
+    ///     Some(index) => Err(ValidationError::custom("INVALID_SLUG", "Slug contains '--'")
+    ///         .with_param("index", index)),
+    ///     None => Ok(()),
+    /// });
+    ///
+    /// assert!(schema.validate(&"my-post".to_string()).is_ok());
+    /// assert_eq!(schema.validate(&"my--post".to_string()).unwrap_err().code, "INVALID_SLUG");
+    /// ```
+    pub fn try_refine<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&String) -> ValidationResult<()> + 'static,
+    {
+        self.refinements.push(Box::new(f));
+        self
+    }
+
     /// Transforms the validated string into a custom type.
     ///
     /// # Arguments
@@ -625,7 +786,8 @@ impl StringSchema {
     {
         TransformedSchema {
             schema: self,
-            transform: Box::new(f),
+            transform: Box::new(move |s| Ok(f(s))),
+            refinements: Vec::new(),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -688,38 +850,22 @@ impl StringSchema {
 
         if let Some(n) = value.downcast_ref::<i64>() {
             Some(n.to_string())
+        } else if let Some(n) = value.downcast_ref::<u64>() {
+            Some(n.to_string())
         } else if let Some(n) = value.downcast_ref::<f64>() {
             Some(format!("{:.0}", n))  // Format without decimal point
-        } else if let Some(b) = value.downcast_ref::<bool>() {
-            Some(b.to_string())
         } else {
-            None
+            value.downcast_ref::<bool>().map(|b| b.to_string())
         }
     }
-}
 
-impl Schema for StringSchema {
-    type Output = String;
-
-    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
-        let string = if let Some(s) = value.downcast_ref::<String>() {
-            s.clone()
-        } else if let Some(s) = self.coerce_to_string(value) {
-            s
-        } else {
-            return Err(ValidationError::new(
-                ErrorType::Type {
-                    expected: "String",
-                    got: type_name(value),
-                },
-                self.error_config.clone(),
-            ));
-        };
+    fn check_constraints(&self, string: &str) -> ValidationResult<()> {
+        let mut errors = Vec::new();
 
         // Check minimum length
         if let Some(min_length) = self.min_length {
             if string.len() < min_length {
-                return Err(ValidationError::new(
+                errors.push(ValidationError::new(
                     ErrorType::MinLength {
                         min: min_length,
                         got: string.len(),
@@ -731,8 +877,8 @@ impl Schema for StringSchema {
 
         // Check maximum length
         if let Some(max_length) = self.max_length {
-            if string.len() > max_length {
-                return Err(ValidationError::new(
+            if string.len() > max_length && (errors.is_empty() || !self.abort_early) {
+                errors.push(ValidationError::new(
                     ErrorType::MaxLength {
                         max: max_length,
                         got: string.len(),
@@ -743,20 +889,73 @@ impl Schema for StringSchema {
         }
 
         // Check pattern
-        if let Some(pattern) = &self.pattern {
-            if !pattern.is_match(&string) {
-                return Err(ValidationError::new(
-                    ErrorType::Pattern {
-                        pattern: pattern.as_str().to_string(),
-                        got: string,
-                    },
-                    self.error_config.clone(),
-                ));
-            }
+        if errors.is_empty() || !self.abort_early {
+            errors.extend(self.check_pattern(string));
+        }
+
+        match ValidationError::from_errors(errors, self.error_config.clone()) {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    fn check_pattern(&self, string: &str) -> Option<ValidationError> {
+        match &self.pattern {
+            Some(pattern) if !pattern.is_match(string) => Some(ValidationError::new(
+                ErrorType::Pattern {
+                    pattern: pattern.as_str().to_string(),
+                    got: string.to_string(),
+                },
+                self.error_config.clone(),
+            )),
+            _ => None,
         }
+    }
+}
+
+impl Schema for StringSchema {
+    type Output = String;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let mut converted = None;
+        let value = json::resolve(value, &mut converted);
+        let string = if let Some(s) = value.downcast_ref::<String>() {
+            s.clone()
+        } else if let Some(s) = self.coerce_to_string(value) {
+            s
+        } else {
+            return Err(ValidationError::new(
+                ErrorType::Type {
+                    expected: "String",
+                    got: type_name(value),
+                },
+                self.error_config.clone(),
+            ));
+        };
+
+        self.check_constraints(&string)?;
+        refine::check(&self.refinements, &string)?;
 
         Ok(string)
     }
+
+    fn to_json_schema(&self) -> Value {
+        let mut keywords = Map::new();
+        keywords.insert("type".to_string(), json!("string"));
+        if let Some(min_length) = self.min_length {
+            keywords.insert("minLength".to_string(), json!(min_length));
+        }
+        if let Some(max_length) = self.max_length {
+            keywords.insert("maxLength".to_string(), json!(max_length));
+        }
+        if let Some(pattern) = &self.pattern {
+            if let Some(format) = patterns::Pattern::from_regex(pattern.as_str()).and_then(|p| p.format()) {
+                keywords.insert("format".to_string(), json!(format));
+            }
+            keywords.insert("pattern".to_string(), json!(pattern.as_str()));
+        }
+        Value::Object(keywords)
+    }
 }
 
 fn transformed_to_string<T: Clone + 'static>(value: &T) -> Option<String> {
@@ -766,17 +965,7 @@ fn transformed_to_string<T: Clone + 'static>(value: &T) -> Option<String> {
         Some(n.to_string())
     } else if let Some(n) = (value as &dyn Any).downcast_ref::<f64>() {
         Some(n.to_string())
-    } else if let Some(b) = (value as &dyn Any).downcast_ref::<bool>() {
-        Some(b.to_string())
     } else {
-        None
+        (value as &dyn Any).downcast_ref::<bool>().map(|b| b.to_string())
     }
 }
-
-fn type_name(value: &dyn Any) -> &'static str {
-    if value.is::<String>() { "String" }
-    else if value.is::<i64>() { "Integer" }
-    else if value.is::<f64>() { "Float" }
-    else if value.is::<bool>() { "Boolean" }
-    else { "Unknown" }
-}
\ No newline at end of file
@@ -0,0 +1,296 @@
+use std::any::Any;
+use std::collections::HashMap;
+use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig};
+use crate::schema::Schema;
+use crate::schema::clone::CloneAny;
+use crate::schema::{json, json_schema};
+use crate::schema::json::type_name;
+use serde_json::{json, Value};
+
+/// How the variant of an enum is represented, following serde's conventions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tagging {
+    /// `"Quit"` (or `{"Quit": null}`) for unit variants and `{"Move": {...}}`
+    /// otherwise.
+    External,
+    /// `{"type": "Move", ...}`, with the variant's fields next to the tag.
+    ///
+    /// Variants are validated against the object without the tag, so
+    /// newtype variants must hold a struct or a map.
+    Internal { tag: String },
+    /// `{"type": "Move", "data": {...}}`.
+    Adjacent { tag: String, content: String },
+}
+
+type VariantFn<T> = Box<dyn Fn(&dyn Any) -> ValidationResult<T>>;
+
+enum VariantKind<T> {
+    Unit(T),
+    Data(VariantFn<T>, Value),
+}
+
+/// A schema for Rust enums whose variants may carry data.
+///
+/// This is the schema `#[derive(Validate)]` generates for enums with
+/// non-unit variants; the representation is chosen with
+/// `#[validate(tag = "...")]` (internal) or
+/// `#[validate(tag = "...", content = "...")]` (adjacent) on the enum and
+/// defaults to external tagging.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{Schema, Validate};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Clone, PartialEq, Validate)]
+/// #[validate(tag = "type")]
+/// enum Shape {
+///     #[validate(rename = "circle")]
+///     Circle { radius: f64 },
+///     #[validate(rename = "square")]
+///     Square { side: f64 },
+///     #[validate(rename = "empty")]
+///     Empty,
+/// }
+///
+/// let schema = Shape::schema();
+/// assert_eq!(
+///     schema.validate(&json!({ "type": "circle", "radius": 1.5 })).unwrap(),
+///     Shape::Circle { radius: 1.5 },
+/// );
+/// assert_eq!(schema.validate(&json!({ "type": "empty" })).unwrap(), Shape::Empty);
+///
+/// let err = schema.validate(&json!({ "type": "triangle" })).unwrap_err();
+/// assert_eq!(err.code, "INVALID_DISCRIMINATOR");
+/// ```
+pub struct TaggedEnumSchema<T: CloneAny + Clone + 'static> {
+    tagging: Tagging,
+    variants: Vec<(String, VariantKind<T>)>,
+    error_config: Option<ErrorConfig>,
+}
+
+impl<T: CloneAny + Clone + 'static> TaggedEnumSchema<T> {
+    /// Creates a schema without variants using the given representation.
+    pub fn new(tagging: Tagging) -> Self {
+        TaggedEnumSchema {
+            tagging,
+            variants: Vec::new(),
+            error_config: None,
+        }
+    }
+
+    /// Adds a variant without data, represented by `label` alone.
+    pub fn unit(mut self, label: &str, value: T) -> Self {
+        self.variants.push((label.to_string(), VariantKind::Unit(value)));
+        self
+    }
+
+    /// Adds a variant whose data is validated by `schema` and turned into the
+    /// variant by `f`.
+    pub fn variant<S, F>(mut self, label: &str, schema: S, f: F) -> Self
+    where
+        S: Schema + 'static,
+        F: Fn(S::Output) -> ValidationResult<T> + 'static,
+    {
+        let json_schema = schema.to_json_schema();
+        self.variants.push((
+            label.to_string(),
+            VariantKind::Data(Box::new(move |value| f(schema.validate(value)?)), json_schema),
+        ));
+        self
+    }
+
+    /// Sets a custom error message for the enum schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The error code to use
+    /// * `message` - The error message to use
+    pub fn set_message<C, M>(mut self, code: C, message: M) -> Self
+    where
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.error_config = Some(ErrorConfig {
+            code: code.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    fn labels(&self) -> Vec<String> {
+        self.variants.iter().map(|(label, _)| label.clone()).collect()
+    }
+
+    fn find(&self, label: &str) -> Option<&VariantKind<T>> {
+        self.variants.iter()
+            .find(|(variant, _)| variant == label)
+            .map(|(_, kind)| kind)
+    }
+
+    fn validate_external(&self, value: &dyn Any) -> ValidationResult<T> {
+        if let Some(label) = value.downcast_ref::<String>() {
+            return match self.find(label) {
+                Some(VariantKind::Unit(variant)) => Ok(variant.clone()),
+                Some(VariantKind::Data(..)) => Err(ValidationError::new(
+                    ErrorType::Type {
+                        expected: "Object",
+                        got: "String",
+                    },
+                    self.error_config.clone(),
+                )),
+                None => Err(ValidationError::new(
+                    ErrorType::Enum {
+                        expected: self.labels(),
+                        got: label.clone(),
+                    },
+                    self.error_config.clone(),
+                )),
+            };
+        }
+
+        let map = match value.downcast_ref::<HashMap<String, Box<dyn Any>>>() {
+            Some(map) if map.len() == 1 => map,
+            _ => return Err(ValidationError::new(
+                ErrorType::Type {
+                    expected: "String or Object with a single key",
+                    got: type_name(value),
+                },
+                self.error_config.clone(),
+            )),
+        };
+
+        let (label, content) = map.iter().next().unwrap();
+        match self.find(label) {
+            Some(VariantKind::Data(parse, _)) => parse(content.as_ref())
+                .map_err(|err| err.with_path_prefix(label.as_str())),
+            // `{"Quit": null}` is accepted like `"Quit"`, but content is not
+            Some(VariantKind::Unit(variant)) => match content.downcast_ref::<Option<()>>() {
+                Some(None) => Ok(variant.clone()),
+                _ => Err(ValidationError::new(
+                    ErrorType::Type {
+                        expected: "null",
+                        got: type_name(content.as_ref()),
+                    },
+                    self.error_config.clone(),
+                ).with_path_prefix(label.as_str())),
+            },
+            None => Err(ValidationError::new(
+                ErrorType::Enum {
+                    expected: self.labels(),
+                    got: label.clone(),
+                },
+                self.error_config.clone(),
+            )),
+        }
+    }
+
+    fn validate_tagged(&self, value: &dyn Any, tag: &str, content: Option<&str>) -> ValidationResult<T> {
+        let map = match value.downcast_ref::<HashMap<String, Box<dyn Any>>>() {
+            Some(map) => map,
+            None => return Err(ValidationError::new(
+                ErrorType::Type {
+                    expected: "Object",
+                    got: type_name(value),
+                },
+                self.error_config.clone(),
+            )),
+        };
+
+        let label = match map.get(tag) {
+            None => return Err(ValidationError::new(
+                ErrorType::Missing { field: tag.to_string() },
+                self.error_config.clone(),
+            ).with_path_prefix(tag)),
+            Some(label) => match label.downcast_ref::<String>() {
+                Some(label) => label,
+                None => return Err(ValidationError::new(
+                    ErrorType::Type {
+                        expected: "String",
+                        got: type_name(label.as_ref()),
+                    },
+                    self.error_config.clone(),
+                ).with_path_prefix(tag)),
+            },
+        };
+
+        let parse = match self.find(label) {
+            Some(VariantKind::Unit(variant)) => return Ok(variant.clone()),
+            Some(VariantKind::Data(parse, _)) => parse,
+            None => return Err(ValidationError::new(
+                ErrorType::InvalidDiscriminator {
+                    field: tag.to_string(),
+                    expected: self.labels(),
+                    got: label.clone(),
+                },
+                self.error_config.clone(),
+            ).with_path_prefix(tag)),
+        };
+
+        match content {
+            // The variant sees the fields next to the tag, without the tag
+            None => {
+                let mut fields = HashMap::with_capacity(map.len());
+                for (key, field) in map.iter().filter(|(key, _)| *key != tag) {
+                    match json::copy_any(field.as_ref()) {
+                        Some(field) => fields.insert(key.clone(), field),
+                        None => return Err(ValidationError::new(
+                            ErrorType::Type {
+                                expected: "JSON value",
+                                got: type_name(field.as_ref()),
+                            },
+                            self.error_config.clone(),
+                        ).with_path_prefix(key.as_str())),
+                    };
+                }
+                parse(&fields)
+            }
+            Some(content) => match map.get(content) {
+                Some(data) => parse(data.as_ref()).map_err(|err| err.with_path_prefix(content)),
+                None => Err(ValidationError::new(
+                    ErrorType::Missing { field: content.to_string() },
+                    self.error_config.clone(),
+                ).with_path_prefix(content)),
+            },
+        }
+    }
+}
+
+impl<T: CloneAny + Clone + 'static> Schema for TaggedEnumSchema<T> {
+    type Output = T;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let mut converted = None;
+        let value = json::resolve(value, &mut converted);
+
+        match &self.tagging {
+            Tagging::External => self.validate_external(value),
+            Tagging::Internal { tag } => self.validate_tagged(value, tag, None),
+            Tagging::Adjacent { tag, content } => self.validate_tagged(value, tag, Some(content)),
+        }
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let variants: Vec<Value> = self.variants.iter()
+            .map(|(label, kind)| match (&self.tagging, kind) {
+                (Tagging::External, VariantKind::Unit(_)) => json!({ "const": label }),
+                (Tagging::External, VariantKind::Data(_, data)) => json!({
+                    "type": "object",
+                    "properties": { label: data },
+                    "required": [label],
+                    "additionalProperties": false
+                }),
+                (Tagging::Internal { tag }, VariantKind::Unit(_)) => json_schema::with_tag(json!({ "type": "object" }), tag, label),
+                (Tagging::Internal { tag }, VariantKind::Data(_, data)) => json_schema::with_tag(data.clone(), tag, label),
+                (Tagging::Adjacent { tag, .. }, VariantKind::Unit(_)) => json_schema::with_tag(json!({ "type": "object" }), tag, label),
+                (Tagging::Adjacent { tag, content }, VariantKind::Data(_, data)) => json_schema::with_tag(
+                    json!({ "type": "object", "properties": { content: data }, "required": [content] }),
+                    tag,
+                    label,
+                ),
+            })
+            .collect();
+        json!({ "oneOf": variants })
+    }
+}
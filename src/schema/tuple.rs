@@ -0,0 +1,237 @@
+use std::any::Any;
+use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig};
+use crate::schema::{Schema, BoxedSchema};
+use crate::schema::clone::CloneAny;
+use crate::schema::json::{self, type_name};
+use serde_json::{json, Value};
+
+/// A schema for fixed-length arrays whose elements are validated by the schema
+/// at the same position.
+///
+/// Accepts `Vec<Box<dyn Any>>` and JSON arrays. The output holds the value
+/// produced by each position's schema; arrays of the wrong length are rejected
+/// with `ErrorType::MinItems` or `ErrorType::MaxItems`.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use serde_json::json;
+///
+/// let s = schema();
+/// let schema = s.tuple(vec![s.string().boxed(), s.integer().boxed()]);
+///
+/// let pair = schema.validate(&json!(["answer", 42])).unwrap();
+/// assert_eq!(pair[1].downcast_ref::<i64>(), Some(&42));
+///
+/// assert_eq!(schema.validate(&json!(["answer"])).unwrap_err().code, "MIN_ITEMS_ERROR");
+/// assert_eq!(schema.validate(&json!([42, "answer"])).unwrap_err().code, "ARRAY_ERROR");
+/// ```
+pub struct TupleSchema {
+    items: Vec<BoxedSchema>,
+    error_config: Option<ErrorConfig>,
+}
+
+impl TupleSchema {
+    pub(crate) fn new(items: Vec<BoxedSchema>) -> Self {
+        TupleSchema {
+            items,
+            error_config: None,
+        }
+    }
+
+    /// Sets a custom error message for the tuple schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The error code to use
+    /// * `message` - The error message to use
+    pub fn set_message<C, M>(mut self, code: C, message: M) -> Self
+    where
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.error_config = Some(ErrorConfig {
+            code: code.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Transforms the validated elements into a custom type, failing
+    /// validation when the transform returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use schema_validator::schema::mapping::element;
+    /// use serde_json::json;
+    ///
+    /// let s = schema();
+    /// let schema = s.tuple(vec![s.number().boxed(), s.number().boxed()])
+    ///     .try_transform(|items| Ok(element::<f64>(&items, 1)? - element::<f64>(&items, 0)?));
+    ///
+    /// assert_eq!(schema.validate(&json!([1.5, 2])).unwrap(), 0.5);
+    /// ```
+    pub fn try_transform<F, T>(self, f: F) -> TransformedTupleSchema<T>
+    where
+        F: Fn(Vec<Box<dyn Any>>) -> ValidationResult<T> + 'static,
+        T: 'static + CloneAny,
+    {
+        TransformedTupleSchema {
+            schema: self,
+            transform: Box::new(f),
+        }
+    }
+}
+
+impl Schema for TupleSchema {
+    type Output = Vec<Box<dyn Any>>;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let converted: Vec<Box<dyn Any>>;
+        let items: &[Box<dyn Any>] = if let Some(items) = value.downcast_ref::<Vec<Box<dyn Any>>>() {
+            items
+        } else if let Some(Value::Array(values)) = value.downcast_ref::<Value>() {
+            converted = values.iter().map(json::to_any).collect();
+            &converted
+        } else {
+            return Err(ValidationError::new(
+                ErrorType::Type {
+                    expected: "Array",
+                    got: type_name(value),
+                },
+                self.error_config.clone(),
+            ));
+        };
+
+        if items.len() < self.items.len() {
+            return Err(ValidationError::new(
+                ErrorType::MinItems {
+                    min: self.items.len(),
+                    got: items.len(),
+                },
+                self.error_config.clone(),
+            ));
+        }
+        if items.len() > self.items.len() {
+            return Err(ValidationError::new(
+                ErrorType::MaxItems {
+                    max: self.items.len(),
+                    got: items.len(),
+                },
+                self.error_config.clone(),
+            ));
+        }
+
+        let mut validated = Vec::with_capacity(items.len());
+        let mut errors = Vec::new();
+
+        for (index, (schema, item)) in self.items.iter().zip(items).enumerate() {
+            match schema.validate(item.as_ref()) {
+                Ok(value) => validated.push(value),
+                Err(err) => errors.push((index, err.with_path_prefix(index))),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ValidationError::new(
+                ErrorType::Array { errors },
+                self.error_config.clone(),
+            ));
+        }
+
+        Ok(validated)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let items: Vec<Value> = self.items.iter().map(|item| item.to_json_schema()).collect();
+        json!({
+            "type": "array",
+            "prefixItems": items,
+            "minItems": self.items.len(),
+            "maxItems": self.items.len()
+        })
+    }
+}
+
+type TupleTransformFn<T> = Box<dyn Fn(Vec<Box<dyn Any>>) -> ValidationResult<T>>;
+
+/// A [`TupleSchema`] whose elements are turned into a custom type.
+pub struct TransformedTupleSchema<T: 'static + CloneAny> {
+    schema: TupleSchema,
+    transform: TupleTransformFn<T>,
+}
+
+impl<T: 'static + CloneAny> Schema for TransformedTupleSchema<T> {
+    type Output = T;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let items = self.schema.validate(value)?;
+        (self.transform)(items)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        self.schema.to_json_schema()
+    }
+}
+
+type NewtypeFn<T> = Box<dyn Fn(&dyn Any) -> ValidationResult<T>>;
+
+/// A schema that validates a value with an inner schema and wraps the result.
+///
+/// This is the schema `#[derive(Validate)]` generates for newtype structs such
+/// as `struct Meters(f64)`, which are validated like the value they wrap.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use schema_validator::schema::tuple::NewtypeSchema;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Meters(f64);
+///
+/// impl schema_validator::schema::clone::CloneAny for Meters {
+///     fn clone_any(&self) -> Box<dyn std::any::Any> {
+///         Box::new(self.clone())
+///     }
+/// }
+///
+/// let s = schema();
+/// let schema = NewtypeSchema::new(s.number().min(0.0), Meters);
+///
+/// assert_eq!(schema.validate(&1.5).unwrap(), Meters(1.5));
+/// assert_eq!(schema.validate(&-1.0).unwrap_err().code, "TOO_SMALL");
+/// ```
+pub struct NewtypeSchema<T: 'static + CloneAny> {
+    validate: NewtypeFn<T>,
+    json_schema: Value,
+}
+
+impl<T: 'static + CloneAny> NewtypeSchema<T> {
+    /// Creates a schema validating with `schema` and wrapping the output with `f`.
+    pub fn new<S, F>(schema: S, f: F) -> Self
+    where
+        S: Schema + 'static,
+        F: Fn(S::Output) -> T + 'static,
+    {
+        NewtypeSchema {
+            json_schema: schema.to_json_schema(),
+            validate: Box::new(move |value| schema.validate(value).map(&f)),
+        }
+    }
+}
+
+impl<T: 'static + CloneAny> Schema for NewtypeSchema<T> {
+    type Output = T;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        (self.validate)(value)
+    }
+
+    fn to_json_schema(&self) -> Value {
+        self.json_schema.clone()
+    }
+}
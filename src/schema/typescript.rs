@@ -0,0 +1,377 @@
+//! TypeScript declarations generated from schemas.
+//!
+//! Declarations are derived from the JSON Schema of a schema (see
+//! [`Schema::to_json_schema`]), so anything that can be exported as JSON
+//! Schema can also be shared with a TypeScript frontend:
+//!
+//! - objects become interfaces, or inline object types when nested;
+//! - optional fields become optional properties accepting `null`;
+//! - literals become literal types;
+//! - enumerations, unions and tagged enums become union types;
+//! - arrays, tuples and records become `T[]`, `[A, B]` and `Record<string, T>`.
+//!
+//! The `schema-ts` binary applies [`declarations_from_json_schema`] to JSON
+//! Schema files and writes `.d.ts` files next to them.
+
+use serde_json::{Map, Value};
+use crate::schema::Schema;
+use crate::schema::mapping::Validate;
+
+/// Returns the TypeScript declaration of `schema` named `name`.
+///
+/// Object schemas are declared as an `interface`, every other schema as a
+/// `type` alias.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use schema_validator::schema::typescript;
+///
+/// let s = schema();
+/// let user = s.object()
+///     .field("name", s.string().min_length(2))
+///     .field("role", s.enumeration(["admin", "editor"]))
+///     .field("tags", s.array(s.string()))
+///     .field("nickname", s.string().optional());
+///
+/// assert_eq!(typescript::declaration("User", &user), "\
+/// export interface User {
+///   name: string;
+///   nickname?: string | null;
+///   role: \"admin\" | \"editor\";
+///   tags: string[];
+/// }
+/// ");
+/// ```
+pub fn declaration<S: Schema + ?Sized>(name: &str, schema: &S) -> String {
+    declarations_from_json_schema(name, &schema.to_json_schema())
+}
+
+/// Returns the TypeScript declaration of a type implementing [`Validate`],
+/// named after the type.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::Validate;
+/// use schema_validator::schema::typescript;
+///
+/// #[derive(Debug, Clone, PartialEq, Validate)]
+/// #[validate(tag = "kind")]
+/// enum Shape {
+///     #[validate(rename = "circle")]
+///     Circle { radius: f64 },
+///     #[validate(rename = "empty")]
+///     Empty,
+/// }
+///
+/// assert_eq!(typescript::declaration_of::<Shape>(), "\
+/// export type Shape = {
+///   kind: \"circle\";
+///   radius: number;
+/// } | {
+///   kind: \"empty\";
+/// };
+/// ");
+/// ```
+pub fn declaration_of<T: Validate>() -> String {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    let name = name.rsplit("::").next().unwrap_or(name);
+    declaration(name, &T::schema())
+}
+
+/// Returns the TypeScript type of `schema` as an inline type expression.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use schema_validator::schema::typescript;
+///
+/// let s = schema();
+/// assert_eq!(typescript::type_of(&s.array(s.string().or(s.integer()))), "(string | number)[]");
+/// assert_eq!(typescript::type_of(&s.literal("admin").optional()), "\"admin\" | null");
+/// ```
+pub fn type_of<S: Schema + ?Sized>(schema: &S) -> String {
+    Generator { root: "" }.render(&schema.to_json_schema(), 0).0
+}
+
+/// Returns TypeScript declarations for a JSON Schema document.
+///
+/// The document is declared as `name`; every schema in `$defs` (or
+/// `definitions`) gets its own declaration, and `$ref`s to them refer to that
+/// declaration by name. Descriptions become doc comments. Keywords without a
+/// TypeScript equivalent, such as bounds and patterns, are left out.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::schema::typescript;
+/// use serde_json::json;
+///
+/// let document = json!({
+///     "type": "object",
+///     "properties": {
+///         "id": { "type": "integer", "description": "Unique id" },
+///         "owner": { "$ref": "#/$defs/user" }
+///     },
+///     "required": ["id", "owner"],
+///     "$defs": {
+///         "user": { "type": "object", "properties": { "name": { "type": "string" } }, "required": ["name"] }
+///     }
+/// });
+///
+/// assert_eq!(typescript::declarations_from_json_schema("Project", &document), "\
+/// export interface Project {
+///   /** Unique id */
+///   id: number;
+///   owner: User;
+/// }
+///
+/// export interface User {
+///   name: string;
+/// }
+/// ");
+/// ```
+pub fn declarations_from_json_schema(name: &str, document: &Value) -> String {
+    let generator = Generator { root: name };
+    let mut declarations = vec![generator.declare(name, document)];
+    for defs in ["$defs", "definitions"] {
+        if let Some(Value::Object(defs)) = document.get(defs) {
+            declarations.extend(defs.iter().map(|(name, schema)| generator.declare(&type_name(name), schema)));
+        }
+    }
+    declarations.join("\n")
+}
+
+/// Converts a schema or file name into a TypeScript type name, for example
+/// `user-profile` into `UserProfile`.
+pub fn type_name(name: &str) -> String {
+    let name: String = name
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_' && c != '$')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map(|first| first.to_ascii_uppercase()).into_iter().chain(chars).collect::<String>()
+        })
+        .collect();
+    match name.chars().next() {
+        None => "Unnamed".to_string(),
+        Some(first) if first.is_ascii_digit() => format!("_{}", name),
+        Some(_) => name,
+    }
+}
+
+/// How tightly a rendered type binds, to know when it needs parentheses.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+enum Precedence {
+    Union,
+    Intersection,
+    Primary,
+}
+
+struct Generator<'a> {
+    root: &'a str,
+}
+
+impl Generator<'_> {
+    fn declare(&self, name: &str, schema: &Value) -> String {
+        let docs = doc_comment(schema, "");
+        let is_interface = schema.get("type") == Some(&Value::String("object".to_string()))
+            && schema.get("properties").is_some()
+            && ["$ref", "const", "enum", "anyOf", "oneOf", "allOf"].iter().all(|keyword| schema.get(keyword).is_none());
+
+        if is_interface {
+            format!("{}export interface {} {}\n", docs, name, self.object(schema, 0))
+        } else {
+            format!("{}export type {} = {};\n", docs, name, self.render(schema, 0).0)
+        }
+    }
+
+    fn render(&self, schema: &Value, indent: usize) -> (String, Precedence) {
+        let keywords = match schema {
+            Value::Bool(false) => return primary("never"),
+            Value::Object(keywords) => keywords,
+            _ => return primary("unknown"),
+        };
+
+        if let Some(Value::String(reference)) = keywords.get("$ref") {
+            return primary(&self.reference(reference));
+        }
+        if let Some(value) = keywords.get("const") {
+            return primary(&value.to_string());
+        }
+        if let Some(Value::Array(options)) = keywords.get("enum") {
+            return union(options.iter().map(|option| (option.to_string(), Precedence::Primary)).collect());
+        }
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(Value::Array(members)) = keywords.get(keyword) {
+                return union(members.iter().map(|member| self.render(member, indent)).collect());
+            }
+        }
+        if let Some(Value::Array(members)) = keywords.get("allOf") {
+            return intersection(members.iter().map(|member| self.render(member, indent)).collect());
+        }
+
+        match keywords.get("type") {
+            Some(Value::String(ty)) => self.render_type(ty, keywords, indent),
+            Some(Value::Array(types)) => union(types.iter()
+                .filter_map(Value::as_str)
+                .map(|ty| self.render_type(ty, keywords, indent))
+                .collect()),
+            _ if keywords.contains_key("properties") => self.render_type("object", keywords, indent),
+            _ if keywords.contains_key("items") || keywords.contains_key("prefixItems") => {
+                self.render_type("array", keywords, indent)
+            }
+            _ => primary("unknown"),
+        }
+    }
+
+    fn render_type(&self, ty: &str, keywords: &Map<String, Value>, indent: usize) -> (String, Precedence) {
+        match ty {
+            "string" => primary("string"),
+            "number" | "integer" => primary("number"),
+            "boolean" => primary("boolean"),
+            "null" => primary("null"),
+            "array" => match (keywords.get("prefixItems"), keywords.get("items")) {
+                (Some(Value::Array(items)), _) => {
+                    let items: Vec<_> = items.iter().map(|item| self.render(item, indent).0).collect();
+                    primary(&format!("[{}]", items.join(", ")))
+                }
+                (None, Some(item)) => {
+                    let item = self.render(item, indent);
+                    primary(&format!("{}[]", parenthesize(item, Precedence::Primary)))
+                }
+                _ => primary("unknown[]"),
+            },
+            "object" if keywords.contains_key("properties") => {
+                primary(&self.object(&Value::Object(keywords.clone()), indent))
+            }
+            "object" => match keywords.get("additionalProperties") {
+                Some(values) => primary(&format!("Record<string, {}>", self.render(values, indent).0)),
+                None => primary("Record<string, unknown>"),
+            },
+            _ => primary("unknown"),
+        }
+    }
+
+    /// Renders an object type with properties, one property per line, closing
+    /// at `indent`. Properties that are not required or accept `null` are
+    /// optional.
+    fn object(&self, schema: &Value, indent: usize) -> String {
+        let required: Vec<&str> = schema.get("required")
+            .and_then(Value::as_array)
+            .map(|names| names.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let inner = " ".repeat(indent + 2);
+
+        let mut members: Vec<String> = schema.get("properties")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .map(|(name, property)| {
+                format!(
+                    "{}{}{}{}: {};",
+                    doc_comment(property, &inner),
+                    inner,
+                    property_name(name),
+                    if required.contains(&name.as_str()) && !accepts_null(property) { "" } else { "?" },
+                    self.render(property, indent + 2).0,
+                )
+            })
+            .collect();
+        match schema.get("additionalProperties") {
+            None | Some(Value::Bool(false)) => {}
+            Some(_) => members.push(format!("{}[key: string]: unknown;", inner)),
+        }
+
+        if members.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{\n{}\n{}}}", members.join("\n"), " ".repeat(indent))
+        }
+    }
+
+    fn reference(&self, reference: &str) -> String {
+        if reference == "#" {
+            return self.root.to_string();
+        }
+        ["#/$defs/", "#/definitions/"].iter()
+            .find_map(|prefix| reference.strip_prefix(prefix))
+            .filter(|name| !name.contains('/'))
+            .map(type_name)
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+}
+
+fn primary(ty: &str) -> (String, Precedence) {
+    (ty.to_string(), Precedence::Primary)
+}
+
+fn union(members: Vec<(String, Precedence)>) -> (String, Precedence) {
+    combine(members, " | ", Precedence::Union, "never")
+}
+
+fn intersection(members: Vec<(String, Precedence)>) -> (String, Precedence) {
+    combine(members, " & ", Precedence::Intersection, "unknown")
+}
+
+fn combine(members: Vec<(String, Precedence)>, separator: &str, precedence: Precedence, empty: &str) -> (String, Precedence) {
+    let mut rendered: Vec<String> = Vec::new();
+    for member in members {
+        let member = parenthesize(member, precedence);
+        if !rendered.contains(&member) {
+            rendered.push(member);
+        }
+    }
+    match rendered.len() {
+        0 => primary(empty),
+        1 => primary(&rendered.remove(0)),
+        _ => (rendered.join(separator), precedence),
+    }
+}
+
+fn parenthesize((ty, precedence): (String, Precedence), context: Precedence) -> String {
+    if precedence < context {
+        format!("({})", ty)
+    } else {
+        ty
+    }
+}
+
+/// Returns `true` if `schema` accepts `null`, in either form exported by
+/// `json_schema::nullable`.
+fn accepts_null(schema: &Value) -> bool {
+    let null = Value::String("null".to_string());
+    match schema.get("type") {
+        Some(Value::Array(types)) if types.contains(&null) => true,
+        _ => schema.get("anyOf")
+            .and_then(Value::as_array)
+            .is_some_and(|members| members.iter().any(|member| member.get("type") == Some(&null))),
+    }
+}
+
+fn property_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars.next().is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        Value::String(name.to_string()).to_string()
+    }
+}
+
+fn doc_comment(schema: &Value, indent: &str) -> String {
+    let description = schema.get("description").and_then(Value::as_str).map(|description| description.replace("*/", "*\\/"));
+    match description.as_deref() {
+        Some(description) if !description.contains('\n') => format!("{}/** {} */\n", indent, description),
+        Some(description) => {
+            let lines: Vec<_> = description.lines().map(|line| format!("{} * {}", indent, line).trim_end().to_string()).collect();
+            format!("{}/**\n{}\n{} */\n", indent, lines.join("\n"), indent)
+        }
+        None => String::new(),
+    }
+}
@@ -0,0 +1,381 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use crate::error::{ValidationError, ValidationResult, ErrorType, ErrorConfig};
+use crate::schema::{Schema, BoxedSchema, json_schema};
+use crate::schema::clone::CloneAny;
+use crate::schema::object::{ObjectSchema, TransformedObjectSchema};
+use crate::schema::json::type_name;
+use serde_json::{json, Value};
+
+/// The output of a [`UnionSchema`] or [`DiscriminatedUnionSchema`]: the value
+/// produced by the matching member, tagged with that member's position and,
+/// for discriminated unions, the discriminator value.
+pub struct UnionValue {
+    index: usize,
+    tag: Option<String>,
+    value: Box<dyn Any>,
+    clone: fn(&dyn Any) -> Box<dyn Any>,
+}
+
+impl UnionValue {
+    /// Returns the position of the member schema that matched.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the discriminator value that selected the member, if any.
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+
+    /// Returns the validated value if it has type `T`.
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.value.downcast_ref::<T>()
+    }
+
+    /// Returns `true` if the validated value has type `T`.
+    pub fn is<T: 'static>(&self) -> bool {
+        self.value.is::<T>()
+    }
+
+    /// Takes the validated value out of the union.
+    pub fn into_inner(self) -> Box<dyn Any> {
+        self.value
+    }
+
+    pub(crate) fn value(&self) -> &dyn Any {
+        self.value.as_ref()
+    }
+}
+
+impl fmt::Debug for UnionValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnionValue")
+            .field("index", &self.index)
+            .field("tag", &self.tag)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CloneAny for UnionValue {
+    fn clone_any(&self) -> Box<dyn Any> {
+        Box::new(UnionValue {
+            index: self.index,
+            tag: self.tag.clone(),
+            value: (self.clone)(self.value.as_ref()),
+            clone: self.clone,
+        })
+    }
+}
+
+/// A schema accepting values that match any of its member schemas.
+///
+/// Members are tried in order and the first success wins. When no member
+/// matches, the error is an `ErrorType::Union` holding the failure of every
+/// member, in the same order as the members.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use schema_validator::error::ErrorType;
+///
+/// let s = schema();
+/// let schema = s.union(vec![
+///     s.string().uuid().boxed(),
+///     s.integer().min(1).boxed(),
+/// ]);
+///
+/// let id = schema.validate(&"123e4567-e89b-42d3-a456-426614174000".to_string()).unwrap();
+/// assert_eq!(id.index(), 0);
+///
+/// let id = schema.validate(&7_i64).unwrap();
+/// assert_eq!(id.downcast_ref::<i64>(), Some(&7));
+///
+/// let err = schema.validate(&0_i64).unwrap_err();
+/// assert_eq!(err.code, "UNION_ERROR");
+/// if let ErrorType::Union { errors } = &err.error_type {
+///     assert_eq!(errors[0].code, "INVALID_UUID");
+///     assert_eq!(errors[1].code, "TOO_SMALL");
+/// }
+/// ```
+pub struct UnionSchema {
+    members: Vec<BoxedSchema>,
+    error_config: Option<ErrorConfig>,
+}
+
+impl UnionSchema {
+    pub(crate) fn new(members: Vec<BoxedSchema>) -> Self {
+        UnionSchema {
+            members,
+            error_config: None,
+        }
+    }
+
+    /// Adds another member schema, tried after the existing ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.string().or(s.integer()).or(s.boolean());
+    ///
+    /// assert_eq!(schema.validate(&true).unwrap().index(), 2);
+    /// ```
+    pub fn or<S: Schema + 'static>(mut self, other: S) -> Self {
+        self.members.push(other.boxed());
+        self
+    }
+
+    /// Sets a custom error message for the union schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The error code to use
+    /// * `message` - The error message to use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    ///
+    /// let s = schema();
+    /// let schema = s.string().or(s.integer())
+    ///     .set_message("INVALID_ID", "ID must be a string or an integer");
+    ///
+    /// let err = schema.validate(&true).unwrap_err();
+    /// assert_eq!(err.code, "INVALID_ID");
+    /// ```
+    pub fn set_message<C, M>(mut self, code: C, message: M) -> Self
+    where
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.error_config = Some(ErrorConfig {
+            code: code.into(),
+            message: message.into(),
+        });
+        self
+    }
+}
+
+impl Schema for UnionSchema {
+    type Output = UnionValue;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let mut errors = Vec::with_capacity(self.members.len());
+
+        for (index, member) in self.members.iter().enumerate() {
+            match member.validate(value) {
+                Ok(value) => return Ok(UnionValue { index, tag: None, value, clone: member.output_cloner() }),
+                Err(err) => errors.push(err),
+            }
+        }
+
+        Err(ValidationError::new(
+            ErrorType::Union { errors },
+            self.error_config.clone(),
+        ))
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let members: Vec<Value> = self.members.iter().map(|member| member.to_json_schema()).collect();
+        json!({ "anyOf": members })
+    }
+}
+
+/// Object schemas that can be used as variants of a [`DiscriminatedUnionSchema`].
+pub trait ObjectVariant: Schema {
+    /// Declares `tag` as the discriminator field, so strict objects do not
+    /// reject it as an unknown key.
+    fn discriminated_by(self, tag: &str) -> Self;
+}
+
+impl ObjectVariant for ObjectSchema {
+    fn discriminated_by(self, tag: &str) -> Self {
+        self.tag_key(tag)
+    }
+}
+
+impl<T: 'static + CloneAny> ObjectVariant for TransformedObjectSchema<T> {
+    fn discriminated_by(self, tag: &str) -> Self {
+        self.tag_key(tag)
+    }
+}
+
+/// A schema for objects whose shape is selected by a tag field.
+///
+/// The discriminator field is read first and the object is validated by the
+/// variant registered for that tag only. Unknown tags are reported with an
+/// `ErrorType::InvalidDiscriminator` error listing the known tags.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use serde_json::json;
+///
+/// let s = schema();
+/// let schema = s.discriminated_union("type")
+///     .variant("click", s.object()
+///         .field("x", s.integer())
+///         .field("y", s.integer()))
+///     .variant("scroll", s.object()
+///         .field("delta", s.number()));
+///
+/// let event = schema.validate(&json!({ "type": "scroll", "delta": -3.5 })).unwrap();
+/// assert_eq!(event.tag(), Some("scroll"));
+///
+/// let err = schema.validate(&json!({ "type": "hover" })).unwrap_err();
+/// assert_eq!(err.code, "INVALID_DISCRIMINATOR");
+/// assert_eq!(err.dotted_path(), "type");
+/// ```
+pub struct DiscriminatedUnionSchema {
+    discriminator: String,
+    variants: Vec<(String, BoxedSchema)>,
+    error_config: Option<ErrorConfig>,
+}
+
+impl DiscriminatedUnionSchema {
+    pub(crate) fn new(discriminator: &str) -> Self {
+        DiscriminatedUnionSchema {
+            discriminator: discriminator.to_string(),
+            variants: Vec::new(),
+            error_config: None,
+        }
+    }
+
+    /// Adds the object schema used when the discriminator equals `tag`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The value of the discriminator field selecting this variant
+    /// * `schema` - The schema validating the whole object
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use schema_validator::{schema, Schema};
+    /// use serde_json::json;
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// struct Click { x: i64 }
+    ///
+    /// impl schema_validator::schema::clone::CloneAny for Click {
+    ///     fn clone_any(&self) -> Box<dyn std::any::Any> {
+    ///         Box::new(self.clone())
+    ///     }
+    /// }
+    ///
+    /// let s = schema();
+    /// let schema = s.discriminated_union("type")
+    ///     .variant("click", s.object()
+    ///         .field("x", s.integer())
+    ///         .transform(|fields| Click {
+    ///             x: *fields.get("x").unwrap().downcast_ref::<i64>().unwrap(),
+    ///         }));
+    ///
+    /// let event = schema.validate(&json!({ "type": "click", "x": 10 })).unwrap();
+    /// assert_eq!(event.downcast_ref::<Click>(), Some(&Click { x: 10 }));
+    /// ```
+    pub fn variant<S: ObjectVariant + 'static>(mut self, tag: &str, schema: S) -> Self {
+        self.variants.retain(|(existing, _)| existing != tag);
+        self.variants.push((tag.to_string(), schema.discriminated_by(&self.discriminator).boxed()));
+        self
+    }
+
+    /// Sets a custom error message for the discriminated union schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The error code to use
+    /// * `message` - The error message to use
+    pub fn set_message<C, M>(mut self, code: C, message: M) -> Self
+    where
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.error_config = Some(ErrorConfig {
+            code: code.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    fn read_tag(&self, value: &dyn Any) -> ValidationResult<String> {
+        let tag: Result<Option<&str>, &'static str> = if let Some(map) = value.downcast_ref::<HashMap<String, Box<dyn Any>>>() {
+            match map.get(&self.discriminator) {
+                None => Ok(None),
+                Some(tag) => tag.downcast_ref::<String>()
+                    .map(|tag| Some(tag.as_str()))
+                    .ok_or_else(|| type_name(tag.as_ref())),
+            }
+        } else if let Some(Value::Object(map)) = value.downcast_ref::<Value>() {
+            match map.get(&self.discriminator) {
+                None => Ok(None),
+                Some(Value::String(tag)) => Ok(Some(tag.as_str())),
+                Some(_) => Err("JSON value"),
+            }
+        } else {
+            return Err(ValidationError::new(
+                ErrorType::Type {
+                    expected: "Object or JSON object",
+                    got: type_name(value),
+                },
+                self.error_config.clone(),
+            ));
+        };
+
+        match tag {
+            Ok(Some(tag)) => Ok(tag.to_string()),
+            Ok(None) => Err(ValidationError::new(
+                ErrorType::Missing { field: self.discriminator.clone() },
+                self.error_config.clone(),
+            ).with_path_prefix(self.discriminator.as_str())),
+            Err(got) => Err(ValidationError::new(
+                ErrorType::Type {
+                    expected: "String",
+                    got,
+                },
+                self.error_config.clone(),
+            ).with_path_prefix(self.discriminator.as_str())),
+        }
+    }
+}
+
+impl Schema for DiscriminatedUnionSchema {
+    type Output = UnionValue;
+
+    fn validate(&self, value: &dyn Any) -> ValidationResult<Self::Output> {
+        let tag = self.read_tag(value)?;
+
+        let variant = self.variants.iter()
+            .enumerate()
+            .find(|(_, (variant, _))| *variant == tag);
+
+        let (index, schema) = match variant {
+            Some((index, (_, schema))) => (index, schema),
+            None => return Err(ValidationError::new(
+                ErrorType::InvalidDiscriminator {
+                    field: self.discriminator.clone(),
+                    expected: self.variants.iter().map(|(variant, _)| variant.clone()).collect(),
+                    got: tag,
+                },
+                self.error_config.clone(),
+            ).with_path_prefix(self.discriminator.as_str())),
+        };
+
+        let value = schema.validate(value)?;
+        Ok(UnionValue { index, tag: Some(tag), value, clone: schema.output_cloner() })
+    }
+
+    fn to_json_schema(&self) -> Value {
+        let variants: Vec<Value> = self.variants.iter()
+            .map(|(tag, schema)| json_schema::with_tag(schema.to_json_schema(), &self.discriminator, tag))
+            .collect();
+        json!({ "oneOf": variants })
+    }
+}
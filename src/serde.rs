@@ -0,0 +1,147 @@
+//! Validation during deserialization.
+//!
+//! Available with the `serde` cargo feature. The input is read from the
+//! deserializer as a JSON value and validated by a schema, so it works with
+//! any self-describing serde format. Validation failures become errors of the
+//! format via [`serde::de::Error::custom`], with the path and code of every
+//! failing value in the message.
+//!
+//! - [`Validated<T>`] deserializes any type implementing [`Validate`].
+//! - [`deserialize`] does the same for a single field, via
+//!   `#[serde(deserialize_with = "schema_validator::serde::deserialize")]`.
+//! - [`validate_with`] runs an arbitrary schema and then deserializes the
+//!   cleaned document with the type's own `Deserialize` implementation.
+//!
+//! # Examples
+//!
+//! ```
+//! use schema_validator::Validate;
+//! use schema_validator::serde::Validated;
+//!
+//! #[derive(Debug, Clone, PartialEq, Validate)]
+//! struct User {
+//!     #[validate(trim, min_length = 2)]
+//!     name: String,
+//!     #[validate(email)]
+//!     email: String,
+//! }
+//!
+//! let user: Validated<User> = serde_json::from_str(r#"{ "name": " Ann ", "email": "ann@example.com" }"#).unwrap();
+//! assert_eq!(user.name, "Ann");
+//!
+//! let err = serde_json::from_str::<Validated<User>>(r#"{ "name": "Ann", "email": "nope" }"#).unwrap_err();
+//! assert!(err.to_string().starts_with("email: [INVALID_EMAIL] Invalid email format"));
+//! ```
+
+use std::ops::{Deref, DerefMut};
+use ::serde::de::{Deserialize, DeserializeOwned, Deserializer, Error};
+use serde_json::Value;
+use crate::schema::Schema;
+use crate::schema::mapping::Validate;
+
+/// A value that was validated by the schema of `T` while being deserialized.
+///
+/// Dereferences to the inner value; use [`Validated::into_inner`] to take it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Validated<T>(pub T);
+
+impl<T> Validated<T> {
+    /// Returns the validated value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Validated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Validated<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<'de, T: Validate> Deserialize<'de> for Validated<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Validated)
+    }
+}
+
+/// Deserializes a value and builds it with the schema of `T`.
+///
+/// Meant for `#[serde(deserialize_with = "...")]` on fields whose type
+/// implements [`Validate`].
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::Validate;
+///
+/// #[derive(Debug, Clone, PartialEq, Validate)]
+/// struct Port(#[validate(min = 1, max = 65535)] u32);
+///
+/// #[derive(Debug, serde::Deserialize)]
+/// struct Config {
+///     #[serde(deserialize_with = "schema_validator::serde::deserialize")]
+///     port: Port,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{ "port": 8080 }"#).unwrap();
+/// assert_eq!(config.port, Port(8080));
+///
+/// let err = serde_json::from_str::<Config>(r#"{ "port": 0 }"#).unwrap_err();
+/// assert!(err.to_string().starts_with("[TOO_SMALL]"));
+/// ```
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Validate,
+{
+    let value = Value::deserialize(deserializer)?;
+    T::schema().validate(&value).map_err(D::Error::custom)
+}
+
+/// Deserializes a value, validates it with `schema` and deserializes the
+/// cleaned document into `T`.
+///
+/// Transformations and coercions of the schema are applied before `T` is
+/// deserialized, as with [`Schema::validate_to_json`]. Call it from a small
+/// function to use a schema with `#[serde(deserialize_with = "...")]`.
+///
+/// # Examples
+///
+/// ```
+/// use schema_validator::{schema, Schema};
+/// use serde::Deserializer;
+///
+/// fn username<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+///     let s = schema();
+///     schema_validator::serde::validate_with(deserializer, &s.string().trim().to_lowercase().min_length(3))
+/// }
+///
+/// #[derive(Debug, serde::Deserialize)]
+/// struct Signup {
+///     #[serde(deserialize_with = "username")]
+///     username: String,
+/// }
+///
+/// let signup: Signup = serde_json::from_str(r#"{ "username": " Ann_B " }"#).unwrap();
+/// assert_eq!(signup.username, "ann_b");
+///
+/// assert!(serde_json::from_str::<Signup>(r#"{ "username": "a" }"#).is_err());
+/// ```
+pub fn validate_with<'de, D, S, T>(deserializer: D, schema: &S) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    S: Schema,
+    T: DeserializeOwned,
+{
+    let value = Value::deserialize(deserializer)?;
+    let cleaned = schema.validate_to_json(&value).map_err(D::Error::custom)?;
+    T::deserialize(cleaned).map_err(D::Error::custom)
+}
@@ -0,0 +1,104 @@
+use schema_validator::{schema, Schema};
+use schema_validator::error::ErrorType;
+use std::collections::HashMap;
+use std::any::Any;
+use serde_json::json;
+
+#[test]
+fn test_array_validation() {
+    let s = schema();
+    let schema = s.array(s.string().trim().to_lowercase());
+
+    // Typed vectors
+    let tags = vec![" Rust ".to_string(), "JSON".to_string()];
+    assert_eq!(schema.validate(&tags).unwrap(), vec!["rust", "json"]);
+
+    // Boxed vectors
+    let tags: Vec<Box<dyn Any>> = vec![Box::new("Rust".to_string()), Box::new("JSON".to_string())];
+    assert_eq!(schema.validate(&tags).unwrap(), vec!["rust", "json"]);
+
+    // JSON arrays
+    assert_eq!(schema.validate(&json!(["Rust", "JSON"])).unwrap(), vec!["rust", "json"]);
+
+    // Invalid types
+    let err = schema.validate(&"rust".to_string()).unwrap_err();
+    assert_eq!(err.code, "TYPE_ERROR");
+    assert!(err.message.contains("expected Array"));
+    assert!(schema.validate(&json!({ "tag": "rust" })).is_err());
+}
+
+#[test]
+fn test_array_length() {
+    let s = schema();
+
+    let schema = s.array(s.number()).min_items(2).max_items(3);
+    assert!(schema.validate(&vec![1.0, 2.0]).is_ok());
+    assert!(schema.validate(&vec![1.0, 2.0, 3.0]).is_ok());
+
+    let err = schema.validate(&vec![1.0]).unwrap_err();
+    assert_eq!(err.code, "MIN_ITEMS_ERROR");
+    assert_eq!(err.message, "Length error: expected at least 2 items, got 1");
+
+    let err = schema.validate(&vec![1.0, 2.0, 3.0, 4.0]).unwrap_err();
+    assert_eq!(err.code, "MAX_ITEMS_ERROR");
+    assert_eq!(err.message, "Length error: expected at most 3 items, got 4");
+
+    let schema = s.array(s.number()).length(2);
+    assert!(schema.validate(&vec![1.0, 2.0]).is_ok());
+    assert!(schema.validate(&vec![1.0, 2.0, 3.0]).is_err());
+
+    let schema = s.array(s.boolean()).nonempty();
+    assert!(schema.validate(&vec![true]).is_ok());
+    assert_eq!(schema.validate(&json!([])).unwrap_err().code, "MIN_ITEMS_ERROR");
+}
+
+#[test]
+fn test_array_element_errors() {
+    let s = schema();
+    let schema = s.array(s.string().email());
+
+    let err = schema.validate(&json!(["a@example.com", "nope", "b@example.com", 42])).unwrap_err();
+    assert_eq!(err.code, "ARRAY_ERROR");
+
+    match err.error_type {
+        ErrorType::Array { errors } => {
+            let indices: Vec<usize> = errors.iter().map(|(index, _)| *index).collect();
+            assert_eq!(indices, vec![1, 3]);
+            assert_eq!(errors[0].1.code, "INVALID_EMAIL");
+        }
+        other => panic!("unexpected error type: {:?}", other),
+    }
+
+    // Custom error message
+    let schema = s.array(s.string())
+        .nonempty()
+        .set_message("INVALID_TAGS", "At least one tag is required");
+    let err = schema.validate(&Vec::<String>::new()).unwrap_err();
+    assert_eq!(err.code, "INVALID_TAGS");
+    assert_eq!(err.message, "At least one tag is required");
+}
+
+#[test]
+fn test_array_field() {
+    let s = schema();
+    let schema = s.object()
+        .field("name", s.string())
+        .field("tags", s.array(s.string().min_length(2)).max_items(3));
+
+    // JSON input
+    let result = schema.validate(&json!({ "name": "John", "tags": ["rust", "json"] })).unwrap();
+    let tags = result.get("tags").unwrap().downcast_ref::<Vec<String>>().unwrap();
+    assert_eq!(tags, &vec!["rust".to_string(), "json".to_string()]);
+
+    // HashMap input
+    let mut obj = HashMap::new();
+    obj.insert("name".to_string(), Box::new("John".to_string()) as Box<dyn Any>);
+    obj.insert("tags".to_string(), Box::new(vec![Box::new("rust".to_string()) as Box<dyn Any>]) as Box<dyn Any>);
+    assert!(schema.validate(&obj).is_ok());
+
+    // Element error inside a field
+    let err = schema.validate(&json!({ "name": "John", "tags": ["rust", "x"] })).unwrap_err();
+    assert_eq!(err.code, "OBJECT_ERROR");
+    assert!(err.message.contains("tags[1]"));
+    assert!(err.message.contains("MIN_LENGTH_ERROR"));
+}
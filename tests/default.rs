@@ -0,0 +1,155 @@
+use schema_validator::{schema, Schema, Validate};
+use serde_json::json;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+fn test_default_for_missing_fields() {
+    let s = schema();
+    let schema = s.object()
+        .field("name", s.string())
+        .field("role", s.string().trim().to_lowercase().default(" Viewer "))
+        .field("limit", s.integer().min(1).default(10))
+        .field("tags", s.array(s.string()).default(json!([])));
+
+    assert_eq!(schema.validate_to_json(&json!({ "name": "Ann" })).unwrap(), json!({
+        "name": "Ann",
+        "role": "viewer",
+        "limit": 10,
+        "tags": []
+    }));
+    // Present values are validated as given
+    assert_eq!(schema.validate_to_json(&json!({ "name": "Ann", "role": "ADMIN", "limit": 5, "tags": ["x"] })).unwrap(), json!({
+        "name": "Ann",
+        "role": "admin",
+        "limit": 5,
+        "tags": ["x"]
+    }));
+    assert!(schema.validate(&json!({ "name": "Ann", "limit": 0 })).is_err());
+    assert!(schema.validate(&json!({ "name": "Ann", "limit": null })).is_err());
+
+    // Invalid defaults fail like invalid input
+    let schema = s.object().field("limit", s.integer().min(1).default(0));
+    let err = schema.validate(&json!({})).unwrap_err();
+    assert_eq!(err.flatten()[0].1.code, "TOO_SMALL");
+}
+
+#[test]
+fn test_default_on_null() {
+    let s = schema();
+    let schema = s.object().field("page", s.integer().default(1).on_null());
+
+    let fields = schema.validate(&json!({ "page": null })).unwrap();
+    assert_eq!(fields["page"].downcast_ref::<i64>(), Some(&1));
+    let fields = schema.validate(&json!({})).unwrap();
+    assert_eq!(fields["page"].downcast_ref::<i64>(), Some(&1));
+    let fields = schema.validate(&json!({ "page": 4 })).unwrap();
+    assert_eq!(fields["page"].downcast_ref::<i64>(), Some(&4));
+
+    // Outside of objects only null is replaced
+    let schema = s.string().default("x").on_null();
+    assert_eq!(schema.validate(&json!(null)).unwrap(), "x");
+    assert_eq!(schema.validate(&None::<()>).unwrap(), "x");
+    assert!(s.string().default("x").validate(&json!(null)).is_err());
+}
+
+#[test]
+fn test_default_object_schema() {
+    use schema_validator::schema::object::ObjectSchema;
+
+    // `default` on an object schema is the builder method
+    let s = schema();
+    let author = ObjectSchema::new()
+        .field("name", s.string())
+        .default(json!({ "name": "anonymous" }));
+    let fields = author.validate_default().unwrap().unwrap();
+    assert_eq!(fields["name"].downcast_ref::<String>().unwrap(), "anonymous");
+
+    let schema = s.object().field("author", author);
+    assert_eq!(schema.validate_to_json(&json!({})).unwrap(), json!({ "author": { "name": "anonymous" } }));
+}
+
+#[test]
+fn test_default_with() {
+    let s = schema();
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    let schema = s.object().field("id", s.integer().default_with(move || {
+        counter.set(counter.get() + 1);
+        counter.get()
+    }));
+
+    let first = schema.validate(&json!({})).unwrap();
+    let second = schema.validate(&json!({})).unwrap();
+    assert_eq!(first["id"].downcast_ref::<i64>(), Some(&1));
+    assert_eq!(second["id"].downcast_ref::<i64>(), Some(&2));
+    schema.validate(&json!({ "id": 7 })).unwrap();
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn test_default_json_schema() {
+    let s = schema();
+    let schema = s.object()
+        .field("name", s.string())
+        .field("role", s.enumeration(["admin", "viewer"]).default("viewer"))
+        .field("nickname", s.string().optional());
+
+    assert_eq!(schema.to_json_schema(), json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string" },
+            "role": { "enum": ["admin", "viewer"], "default": "viewer" },
+            "nickname": { "type": ["string", "null"] }
+        },
+        "required": ["name", "nickname"]
+    }));
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Settings {
+    #[validate(default = "light")]
+    theme: String,
+    #[validate(min = 1, default = 20)]
+    page_size: u32,
+    #[validate(default = "en")]
+    language: Option<String>,
+    notifications: bool,
+}
+
+#[test]
+fn test_derived_defaults() {
+    let settings = Settings::schema().validate(&json!({ "notifications": true })).unwrap();
+    assert_eq!(settings, Settings {
+        theme: "light".to_string(),
+        page_size: 20,
+        language: Some("en".to_string()),
+        notifications: true,
+    });
+
+    let settings = Settings::schema().validate(&json!({ "language": null, "notifications": false, "page_size": 5 })).unwrap();
+    assert_eq!(settings.language, None);
+    assert_eq!(settings.page_size, 5);
+
+    let err = Settings::schema().validate(&json!({})).unwrap_err();
+    assert_eq!(err.flatten().len(), 1);
+    assert_eq!(err.flatten()[0].1.dotted_path(), "notifications");
+
+    let exported = Settings::schema().to_json_schema();
+    assert_eq!(exported["properties"]["page_size"]["default"], 20);
+    assert_eq!(exported["properties"]["language"], json!({ "type": ["string", "null"], "default": "en" }));
+    assert_eq!(exported["required"], json!(["notifications"]));
+}
+
+#[test]
+fn test_load_json_schema_defaults() {
+    let s = schema();
+    let original = s.object()
+        .field("name", s.string())
+        .field("limit", s.integer().min(1).default(10));
+    let document = schema_validator::schema::json_schema::document(&original);
+    let loaded = schema_validator::schema::json_schema::schema_from_json_schema(&document).unwrap();
+
+    assert_eq!(loaded.validate_to_json(&json!({ "name": "Ann" })).unwrap(), json!({ "name": "Ann", "limit": 10 }));
+    assert_eq!(loaded.to_json_schema(), original.to_json_schema());
+}
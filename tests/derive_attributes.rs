@@ -0,0 +1,104 @@
+use schema_validator::{Schema, Validate};
+use serde_json::json;
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct User {
+    #[validate(min_length = 2, max_length = 20)]
+    name: String,
+    #[validate(trim, lowercase, email, max_length = 50)]
+    email: String,
+    #[validate(min = 0, max = 150)]
+    age: u8,
+    #[validate(pattern = r"^\+?[0-9]{7,15}$", message(code = "INVALID_PHONE", text = "Invalid phone number"))]
+    phone: Option<String>,
+    #[validate(rename = "isActive")]
+    active: bool,
+    #[validate(positive, multiple_of = 0.5)]
+    score: f64,
+}
+
+#[test]
+fn test_derived_schema() {
+    let schema = User::schema();
+
+    let user = schema.validate(&json!({
+        "name": "John",
+        "email": "  John@Example.COM ",
+        "age": 30,
+        "phone": "+4915112345678",
+        "isActive": true,
+        "score": 2.5
+    })).unwrap();
+
+    assert_eq!(user, User {
+        name: "John".to_string(),
+        email: "john@example.com".to_string(),
+        age: 30,
+        phone: Some("+4915112345678".to_string()),
+        active: true,
+        score: 2.5,
+    });
+
+    let user = schema.validate(&json!({
+        "name": "Jane",
+        "email": "jane@example.com",
+        "age": 0,
+        "phone": null,
+        "isActive": false,
+        "score": 0.5
+    })).unwrap();
+    assert_eq!(user.phone, None);
+}
+
+#[test]
+fn test_derived_schema_errors() {
+    let schema = User::schema();
+
+    let err = schema.validate(&json!({
+        "name": "J",
+        "email": "not-an-email",
+        "age": 151,
+        "phone": "call me",
+        "isActive": "yes",
+        "score": 1.2
+    })).unwrap_err();
+
+    let leaves: Vec<(String, String)> = err.flatten()
+        .into_iter()
+        .map(|(_, leaf)| (leaf.dotted_path(), leaf.code.clone()))
+        .collect();
+    assert_eq!(leaves, vec![
+        ("age".to_string(), "TOO_LARGE".to_string()),
+        ("email".to_string(), "INVALID_EMAIL".to_string()),
+        ("isActive".to_string(), "TYPE_ERROR".to_string()),
+        ("name".to_string(), "MIN_LENGTH_ERROR".to_string()),
+        ("phone".to_string(), "INVALID_PHONE".to_string()),
+        ("score".to_string(), "NOT_MULTIPLE_OF".to_string()),
+    ]);
+
+    let err = schema.validate(&json!({ "name": "John" })).unwrap_err();
+    assert!(err.flatten().iter().any(|(_, leaf)| leaf.code == "MISSING_FIELD" && leaf.dotted_path() == "isActive"));
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+#[validate(tag = "type")]
+enum Contact {
+    Email {
+        #[validate(email)]
+        address: String,
+    },
+    Phone(#[validate(phone)] String),
+}
+
+#[test]
+fn test_variant_field_attributes() {
+    let schema = Contact::schema();
+
+    assert_eq!(
+        schema.validate(&json!({ "type": "Email", "address": "a@example.com" })).unwrap(),
+        Contact::Email { address: "a@example.com".to_string() },
+    );
+
+    let err = schema.validate(&json!({ "type": "Email", "address": "nope" })).unwrap_err();
+    assert_eq!(err.flatten()[0].1.code, "INVALID_EMAIL");
+}
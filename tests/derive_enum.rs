@@ -0,0 +1,160 @@
+use schema_validator::{schema, Schema, Validate};
+use serde_json::json;
+use std::any::Any;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+enum Role {
+    #[validate(rename = "admin")]
+    Admin,
+    #[validate(rename = "editor")]
+    Editor,
+    Viewer,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+enum Command {
+    Quit,
+    Move { x: i64, y: i64 },
+    Write(String),
+    #[validate(rename = "color")]
+    ChangeColor { #[validate(rename = "hex")] value: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+#[validate(tag = "type")]
+enum Event {
+    #[validate(rename = "click")]
+    Click { x: i64, y: i64 },
+    #[validate(rename = "key")]
+    Key { code: String, shift: Option<bool> },
+    #[validate(rename = "resize")]
+    Resize(Size),
+    #[validate(rename = "blur")]
+    Blur,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Size {
+    width: i64,
+    height: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+#[validate(tag = "kind", content = "data")]
+enum Message {
+    Text(String),
+    Ping,
+    Roles { roles: Vec<Role> },
+}
+
+#[test]
+fn test_unit_enum_rename() {
+    let role_schema = Role::schema();
+    assert_eq!(role_schema.validate(&"admin".to_string()).unwrap(), Role::Admin);
+    assert_eq!(role_schema.validate(&json!("Viewer")).unwrap(), Role::Viewer);
+
+    let err = role_schema.validate(&"Admin".to_string()).unwrap_err();
+    assert_eq!(err.code, "ENUM_ERROR");
+    assert_eq!(err.message, "Enum error: expected one of 'admin', 'editor', 'Viewer', got 'Admin'");
+
+    // The derived schema composes with the builder
+    let s = schema();
+    let schema = s.object().field("role", Role::schema());
+    let result = schema.validate(&json!({ "role": "editor" })).unwrap();
+    assert_eq!(result.get("role").unwrap().downcast_ref::<Role>(), Some(&Role::Editor));
+}
+
+#[test]
+fn test_externally_tagged_enum() {
+    let schema = Command::schema();
+
+    assert_eq!(schema.validate(&json!("Quit")).unwrap(), Command::Quit);
+    assert_eq!(schema.validate(&json!({ "Move": { "x": 1, "y": -2 } })).unwrap(), Command::Move { x: 1, y: -2 });
+    assert_eq!(schema.validate(&json!({ "Write": "hello" })).unwrap(), Command::Write("hello".to_string()));
+    assert_eq!(
+        schema.validate(&json!({ "color": { "hex": "#fff" } })).unwrap(),
+        Command::ChangeColor { value: "#fff".to_string() },
+    );
+
+    // HashMap input works the same way
+    let mut inner = HashMap::new();
+    inner.insert("x".to_string(), Box::new(3_i64) as Box<dyn Any>);
+    inner.insert("y".to_string(), Box::new(4_i64) as Box<dyn Any>);
+    let mut outer = HashMap::new();
+    outer.insert("Move".to_string(), Box::new(inner) as Box<dyn Any>);
+    assert_eq!(schema.validate(&outer).unwrap(), Command::Move { x: 3, y: 4 });
+
+    let err = schema.validate(&json!({ "Move": { "x": 1 } })).unwrap_err();
+    assert_eq!(err.flatten()[0].1.dotted_path(), "Move.y");
+
+    assert_eq!(schema.validate(&json!("Jump")).unwrap_err().code, "ENUM_ERROR");
+    assert_eq!(schema.validate(&json!("Move")).unwrap_err().code, "TYPE_ERROR");
+    assert_eq!(schema.validate(&json!({ "Quit": null, "Move": {} })).unwrap_err().code, "TYPE_ERROR");
+
+    // Unit variants may be written as a key with null content, but not with data
+    assert_eq!(schema.validate(&json!({ "Quit": null })).unwrap(), Command::Quit);
+    let err = schema.validate(&json!({ "Quit": { "now": true } })).unwrap_err();
+    assert_eq!(err.code, "TYPE_ERROR");
+    assert_eq!(err.dotted_path(), "Quit");
+}
+
+#[test]
+fn test_internally_tagged_enum() {
+    let schema = Event::schema();
+
+    assert_eq!(
+        schema.validate(&json!({ "type": "click", "x": 10, "y": 20 })).unwrap(),
+        Event::Click { x: 10, y: 20 },
+    );
+    assert_eq!(
+        schema.validate(&json!({ "type": "key", "code": "KeyA", "shift": null })).unwrap(),
+        Event::Key { code: "KeyA".to_string(), shift: None },
+    );
+    assert_eq!(schema.validate(&json!({ "type": "blur" })).unwrap(), Event::Blur);
+
+    // Newtype variants validate the fields next to the tag
+    assert_eq!(
+        schema.validate(&json!({ "type": "resize", "width": 640, "height": 480 })).unwrap(),
+        Event::Resize(Size { width: 640, height: 480 }),
+    );
+    let err = schema.validate(&json!({ "type": "resize", "width": 640 })).unwrap_err();
+    assert_eq!(err.flatten()[0].1.dotted_path(), "height");
+
+    let mut input: HashMap<String, Box<dyn Any>> = HashMap::new();
+    input.insert("type".to_string(), Box::new("click".to_string()));
+    input.insert("x".to_string(), Box::new(1_i64));
+    input.insert("y".to_string(), Box::new(2_i64));
+    assert_eq!(schema.validate(&input).unwrap(), Event::Click { x: 1, y: 2 });
+
+    let err = schema.validate(&json!({ "type": "scroll" })).unwrap_err();
+    assert_eq!(err.code, "INVALID_DISCRIMINATOR");
+    assert_eq!(err.dotted_path(), "type");
+
+    let err = schema.validate(&json!({ "x": 1 })).unwrap_err();
+    assert_eq!(err.code, "MISSING_FIELD");
+}
+
+#[test]
+fn test_adjacently_tagged_enum() {
+    let schema = Message::schema();
+
+    assert_eq!(
+        schema.validate(&json!({ "kind": "Text", "data": "hi" })).unwrap(),
+        Message::Text("hi".to_string()),
+    );
+    assert_eq!(schema.validate(&json!({ "kind": "Ping" })).unwrap(), Message::Ping);
+    assert_eq!(
+        schema.validate(&json!({ "kind": "Roles", "data": { "roles": ["admin", "Viewer"] } })).unwrap(),
+        Message::Roles { roles: vec![Role::Admin, Role::Viewer] },
+    );
+
+    let err = schema.validate(&json!({ "kind": "Roles", "data": { "roles": ["owner"] } })).unwrap_err();
+    let leaves = err.flatten();
+    assert_eq!(leaves[0].1.code, "ENUM_ERROR");
+    assert_eq!(leaves[0].1.dotted_path(), "data.roles[0]");
+
+    let err = schema.validate(&json!({ "kind": "Text" })).unwrap_err();
+    assert_eq!(err.code, "MISSING_FIELD");
+    assert_eq!(err.dotted_path(), "data");
+}
@@ -0,0 +1,110 @@
+use schema_validator::{schema, Schema, ValidateAs, Validate};
+use serde_json::json;
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Meters(#[validate(min = 0)] f64);
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Point(f64, f64);
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Page<T> {
+    items: Vec<T>,
+    #[validate(min = 1)]
+    page: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Pair<A, B>(A, B)
+where
+    A: PartialEq;
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+enum Shape {
+    Circle(Point, f64),
+    Segment { from: Point, to: Point },
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Route {
+    name: String,
+    length: Meters,
+    waypoints: Vec<Point>,
+}
+
+#[test]
+fn test_newtype_struct() {
+    let schema = Meters::schema();
+
+    assert_eq!(schema.validate(&12.5).unwrap(), Meters(12.5));
+    assert_eq!(schema.validate(&-1.0).unwrap_err().code, "TOO_SMALL");
+    assert_eq!(schema.validate(&"far".to_string()).unwrap_err().code, "TYPE_ERROR");
+}
+
+#[test]
+fn test_tuple_struct() {
+    let schema = Point::schema();
+
+    assert_eq!(schema.validate(&json!([1.0, 2.5])).unwrap(), Point(1.0, 2.5));
+    assert_eq!(schema.validate(&json!([1.0])).unwrap_err().code, "MIN_ITEMS_ERROR");
+    assert_eq!(schema.validate(&json!([1.0, 2.0, 3.0])).unwrap_err().code, "MAX_ITEMS_ERROR");
+
+    let err = schema.validate(&json!([1.0, "x"])).unwrap_err();
+    assert_eq!(err.flatten()[0].1.dotted_path(), "[1]");
+}
+
+#[test]
+fn test_generic_struct() {
+    let schema = Page::<Point>::schema();
+
+    let page = schema.validate(&json!({ "items": [[0, 0], [1, 1]], "page": 2 })).unwrap();
+    assert_eq!(page, Page { items: vec![Point(0.0, 0.0), Point(1.0, 1.0)], page: 2 });
+
+    let err = schema.validate(&json!({ "items": [[0, 0], [1]], "page": 0 })).unwrap_err();
+    let mut leaves: Vec<String> = err.flatten().into_iter().map(|(_, leaf)| leaf.dotted_path()).collect();
+    leaves.sort();
+    assert_eq!(leaves, vec!["items[1]", "page"]);
+
+    let schema = Pair::<String, Meters>::schema();
+    let pair = schema.validate(&json!(["height", 3])).unwrap();
+    assert_eq!(pair, Pair("height".to_string(), Meters(3.0)));
+}
+
+#[test]
+fn test_tuple_variant() {
+    let schema = Shape::schema();
+
+    assert_eq!(
+        schema.validate(&json!({ "Circle": [[0, 0], 2.5] })).unwrap(),
+        Shape::Circle(Point(0.0, 0.0), 2.5),
+    );
+    assert_eq!(
+        schema.validate(&json!({ "Segment": { "from": [0, 0], "to": [1, 2] } })).unwrap(),
+        Shape::Segment { from: Point(0.0, 0.0), to: Point(1.0, 2.0) },
+    );
+
+    let err = schema.validate(&json!({ "Circle": [[0, 0], "big"] })).unwrap_err();
+    assert_eq!(err.flatten()[0].1.dotted_path(), "Circle[1]");
+}
+
+#[test]
+fn test_validate_as_tuple_fields() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("name", s.string())
+        .field("length", s.number())
+        .field("waypoints", s.array(s.tuple(vec![s.number().boxed(), s.number().boxed()])));
+
+    let route: Route = schema.validate_as(&json!({
+        "name": "Ring",
+        "length": 1200,
+        "waypoints": [[52.5, 13.4], [52.6, 13.3]]
+    })).unwrap();
+
+    assert_eq!(route, Route {
+        name: "Ring".to_string(),
+        length: Meters(1200.0),
+        waypoints: vec![Point(52.5, 13.4), Point(52.6, 13.3)],
+    });
+}
@@ -0,0 +1,132 @@
+use schema_validator::{schema, Schema, ValidateAs, Validate};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Geo {
+    lat: f64,
+    lng: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Address {
+    #[validate(min_length = 2)]
+    city: String,
+    geo: Option<Geo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Profile {
+    name: String,
+    address: Address,
+    previous: Vec<Address>,
+    tags: Vec<String>,
+    scores: HashMap<String, i64>,
+    nickname: Option<String>,
+}
+
+#[test]
+fn test_validate_as_nested() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("name", s.string())
+        .field("address", s.object()
+            .field("city", s.string())
+            .field("geo", s.object()
+                .field("lat", s.number())
+                .field("lng", s.number())
+                .optional()))
+        .field("previous", s.array(s.object()
+            .field("city", s.string())
+            .field("geo", s.object()
+                .field("lat", s.number())
+                .field("lng", s.number())
+                .optional())))
+        .field("tags", s.array(s.string()))
+        .field("scores", s.record(s.integer()))
+        .field("nickname", s.string().optional());
+
+    let profile: Profile = schema.validate_as(&json!({
+        "name": "John",
+        "address": { "city": "Berlin", "geo": { "lat": 52.52, "lng": 13.4 } },
+        "previous": [{ "city": "Paris", "geo": null }],
+        "tags": ["admin"],
+        "scores": { "math": 90 },
+        "nickname": null
+    })).unwrap();
+
+    assert_eq!(profile, Profile {
+        name: "John".to_string(),
+        address: Address {
+            city: "Berlin".to_string(),
+            geo: Some(Geo { lat: 52.52, lng: 13.4 }),
+        },
+        previous: vec![Address { city: "Paris".to_string(), geo: None }],
+        tags: vec!["admin".to_string()],
+        scores: HashMap::from([("math".to_string(), 90)]),
+        nickname: None,
+    });
+}
+
+#[test]
+fn test_validate_as_nested_wrong_type() {
+    let s = schema();
+
+    // The nested object has no `geo` field, so it cannot become an `Address`
+    let schema = s.object()
+        .field("name", s.string())
+        .field("address", s.object().field("city", s.string()))
+        .field("previous", s.array(s.string()))
+        .field("tags", s.array(s.string()))
+        .field("scores", s.record(s.integer()))
+        .field("nickname", s.string().optional());
+
+    let result: Result<Profile, _> = schema.validate_as(&json!({
+        "name": "John",
+        "address": { "city": "Berlin" },
+        "previous": [],
+        "tags": [],
+        "scores": {},
+        "nickname": null
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_derived_nested_schema() {
+    let schema = Profile::schema();
+
+    let profile = schema.validate(&json!({
+        "name": "John",
+        "address": { "city": "Berlin", "geo": null },
+        "previous": [{ "city": "Paris", "geo": { "lat": 48.86, "lng": 2.35 } }],
+        "tags": [],
+        "scores": { "math": 90, "art": 75 },
+        "nickname": "JJ"
+    })).unwrap();
+
+    assert_eq!(profile.previous[0].geo, Some(Geo { lat: 48.86, lng: 2.35 }));
+    assert_eq!(profile.scores["art"], 75);
+    assert_eq!(profile.nickname.as_deref(), Some("JJ"));
+
+    let err = schema.validate(&json!({
+        "name": "John",
+        "address": { "city": "B", "geo": null },
+        "previous": [{ "city": "Paris", "geo": { "lat": "north", "lng": 2.35 } }],
+        "tags": [],
+        "scores": { "math": 1.5 },
+        "nickname": null
+    })).unwrap_err();
+
+    let mut leaves: Vec<(String, String)> = err.flatten()
+        .into_iter()
+        .map(|(_, leaf)| (leaf.dotted_path(), leaf.code.clone()))
+        .collect();
+    leaves.sort();
+    assert_eq!(leaves, vec![
+        ("address.city".to_string(), "MIN_LENGTH_ERROR".to_string()),
+        ("previous[0].geo.lat".to_string(), "TYPE_ERROR".to_string()),
+        ("scores.math".to_string(), "NOT_INTEGER".to_string()),
+    ]);
+}
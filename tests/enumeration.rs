@@ -0,0 +1,71 @@
+use schema_validator::{schema, Schema, Validate};
+use schema_validator::error::ErrorType;
+use serde_json::json;
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+enum Role {
+    Admin,
+    Editor,
+    Viewer,
+}
+
+#[test]
+fn test_string_enumeration() {
+    let s = schema();
+
+    let schema = s.enumeration(["admin", "editor", "viewer"]);
+    assert_eq!(schema.validate(&"admin".to_string()).unwrap(), "admin");
+    assert_eq!(schema.validate(&json!("viewer")).unwrap(), "viewer");
+    assert!(schema.validate(&"Admin".to_string()).is_err());
+
+    let err = schema.validate(&"owner".to_string()).unwrap_err();
+    assert_eq!(err.code, "ENUM_ERROR");
+    match &err.error_type {
+        ErrorType::Enum { expected, got } => {
+            assert_eq!(expected, &vec!["admin".to_string(), "editor".to_string(), "viewer".to_string()]);
+            assert_eq!(got, "owner");
+        }
+        other => panic!("unexpected error type: {:?}", other),
+    }
+
+    // Values of another type are rejected as well
+    let err = schema.validate(&42_i64).unwrap_err();
+    assert_eq!(err.message, "Enum error: expected one of 'admin', 'editor', 'viewer', got '42'");
+
+    let schema = s.enumeration(vec!["a".to_string(), "b".to_string()]).case_insensitive();
+    assert_eq!(schema.validate(&"B".to_string()).unwrap(), "b");
+}
+
+#[test]
+fn test_numeric_enumeration() {
+    let s = schema();
+
+    let schema = s.enumeration([1_i64, 2, 3]);
+    assert_eq!(schema.validate(&2_i64).unwrap(), 2);
+    assert_eq!(schema.validate(&2.0).unwrap(), 2);
+    assert_eq!(schema.validate(&json!(3)).unwrap(), 3);
+    assert!(schema.validate(&4_i64).is_err());
+    assert!(schema.validate(&"1".to_string()).is_err());
+
+    let schema = s.enumeration([0.5, 1.5]);
+    assert_eq!(schema.validate(&json!(1.5)).unwrap(), 1.5);
+}
+
+#[test]
+fn test_native_enum() {
+    let s = schema();
+
+    let schema = s.native_enum::<Role>();
+    assert_eq!(schema.validate(&"Viewer".to_string()).unwrap(), Role::Viewer);
+    assert!(schema.validate(&"viewer".to_string()).is_err());
+
+    let schema = s.object()
+        .field("role", s.native_enum::<Role>().case_insensitive());
+    let result = schema.validate(&json!({ "role": "editor" })).unwrap();
+    assert_eq!(result.get("role").unwrap().downcast_ref::<Role>(), Some(&Role::Editor));
+
+    let err = schema.validate(&json!({ "role": "owner" })).unwrap_err();
+    let leaves = err.flatten();
+    assert_eq!(leaves[0].1.code, "ENUM_ERROR");
+    assert_eq!(leaves[0].1.dotted_path(), "role");
+}
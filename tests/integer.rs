@@ -0,0 +1,108 @@
+use schema_validator::{schema, Schema};
+use schema_validator::error::ErrorType;
+use serde_json::json;
+use std::any::Any;
+use std::collections::HashMap;
+
+#[test]
+fn test_integer_native_and_json() {
+    let s = schema();
+
+    let schema = s.integer();
+    assert_eq!(schema.validate(&42_i64).unwrap(), 42);
+    assert_eq!(schema.validate(&42_i32).unwrap(), 42);
+    assert_eq!(schema.validate(&42_u8).unwrap(), 42);
+    assert_eq!(schema.validate(&42.0).unwrap(), 42);
+
+    // JSON integers do not go through f64
+    assert_eq!(schema.validate(&json!(9007199254740993_i64)).unwrap(), 9007199254740993);
+    assert_eq!(s.u64().validate(&json!(u64::MAX)).unwrap(), u64::MAX);
+
+    let err = schema.validate(&"42".to_string()).unwrap_err();
+    assert_eq!(err.code, "TYPE_ERROR");
+    assert_eq!(err.message, "Type error: expected Integer, got String");
+}
+
+#[test]
+fn test_integer_fraction_and_overflow() {
+    let s = schema();
+
+    let err = s.integer().validate(&1.5).unwrap_err();
+    assert_eq!(err.code, "NOT_INTEGER");
+    assert!(s.integer().validate(&json!(2.5)).is_err());
+    assert!(s.integer().validate(&f64::NAN).is_err());
+
+    let err = s.u8().validate(&256_i64).unwrap_err();
+    assert_eq!(err.code, "INTEGER_OVERFLOW");
+    assert_eq!(err.message, "Range error: 256 is out of range for u8");
+    assert!(matches!(err.error_type, ErrorType::Overflow { target: "u8", .. }));
+
+    assert!(s.u32().validate(&-1_i64).is_err());
+    assert!(s.i16().validate(&json!(40000)).is_err());
+    assert!(s.i64().validate(&json!(u64::MAX)).is_err());
+    assert!(s.i64().validate(&1e30).is_err());
+}
+
+#[test]
+fn test_integer_coercion_and_bounds() {
+    let s = schema();
+
+    let schema = s.coerce().u32();
+    assert_eq!(schema.validate(&"  7 ".to_string()).unwrap(), 7_u32);
+    assert_eq!(schema.validate(&"7.0".to_string()).unwrap(), 7_u32);
+    assert_eq!(schema.validate(&true).unwrap(), 1_u32);
+    assert_eq!(schema.validate(&"7.5".to_string()).unwrap_err().code, "NOT_INTEGER");
+    assert_eq!(schema.validate(&"-7".to_string()).unwrap_err().code, "INTEGER_OVERFLOW");
+    assert_eq!(schema.validate(&"seven".to_string()).unwrap_err().code, "COERCION_ERROR");
+
+    let schema = s.i8().min(-10).max(10).abort_early(false);
+    assert!(schema.validate(&10_i64).is_ok());
+    assert_eq!(schema.validate(&-11_i64).unwrap_err().code, "TOO_SMALL");
+    assert_eq!(schema.validate(&11_i64).unwrap_err().code, "TOO_LARGE");
+}
+
+#[test]
+fn test_integer_fields() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("id", s.u64())
+        .field("age", s.u8().optional())
+        .field("score", s.number());
+
+    let json = json!({ "id": 18446744073709551615_u64, "age": 30, "score": 7 });
+    let result = schema.validate(&json).unwrap();
+    assert_eq!(*result.get("id").unwrap().downcast_ref::<u64>().unwrap(), u64::MAX);
+    assert_eq!(*result.get("age").unwrap().downcast_ref::<Option<u8>>().unwrap(), Some(30));
+    assert_eq!(*result.get("score").unwrap().downcast_ref::<f64>().unwrap(), 7.0);
+
+    let mut obj = HashMap::new();
+    obj.insert("id".to_string(), Box::new(1_i64) as Box<dyn Any>);
+    obj.insert("age".to_string(), Box::new(300_i64) as Box<dyn Any>);
+    obj.insert("score".to_string(), Box::new(1.5) as Box<dyn Any>);
+
+    let err = schema.validate(&obj).unwrap_err();
+    assert_eq!(err.flatten()[0].1.dotted_path(), "age");
+    assert_eq!(err.flatten()[0].1.code, "INTEGER_OVERFLOW");
+
+    // Map values of the sized integer types are read as they are
+    let schema = s.object()
+        .field("rank", s.i32())
+        .field("level", s.u8().optional());
+    let mut obj = HashMap::new();
+    obj.insert("rank".to_string(), Box::new(5_i32) as Box<dyn Any>);
+    obj.insert("level".to_string(), Box::new(Some(3_u8)) as Box<dyn Any>);
+    let result = schema.validate(&obj).unwrap();
+    assert_eq!(result["rank"].downcast_ref::<i32>(), Some(&5));
+    assert_eq!(result["level"].downcast_ref::<Option<u8>>(), Some(&Some(3)));
+}
+
+#[test]
+fn test_optional_sized_integers() {
+    let s = schema();
+
+    assert_eq!(s.u32().optional().validate(&Some(5_u32)).unwrap(), Some(5));
+    assert_eq!(s.i8().optional().validate(&None::<i8>).unwrap(), None);
+    assert_eq!(s.i16().optional().validate(&Some(-7_i16)).unwrap(), Some(-7));
+    assert_eq!(s.u8().optional().validate(&Some(300_u16)).unwrap_err().code, "INTEGER_OVERFLOW");
+}
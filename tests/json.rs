@@ -0,0 +1,169 @@
+use schema_validator::{schema, Schema};
+use serde_json::json;
+
+#[test]
+fn test_json_scalars() {
+    let s = schema();
+
+    assert_eq!(s.string().email().validate(&json!("user@example.com")).unwrap(), "user@example.com");
+    assert_eq!(s.string().validate(&json!(42)).unwrap_err().code, "TYPE_ERROR");
+    assert_eq!(s.coerce().string().validate(&json!(42)).unwrap(), "42");
+
+    assert_eq!(s.number().validate(&json!(1.5)).unwrap(), 1.5);
+    assert_eq!(s.number().validate(&json!(2)).unwrap(), 2.0);
+    assert_eq!(s.number().min(0.0).validate(&json!(-1)).unwrap_err().code, "TOO_SMALL");
+    assert_eq!(s.coerce().number().validate(&json!("3.5")).unwrap(), 3.5);
+
+    assert!(s.boolean().validate(&json!(true)).unwrap());
+    assert_eq!(s.boolean().validate(&json!("true")).unwrap_err().code, "TYPE_ERROR");
+
+    assert_eq!(s.coerce().integer().validate(&json!(" 7 ")).unwrap(), 7);
+
+    let transformed = s.string().transform(|s| s.len());
+    assert_eq!(transformed.validate(&json!("four")).unwrap(), 4);
+}
+
+#[test]
+fn test_json_literals() {
+    let s = schema();
+
+    assert_eq!(s.literal("tuna").validate(&json!("tuna")).unwrap(), "tuna");
+    assert!(s.literal("tuna").validate(&json!("salmon")).is_err());
+    assert_eq!(s.literal(42_i64).validate(&json!(42)).unwrap(), 42);
+    assert_eq!(s.literal(1.0).validate(&json!(1)).unwrap(), 1.0);
+    assert!(s.literal(true).validate(&json!(true)).unwrap());
+    assert_eq!(s.literal(true).validate(&json!(false)).unwrap_err().code, "LITERAL_ERROR");
+}
+
+#[test]
+fn test_json_null_and_optional() {
+    let s = schema();
+    let schema = s.string().optional();
+
+    assert_eq!(schema.validate(&json!(null)).unwrap(), None);
+    assert_eq!(schema.validate(&json!("hi")).unwrap(), Some("hi".to_string()));
+    assert!(schema.validate(&json!(1)).is_err());
+
+    assert_eq!(s.string().validate(&json!(null)).unwrap_err().code, "TYPE_ERROR");
+}
+
+#[test]
+fn test_json_root_array() {
+    let s = schema();
+    let schema = s.array(s.object().field("id", s.integer()));
+
+    let items = schema.validate(&json!([{ "id": 1 }, { "id": 2 }])).unwrap();
+    assert_eq!(items.len(), 2);
+
+    let err = schema.validate(&json!([{ "id": 1 }, { "id": "two" }])).unwrap_err();
+    assert_eq!(err.flatten()[0].1.dotted_path(), "[1].id");
+
+    let schema = s.string().or(s.number());
+    assert_eq!(schema.validate(&json!(3)).unwrap().index(), 1);
+}
+
+#[test]
+fn test_validate_to_json() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("name", s.string().trim())
+        .field("id", s.u64())
+        .field("score", s.coerce().number())
+        .field("address", s.object()
+            .field("city", s.string().to_uppercase())
+            .field("zip", s.string().optional()))
+        .field("roles", s.array(s.enumeration(["admin", "editor"]).case_insensitive()))
+        .field("limits", s.record(s.integer()))
+        .field("contact", s.string().email().or(s.integer()));
+
+    let cleaned = schema.validate_to_json(&json!({
+        "name": " John ",
+        "id": 18446744073709551615_u64,
+        "score": "9.5",
+        "address": { "city": "berlin", "zip": null },
+        "roles": ["ADMIN"],
+        "limits": { "daily": 10 },
+        "contact": 5551234
+    })).unwrap();
+
+    assert_eq!(cleaned, json!({
+        "name": "John",
+        "id": 18446744073709551615_u64,
+        "score": 9.5,
+        "address": { "city": "BERLIN", "zip": null },
+        "roles": ["admin"],
+        "limits": { "daily": 10 },
+        "contact": 5551234
+    }));
+
+    // Root arrays and scalars
+    assert_eq!(s.array(s.string().trim()).validate_to_json(&json!([" a ", "b "])).unwrap(), json!(["a", "b"]));
+    assert_eq!(s.coerce().boolean().validate_to_json(&json!(1)).unwrap(), json!(true));
+
+    // Validation errors are returned unchanged
+    let err = schema.validate_to_json(&json!({})).unwrap_err();
+    assert_eq!(err.code, "OBJECT_ERROR");
+
+    // Numbers JSON cannot represent are rejected instead of becoming null
+    let schema = s.object().field("ratio", s.number().transform(|n| n / 0.0));
+    let err = schema.validate_to_json(&json!({ "ratio": 1 })).unwrap_err();
+    assert_eq!(err.code, "TYPE_ERROR");
+    assert_eq!(err.dotted_path(), "ratio");
+    assert_eq!(err.message, "Type error: expected finite number, got Infinity");
+}
+
+#[derive(Debug, Clone)]
+struct Tag(String);
+
+impl schema_validator::schema::clone::CloneAny for Tag {
+    fn clone_any(&self) -> Box<dyn std::any::Any> {
+        Box::new(self.clone())
+    }
+}
+
+#[test]
+fn test_validate_to_json_custom_output() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("tags", s.array(s.string().transform(Tag)));
+
+    let tags = schema.validate(&json!({ "tags": ["ab"] })).unwrap();
+    assert_eq!(tags["tags"].downcast_ref::<Vec<Tag>>().unwrap()[0].0, "ab");
+
+    let err = schema.validate_to_json(&json!({ "tags": ["ab"] })).unwrap_err();
+    assert_eq!(err.code, "TYPE_ERROR");
+    assert_eq!(err.dotted_path(), "tags");
+}
+
+#[test]
+fn test_json_values_in_maps() {
+    use std::any::Any;
+    use std::collections::HashMap;
+
+    let s = schema();
+    let schema = s.object()
+        .field("name", s.string())
+        .field("age", s.i32())
+        .field("nickname", s.string().optional())
+        .field("address", s.object().field("city", s.string()));
+
+    let mut input: HashMap<String, Box<dyn Any>> = HashMap::new();
+    input.insert("name".to_string(), Box::new(json!("Ann")));
+    input.insert("age".to_string(), Box::new(json!(30)));
+    input.insert("nickname".to_string(), Box::new(json!(null)));
+    input.insert("address".to_string(), Box::new(json!({ "city": "Berlin" })));
+
+    let fields = schema.validate(&input).unwrap();
+    assert_eq!(fields["name"].downcast_ref::<String>(), Some(&"Ann".to_string()));
+    assert_eq!(fields["age"].downcast_ref::<i32>(), Some(&30));
+    assert_eq!(fields["nickname"].downcast_ref::<Option<String>>(), Some(&None));
+    let address = fields["address"].downcast_ref::<HashMap<String, Box<dyn Any>>>().unwrap();
+    assert_eq!(address["city"].downcast_ref::<String>(), Some(&"Berlin".to_string()));
+
+    input.insert("age".to_string(), Box::new(json!("thirty")));
+    let err = schema.validate(&input).unwrap_err();
+    assert_eq!(err.flatten()[0].1.dotted_path(), "age");
+    assert_eq!(err.flatten()[0].1.code, "TYPE_ERROR");
+}
@@ -0,0 +1,381 @@
+use schema_validator::{schema, Schema, Validate};
+use schema_validator::schema::json_schema;
+use schema_validator::schema::patterns;
+use serde_json::json;
+
+#[test]
+fn test_primitive_json_schemas() {
+    let s = schema();
+
+    assert_eq!(s.string().min_length(2).max_length(5).to_json_schema(), json!({
+        "type": "string",
+        "minLength": 2,
+        "maxLength": 5
+    }));
+    assert_eq!(s.string().trim().pattern(r"^\d+$").to_json_schema(), json!({
+        "type": "string",
+        "pattern": r"^\d+$"
+    }));
+    assert_eq!(s.string().uuid().to_json_schema(), json!({
+        "type": "string",
+        "format": "uuid",
+        "pattern": patterns::UUID.as_str()
+    }));
+    // Patterns without a JSON Schema format are exported as plain patterns
+    assert_eq!(s.string().phone().to_json_schema()["format"], json!(null));
+
+    assert_eq!(s.number().gt(0.0).max(10.0).multiple_of(0.5).to_json_schema(), json!({
+        "type": "number",
+        "exclusiveMinimum": 0.0,
+        "maximum": 10.0,
+        "multipleOf": 0.5
+    }));
+    assert_eq!(s.number().int().to_json_schema(), json!({ "type": "integer" }));
+
+    assert_eq!(s.integer().to_json_schema(), json!({ "type": "integer" }));
+    assert_eq!(s.integer().min(1).to_json_schema(), json!({ "type": "integer", "minimum": 1 }));
+    assert_eq!(s.u8().to_json_schema(), json!({ "type": "integer", "minimum": 0, "maximum": 255 }));
+    assert_eq!(s.u64().to_json_schema(), json!({ "type": "integer", "minimum": 0 }));
+
+    assert_eq!(s.boolean().to_json_schema(), json!({ "type": "boolean" }));
+    assert_eq!(s.literal("admin").to_json_schema(), json!({ "const": "admin" }));
+    assert_eq!(s.literal(42_i64).to_json_schema(), json!({ "const": 42 }));
+}
+
+#[test]
+fn test_optional_json_schema() {
+    let s = schema();
+
+    assert_eq!(s.string().optional().to_json_schema(), json!({ "type": ["string", "null"] }));
+    assert_eq!(s.literal("x").optional().to_json_schema(), json!({
+        "anyOf": [{ "const": "x" }, { "type": "null" }]
+    }));
+}
+
+#[test]
+fn test_object_json_schema() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("name", s.string().min_length(1))
+        .field("tags", s.array(s.string()).max_items(5))
+        .field("scores", s.record(s.number()))
+        .field("point", s.tuple(vec![s.number().boxed(), s.number().boxed()]))
+        .field("role", s.enumeration(["admin", "editor"]))
+        .field("contact", s.string().email().or(s.integer()))
+        .field("nickname", s.string().optional());
+
+    assert_eq!(json_schema::document(&schema), json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": {
+            "name": { "type": "string", "minLength": 1 },
+            "tags": { "type": "array", "items": { "type": "string" }, "maxItems": 5 },
+            "scores": { "type": "object", "additionalProperties": { "type": "number" } },
+            "point": {
+                "type": "array",
+                "prefixItems": [{ "type": "number" }, { "type": "number" }],
+                "minItems": 2,
+                "maxItems": 2
+            },
+            "role": { "enum": ["admin", "editor"] },
+            "contact": {
+                "anyOf": [
+                    { "type": "string", "format": "email", "pattern": patterns::EMAIL.as_str() },
+                    { "type": "integer" }
+                ]
+            },
+            "nickname": { "type": ["string", "null"] }
+        },
+        "required": ["contact", "name", "nickname", "point", "role", "scores", "tags"]
+    }));
+}
+
+#[test]
+fn test_discriminated_union_json_schema() {
+    let s = schema();
+
+    let schema = s.discriminated_union("type")
+        .variant("click", s.object().field("x", s.integer()))
+        .variant("key", s.object().field("code", s.string()));
+
+    assert_eq!(schema.to_json_schema(), json!({
+        "oneOf": [
+            {
+                "type": "object",
+                "properties": { "type": { "const": "click" }, "x": { "type": "integer" } },
+                "required": ["type", "x"]
+            },
+            {
+                "type": "object",
+                "properties": { "type": { "const": "key" }, "code": { "type": "string" } },
+                "required": ["type", "code"]
+            }
+        ]
+    }));
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Meters(#[validate(min = 0)] f64);
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct User {
+    #[validate(trim, min_length = 2)]
+    name: String,
+    #[validate(rename = "heightInMeters")]
+    height: Meters,
+    nickname: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+#[validate(tag = "kind")]
+enum Shape {
+    #[validate(rename = "circle")]
+    Circle { radius: f64 },
+    #[validate(rename = "empty")]
+    Empty,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+enum Command {
+    Quit,
+    Move(i32, i32),
+}
+
+#[test]
+fn test_derived_json_schema() {
+    assert_eq!(User::schema().to_json_schema(), json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string", "minLength": 2 },
+            "heightInMeters": { "type": "number", "minimum": 0.0 },
+            "nickname": { "type": ["string", "null"] }
+        },
+        "required": ["heightInMeters", "name", "nickname"]
+    }));
+
+    assert_eq!(Shape::schema().to_json_schema(), json!({
+        "oneOf": [
+            {
+                "type": "object",
+                "properties": { "kind": { "const": "circle" }, "radius": { "type": "number" } },
+                "required": ["kind", "radius"]
+            },
+            {
+                "type": "object",
+                "properties": { "kind": { "const": "empty" } },
+                "required": ["kind"]
+            }
+        ]
+    }));
+
+    let int32 = json!({ "type": "integer", "minimum": i32::MIN, "maximum": i32::MAX });
+    assert_eq!(Command::schema().to_json_schema(), json!({
+        "oneOf": [
+            { "const": "Quit" },
+            {
+                "type": "object",
+                "properties": {
+                    "Move": { "type": "array", "prefixItems": [int32, int32], "minItems": 2, "maxItems": 2 }
+                },
+                "required": ["Move"],
+                "additionalProperties": false
+            }
+        ]
+    }));
+}
+
+#[test]
+fn test_load_json_schema() {
+    let schema = json_schema::schema_from_json_schema(&json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "Order",
+        "type": "object",
+        "properties": {
+            "id": { "type": "string", "format": "uuid" },
+            "quantity": { "type": "integer", "exclusiveMinimum": 0, "multipleOf": 2 },
+            "price": { "type": "number", "minimum": 0 },
+            "status": { "enum": ["open", "closed"] },
+            "kind": { "const": "order" },
+            "code": { "type": "string", "pattern": "^[A-Z]{3}$" },
+            "note": { "type": ["string", "null"], "maxLength": 10 },
+            "point": { "type": "array", "prefixItems": [{ "type": "number" }, { "type": "number" }], "minItems": 2, "maxItems": 2 },
+            "labels": { "type": "object", "additionalProperties": { "type": "string" } },
+            "contact": { "anyOf": [{ "type": "string", "format": "email" }, { "type": "integer" }] }
+        },
+        "required": ["id", "quantity", "price", "status", "kind"]
+    })).unwrap();
+
+    let order = json!({
+        "id": "123e4567-e89b-42d3-a456-426614174000",
+        "quantity": 4,
+        "price": 9.5,
+        "status": "open",
+        "kind": "order",
+        "code": "ABC",
+        "point": [1, 2.5],
+        "labels": { "color": "red" },
+        "contact": 42
+    });
+    assert_eq!(schema.validate_to_json(&order).unwrap(), json!({
+        "id": "123e4567-e89b-42d3-a456-426614174000",
+        "quantity": 4,
+        "price": 9.5,
+        "status": "open",
+        "kind": "order",
+        "code": "ABC",
+        "note": null,
+        "point": [1.0, 2.5],
+        "labels": { "color": "red" },
+        "contact": 42
+    }));
+
+    let err = schema.validate(&json!({
+        "id": "nope",
+        "quantity": 3,
+        "price": -1,
+        "status": "lost",
+        "kind": "invoice",
+        "code": "abc",
+        "note": "far too long for this",
+        "point": [1],
+        "contact": "nope"
+    })).unwrap_err();
+    let mut failures: Vec<_> = err.flatten().iter()
+        .map(|(path, leaf)| (schema_validator::error::dotted_path(path), leaf.code.clone()))
+        .collect();
+    failures.sort();
+    assert_eq!(failures.iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>(), vec![
+        "code", "contact", "id", "kind", "note", "point", "price", "quantity", "status",
+    ]);
+    assert!(failures.contains(&("id".to_string(), "INVALID_UUID".to_string())));
+    assert!(failures.contains(&("quantity".to_string(), "NOT_MULTIPLE_OF".to_string())));
+    assert!(failures.contains(&("note".to_string(), "MAX_LENGTH_ERROR".to_string())));
+
+    // Missing required properties are reported, optional ones may be omitted
+    let err = schema.validate(&json!({})).unwrap_err();
+    assert_eq!(err.flatten().len(), 5);
+    assert!(err.flatten().iter().all(|(_, leaf)| leaf.code == "MISSING_FIELD"));
+}
+
+#[test]
+fn test_load_json_schema_outputs() {
+    use std::any::Any;
+    use std::collections::HashMap;
+
+    let schema = json_schema::schema_from_json_schema(&json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string" },
+            "age": { "type": "integer" },
+            "address": { "type": "object", "properties": { "city": { "type": "string" } }, "required": ["city"] },
+            "nickname": { "type": "string" }
+        },
+        "required": ["name", "age", "address"]
+    })).unwrap();
+
+    // Loaded fields hold the outputs of their schemas, boxed once
+    let result = schema.validate(&json!({ "name": "Ann", "age": 30, "address": { "city": "Berlin" } })).unwrap();
+    let fields = result.downcast_ref::<HashMap<String, Box<dyn Any>>>().unwrap();
+    assert_eq!(fields["name"].downcast_ref::<String>(), Some(&"Ann".to_string()));
+    assert_eq!(fields["age"].downcast_ref::<i64>(), Some(&30));
+    let address = fields["address"].downcast_ref::<HashMap<String, Box<dyn Any>>>().unwrap();
+    assert_eq!(address["city"].downcast_ref::<String>(), Some(&"Berlin".to_string()));
+    assert!(fields["nickname"].downcast_ref::<Option<Box<dyn Any>>>().unwrap().is_none());
+}
+
+#[test]
+fn test_unknown_keys_json_schema() {
+    let s = schema();
+
+    assert_eq!(s.object().field("a", s.string()).strict().to_json_schema(), json!({
+        "type": "object",
+        "properties": { "a": { "type": "string" } },
+        "required": ["a"],
+        "additionalProperties": false
+    }));
+    assert_eq!(s.object().catchall(s.integer()).to_json_schema()["additionalProperties"], json!({ "type": "integer" }));
+    assert!(s.object().passthrough().to_json_schema().get("additionalProperties").is_none());
+
+    let load = |document| json_schema::schema_from_json_schema(&document).unwrap();
+    let strict = load(json!({ "properties": { "a": { "type": "string" } }, "additionalProperties": false }));
+    assert_eq!(strict.validate(&json!({ "a": "x", "b": 1 })).unwrap_err().flatten()[0].1.code, "UNKNOWN_FIELD");
+    let empty = load(json!({ "type": "object", "additionalProperties": false }));
+    assert!(empty.validate(&json!({})).is_ok());
+    assert!(empty.validate(&json!({ "b": 1 })).is_err());
+    let catchall = load(json!({ "properties": { "a": { "type": "string" } }, "additionalProperties": { "type": "integer" } }));
+    assert_eq!(catchall.validate_to_json(&json!({ "b": 1 })).unwrap(), json!({ "a": null, "b": 1 }));
+    assert!(catchall.validate(&json!({ "b": "x" })).is_err());
+    let open = load(json!({ "properties": { "a": { "type": "string" } } }));
+    assert_eq!(open.validate_to_json(&json!({ "a": "x", "b": [1] })).unwrap(), json!({ "a": "x", "b": [1] }));
+}
+
+#[test]
+fn test_load_json_schema_refs() {
+    let schema = json_schema::schema_from_json_schema(&json!({
+        "type": "array",
+        "items": { "$ref": "#/$defs/user" },
+        "$defs": {
+            "name": { "type": "string", "minLength": 2 },
+            "user": {
+                "type": "object",
+                "properties": { "name": { "$ref": "#/$defs/name" }, "friend": { "$ref": "#/$defs/name" } },
+                "required": ["name"]
+            }
+        }
+    })).unwrap();
+
+    assert!(schema.validate(&json!([{ "name": "Ann", "friend": "Bob" }])).is_ok());
+    let err = schema.validate(&json!([{ "name": "Ann" }, { "name": "B" }])).unwrap_err();
+    assert_eq!(err.to_string(), "[1].name: [MIN_LENGTH_ERROR] Length error: expected at least 2 characters, got 1");
+}
+
+#[test]
+fn test_load_exported_json_schema() {
+    let original = User::schema();
+    let document = json_schema::document(&original);
+    let loaded = json_schema::schema_from_json_schema(&document).unwrap();
+
+    let user = json!({ "name": "Ann", "heightInMeters": 1.7, "nickname": null });
+    assert_eq!(loaded.validate_to_json(&user).unwrap(), user);
+    assert!(loaded.validate(&json!({ "name": "Ann", "heightInMeters": -1 })).is_err());
+    assert_eq!(loaded.to_json_schema(), original.to_json_schema());
+}
+
+#[test]
+fn test_load_json_schema_errors() {
+    use json_schema::{schema_from_json_schema as load, SchemaLoadError};
+
+    assert_eq!(load(&json!({ "type": "string", "contentEncoding": "base64" })).err().unwrap(), SchemaLoadError::UnsupportedKeyword {
+        keyword: "contentEncoding".to_string(),
+        path: "#".to_string(),
+    });
+    assert_eq!(load(&json!({ "properties": { "a": { "oneOf": [] } } })).err().unwrap(), SchemaLoadError::UnsupportedKeyword {
+        keyword: "oneOf".to_string(),
+        path: "#/properties/a".to_string(),
+    });
+    assert_eq!(load(&json!({ "type": "string", "format": "hostname" })).err().unwrap(), SchemaLoadError::UnsupportedFormat {
+        format: "hostname".to_string(),
+        path: "#".to_string(),
+    });
+    assert!(matches!(
+        load(&json!({ "type": "string", "pattern": "(" })).err().unwrap(),
+        SchemaLoadError::InvalidKeyword { keyword, .. } if keyword == "pattern"
+    ));
+    assert!(matches!(
+        load(&json!({ "type": "number", "multipleOf": 0 })).err().unwrap(),
+        SchemaLoadError::InvalidKeyword { keyword, .. } if keyword == "multipleOf"
+    ));
+    assert_eq!(load(&json!({ "$ref": "other.json#/a" })).err().unwrap(), SchemaLoadError::UnresolvedRef {
+        reference: "other.json#/a".to_string(),
+        path: "#".to_string(),
+    });
+    assert_eq!(load(&json!({ "items": { "$ref": "#" } })).err().unwrap(), SchemaLoadError::RecursiveRef {
+        reference: "#".to_string(),
+        path: "#/items".to_string(),
+    });
+    assert_eq!(load(&json!([])).err().unwrap(), SchemaLoadError::InvalidSchema { path: "#".to_string() });
+
+    let err = load(&json!({ "minLength": 1, "minimum": 0 })).err().unwrap();
+    assert_eq!(err.to_string(), "invalid value for 'type' at '#': keywords of several types require an explicit 'type'");
+}
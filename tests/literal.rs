@@ -1,4 +1,5 @@
 use schema_validator::{schema, Schema};
+use serde_json::json;
 
 #[test]
 fn test_string_literal() {
@@ -91,4 +92,17 @@ fn test_literal_error_messages() {
     assert_eq!(err.code, "LITERAL_ERROR");
     assert!(err.message.contains("expected true"));
     assert!(err.message.contains("got false"));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_numeric_literal_from_json_integers() {
+    let s = schema();
+    let schema = s.object()
+        .field("version", s.literal(1.0))
+        .field("kind", s.literal(2_i64));
+
+    // JSON integers are read as i64, which still matches an f64 literal
+    assert!(schema.validate(&json!({ "version": 1, "kind": 2 })).is_ok());
+    assert!(schema.validate(&json!({ "version": 1.0, "kind": 2 })).is_ok());
+    assert!(schema.validate(&json!({ "version": 2, "kind": 2 })).is_err());
+}
@@ -1,4 +1,5 @@
 use schema_validator::{schema, Schema, ValidateAs, Validate};
+use schema_validator::error::ErrorType;
 use std::collections::HashMap;
 use std::any::Any;
 
@@ -80,4 +81,60 @@ fn test_validate_as_custom_error() {
     let err = result.unwrap_err();
     assert_eq!(err.code, "INVALID_POINT");
     assert_eq!(err.message, "Invalid point coordinates");
-}
\ No newline at end of file
+}
+
+#[derive(Debug, PartialEq, Clone, Validate)]
+struct Team {
+    name: String,
+    members: Vec<User>,
+}
+
+#[test]
+fn test_validate_as_field_type_error() {
+    let s = schema();
+
+    // The schema produces an integer where the struct expects an f64 and a
+    // string where it expects a bool
+    let schema = s.object()
+        .field("name", s.string())
+        .field("age", s.integer())
+        .field("is_active", s.string());
+
+    let mut obj = HashMap::new();
+    obj.insert("name".to_string(), Box::new("John".to_string()) as Box<dyn Any>);
+    obj.insert("age".to_string(), Box::new(30_i64) as Box<dyn Any>);
+    obj.insert("is_active".to_string(), Box::new("yes".to_string()) as Box<dyn Any>);
+
+    let err = schema.validate_as::<User>(&obj).unwrap_err();
+    assert_eq!(err.code, "FIELD_TYPE_ERROR");
+    assert_eq!(err.dotted_path(), "is_active");
+    // Type names come from `std::any::type_name`, whose output is not stable
+    assert!(err.message.starts_with("Field type error: expected 'is_active' to be bool, got "));
+    match err.error_type {
+        ErrorType::FieldType { field, expected, .. } => {
+            assert_eq!(field, "is_active");
+            assert_eq!(expected, "bool");
+        }
+        other => panic!("unexpected error type: {:?}", other),
+    }
+}
+
+#[test]
+fn test_validate_as_nested_field_type_error() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("name", s.string())
+        .field("members", s.array(s.object()
+            .field("name", s.string())
+            .field("age", s.number())
+            .field("is_active", s.number())));
+
+    let err = schema.validate_as::<Team>(&serde_json::json!({
+        "name": "Core",
+        "members": [{ "name": "John", "age": 30, "is_active": 1 }]
+    })).unwrap_err();
+
+    assert_eq!(err.code, "FIELD_TYPE_ERROR");
+    assert_eq!(err.dotted_path(), "members[0].is_active");
+}
@@ -1,5 +1,6 @@
 mod common;
 mod validation;
 mod coercion;
+#[allow(clippy::bool_assert_comparison)]
 mod transform;
 mod error;
\ No newline at end of file
@@ -0,0 +1,135 @@
+use schema_validator::{schema, Schema};
+use schema_validator::error::ErrorType;
+use serde_json::json;
+
+#[test]
+fn test_number_range() {
+    let s = schema();
+
+    // Inclusive bounds
+    let schema = s.number().min(0.0).max(150.0);
+    assert!(schema.validate(&0.0).is_ok());
+    assert!(schema.validate(&150.0).is_ok());
+
+    let err = schema.validate(&-1.0).unwrap_err();
+    assert_eq!(err.code, "TOO_SMALL");
+    assert_eq!(err.message, "Range error: expected a number greater than or equal to 0, got -1");
+
+    let err = schema.validate(&151.0).unwrap_err();
+    assert_eq!(err.code, "TOO_LARGE");
+    assert_eq!(err.message, "Range error: expected a number less than or equal to 150, got 151");
+
+    // Exclusive bounds
+    let schema = s.number().gt(0.0).lt(10.0);
+    assert!(schema.validate(&5.0).is_ok());
+    let err = schema.validate(&0.0).unwrap_err();
+    assert_eq!(err.message, "Range error: expected a number greater than 0, got 0");
+    assert!(matches!(err.error_type, ErrorType::TooSmall { inclusive: false, .. }));
+    assert!(schema.validate(&10.0).is_err());
+
+    // Sign helpers
+    assert!(s.number().positive().validate(&0.0).is_err());
+    assert!(s.number().positive().validate(&0.1).is_ok());
+    assert!(s.number().negative().validate(&0.0).is_err());
+    assert!(s.number().negative().validate(&-0.1).is_ok());
+    assert!(s.number().nonnegative().validate(&0.0).is_ok());
+    assert!(s.number().nonnegative().validate(&-0.1).is_err());
+    assert!(s.number().nonpositive().validate(&0.1).is_err());
+
+    // NaN is outside every range
+    assert_eq!(s.number().min(0.0).validate(&f64::NAN).unwrap_err().code, "TOO_SMALL");
+    assert_eq!(s.number().lt(10.0).validate(&f64::NAN).unwrap_err().code, "TOO_LARGE");
+}
+
+#[test]
+fn test_number_multiple_of_and_integer() {
+    let s = schema();
+
+    let schema = s.number().multiple_of(0.1);
+    assert!(schema.validate(&0.3).is_ok());
+    assert!(schema.validate(&-1.2).is_ok());
+    let err = schema.validate(&0.35).unwrap_err();
+    assert_eq!(err.code, "NOT_MULTIPLE_OF");
+
+    let schema = s.number().int();
+    assert!(schema.validate(&42.0).is_ok());
+    let err = schema.validate(&42.5).unwrap_err();
+    assert_eq!(err.code, "NOT_INTEGER");
+    assert_eq!(err.message, "Number error: expected an integer, got 42.5");
+
+    let schema = s.number().finite();
+    assert!(schema.validate(&f64::MAX).is_ok());
+    assert_eq!(schema.validate(&f64::NAN).unwrap_err().code, "NOT_FINITE");
+    assert_eq!(schema.validate(&f64::NEG_INFINITY).unwrap_err().code, "NOT_FINITE");
+}
+
+#[test]
+#[should_panic(expected = "multiple_of requires a positive step")]
+fn test_number_multiple_of_zero() {
+    schema().number().multiple_of(0.0);
+}
+
+#[test]
+fn test_number_constraints_with_transform_and_coercion() {
+    let s = schema();
+
+    // Constraints apply to the transformed value
+    let schema = s.number().transform(|n| n.round()).int().max(10.0);
+    assert_eq!(schema.validate(&3.7).unwrap(), 4.0);
+    assert!(schema.validate(&10.6).is_err());
+
+    // Coerced values are checked as well
+    let schema = s.coerce().number().min(18.0);
+    assert!(schema.validate(&"21".to_string()).is_ok());
+    assert_eq!(schema.validate(&"16".to_string()).unwrap_err().code, "TOO_SMALL");
+
+    // Custom error message
+    let schema = s.number().min(18.0).set_message("TOO_YOUNG", "Must be at least 18");
+    let err = schema.validate(&16.0).unwrap_err();
+    assert_eq!(err.code, "TOO_YOUNG");
+    assert_eq!(err.message, "Must be at least 18");
+}
+
+#[test]
+fn test_number_accepts_integers() {
+    let s = schema();
+
+    // Integers of every type are numbers without coercion
+    let schema = s.number().max(100.0);
+    assert_eq!(schema.validate(&42_i64).unwrap(), 42.0);
+    assert_eq!(schema.validate(&7_u8).unwrap(), 7.0);
+    assert_eq!(schema.validate(&-3_i32).unwrap(), -3.0);
+    assert_eq!(schema.validate(&json!(5)).unwrap(), 5.0);
+    assert_eq!(schema.validate(&200_u64).unwrap_err().code, "TOO_LARGE");
+
+    // Other types still need coercion
+    assert_eq!(schema.validate(&"5".to_string()).unwrap_err().code, "TYPE_ERROR");
+    assert_eq!(schema.validate(&true).unwrap_err().code, "TYPE_ERROR");
+}
+
+#[test]
+fn test_number_collect_errors() {
+    let s = schema();
+
+    let schema = s.number().int().min(10.0).multiple_of(5.0);
+    assert_eq!(schema.validate(&2.5).unwrap_err().code, "NOT_INTEGER");
+
+    let schema = schema.abort_early(false);
+    let err = schema.validate(&2.5).unwrap_err();
+    assert_eq!(err.code, "MULTIPLE_ERRORS");
+    let codes: Vec<_> = err.flatten().into_iter().map(|(_, leaf)| leaf.code.clone()).collect();
+    assert_eq!(codes, vec!["NOT_INTEGER", "TOO_SMALL", "NOT_MULTIPLE_OF"]);
+
+    // Object fields report the constraint failures at the field path
+    let s = s.abort_early(false);
+    let schema = s.object().field("age", s.number().int().min(0.0));
+    let err = schema.validate(&json!({ "age": -1.5 })).unwrap_err();
+    let leaves: Vec<_> = err.flatten()
+        .into_iter()
+        .map(|(_, leaf)| (leaf.dotted_path(), leaf.code.clone()))
+        .collect();
+    assert_eq!(leaves, vec![
+        ("age".to_string(), "NOT_INTEGER".to_string()),
+        ("age".to_string(), "TOO_SMALL".to_string()),
+    ]);
+}
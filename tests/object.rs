@@ -1,4 +1,5 @@
 use schema_validator::{schema, Schema, Validate, ValidateAs};
+use schema_validator::error::{PathSegment, ValidationError};
 use std::collections::HashMap;
 use std::any::Any;
 use serde_json::json;
@@ -51,8 +52,7 @@ fn test_object_validation() {
 
     let err = schema.validate_as::<User>(&obj).unwrap_err();
     assert_eq!(err.code, "OBJECT_ERROR");
-    assert!(err.message.contains("email"));
-    assert!(err.message.contains("pattern"));
+    assert!(err.message.contains("email: [INVALID_EMAIL] Invalid email format"));
 }
 
 #[test]
@@ -154,7 +154,375 @@ fn test_unknown_json_with_coercion() {
     });
 
     let err = schema.validate_as::<User>(&json).unwrap_err();
-    assert_eq!(err.code, "TYPE_ERROR");
+    assert_eq!(err.code, "OBJECT_ERROR");
     assert!(err.message.contains("expected String"));
     assert!(err.message.contains("got Array"));
-}
\ No newline at end of file
+    assert!(err.message.contains("INVALID_EMAIL"));
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Address {
+    city: String,
+    zip: String,
+}
+
+impl schema_validator::schema::clone::CloneAny for Address {
+    fn clone_any(&self) -> Box<dyn Any> {
+        Box::new(self.clone())
+    }
+}
+
+#[test]
+fn test_nested_object_json() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("name", s.string())
+        .field("address", s.object()
+            .field("city", s.string().min_length(2))
+            .field("geo", s.object()
+                .field("lat", s.number())
+                .field("lng", s.number())));
+
+    let json = json!({
+        "name": "John",
+        "address": {
+            "city": "Berlin",
+            "geo": { "lat": 52.52, "lng": 13.40 }
+        }
+    });
+
+    let result = schema.validate(&json).unwrap();
+    let address = result.get("address").unwrap()
+        .downcast_ref::<HashMap<String, Box<dyn Any>>>().unwrap();
+    assert_eq!(address.get("city").unwrap().downcast_ref::<String>().unwrap(), "Berlin");
+    let geo = address.get("geo").unwrap()
+        .downcast_ref::<HashMap<String, Box<dyn Any>>>().unwrap();
+    assert_eq!(*geo.get("lat").unwrap().downcast_ref::<f64>().unwrap(), 52.52);
+
+    // Nested failures bubble up through the parent
+    let json = json!({
+        "name": "John",
+        "address": {
+            "city": "B",
+            "geo": { "lat": "north", "lng": 13.40 }
+        }
+    });
+
+    let err = schema.validate(&json).unwrap_err();
+    assert_eq!(err.code, "OBJECT_ERROR");
+    assert!(err.message.contains("address"));
+    assert!(err.message.contains("MIN_LENGTH_ERROR"));
+    assert!(err.message.contains("lat"));
+
+    // Nested field with a non-object value
+    let err = schema.validate(&json!({ "name": "John", "address": "Berlin" })).unwrap_err();
+    assert_eq!(err.code, "OBJECT_ERROR");
+    assert!(err.message.contains("expected Object or JSON object"));
+}
+
+#[test]
+fn test_nested_object_hashmap() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("name", s.string())
+        .field("address", s.object()
+            .field("city", s.string())
+            .field("zip", s.string().pattern(r"^\d{5}$"))
+            .transform(|fields| Address {
+                city: fields.get("city").unwrap().downcast_ref::<String>().unwrap().clone(),
+                zip: fields.get("zip").unwrap().downcast_ref::<String>().unwrap().clone(),
+            }));
+
+    let mut address = HashMap::new();
+    address.insert("city".to_string(), Box::new("Berlin".to_string()) as Box<dyn Any>);
+    address.insert("zip".to_string(), Box::new("10115".to_string()) as Box<dyn Any>);
+
+    let mut obj = HashMap::new();
+    obj.insert("name".to_string(), Box::new("John".to_string()) as Box<dyn Any>);
+    obj.insert("address".to_string(), Box::new(address) as Box<dyn Any>);
+
+    let result = schema.validate(&obj).unwrap();
+    assert_eq!(
+        result.get("address").unwrap().downcast_ref::<Address>().unwrap(),
+        &Address { city: "Berlin".to_string(), zip: "10115".to_string() }
+    );
+
+    // The transformed struct is produced for JSON input as well
+    let json = json!({ "name": "John", "address": { "city": "Berlin", "zip": "10115" } });
+    let result = schema.validate(&json).unwrap();
+    assert!(result.get("address").unwrap().downcast_ref::<Address>().is_some());
+}
+
+#[test]
+fn test_optional_nested_object() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("name", s.string())
+        .field("address", s.object().field("city", s.string()).optional());
+
+    let result = schema.validate(&json!({ "name": "John", "address": null })).unwrap();
+    assert!(result.get("address").unwrap()
+        .downcast_ref::<Option<HashMap<String, Box<dyn Any>>>>().unwrap()
+        .is_none());
+
+    let result = schema.validate(&json!({ "name": "John", "address": { "city": "Berlin" } })).unwrap();
+    let address = result.get("address").unwrap()
+        .downcast_ref::<Option<HashMap<String, Box<dyn Any>>>>().unwrap()
+        .as_ref().unwrap();
+    assert_eq!(address.get("city").unwrap().downcast_ref::<String>().unwrap(), "Berlin");
+
+    assert!(schema.validate(&json!({ "name": "John", "address": { "city": 1 } })).is_err());
+
+    // Optional fields accept `null` but must be present
+    let err = schema.validate(&json!({ "name": "John" })).unwrap_err();
+    assert_eq!(err.flatten()[0].1.code, "MISSING_FIELD");
+}
+
+#[test]
+fn test_error_paths() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("name", s.string())
+        .field("users", s.array(s.object()
+            .field("email", s.string().email())
+            .field("address", s.object().field("city", s.string().min_length(2)))));
+
+    let json = json!({
+        "name": 42,
+        "users": [
+            { "email": "a@example.com", "address": { "city": "Berlin" } },
+            { "email": "b@example.com", "address": { "city": "B" } },
+            { "email": "nope", "address": { "city": "Paris" } }
+        ]
+    });
+
+    let err = schema.validate(&json).unwrap_err();
+    assert_eq!(err.code, "OBJECT_ERROR");
+    assert!(err.path.is_empty());
+
+    let leaves: Vec<(String, String, String)> = err.flatten()
+        .into_iter()
+        .map(|(_, leaf)| (leaf.dotted_path(), leaf.json_pointer(), leaf.code.clone()))
+        .collect();
+    assert_eq!(leaves, vec![
+        ("name".to_string(), "/name".to_string(), "TYPE_ERROR".to_string()),
+        ("users[1].address.city".to_string(), "/users/1/address/city".to_string(), "MIN_LENGTH_ERROR".to_string()),
+        ("users[2].email".to_string(), "/users/2/email".to_string(), "INVALID_EMAIL".to_string()),
+    ]);
+
+    let (path, leaf) = err.flatten()[2];
+    assert_eq!(path, &[
+        PathSegment::Key("users".to_string()),
+        PathSegment::Index(2),
+        PathSegment::Key("email".to_string()),
+    ]);
+    assert_eq!(leaf.message, "Invalid email format");
+
+    // The aggregated message lists every failure with its path
+    assert!(err.message.contains("users[1].address.city: [MIN_LENGTH_ERROR]"));
+
+    // Missing fields point at the missing key
+    let err = schema.validate(&json!({ "users": [] })).unwrap_err();
+    let leaves = err.flatten();
+    assert_eq!(leaves.len(), 1);
+    assert_eq!(leaves[0].1.code, "MISSING_FIELD");
+    assert_eq!(leaves[0].1.dotted_path(), "name");
+}
+
+#[test]
+fn test_unknown_keys() {
+    let s = schema();
+    let input = json!({ "name": " Ann ", "admin": true, "tags": ["a"] });
+
+    // Unknown keys are stripped by default
+    let schema = s.object().field("name", s.string().trim());
+    assert_eq!(schema.validate_to_json(&input).unwrap(), json!({ "name": "Ann" }));
+
+    let schema = s.object().field("name", s.string().trim()).strict();
+    let err = schema.validate(&input).unwrap_err();
+    let leaves: Vec<_> = err.flatten().iter().map(|(_, leaf)| (leaf.dotted_path(), leaf.code.clone())).collect();
+    assert_eq!(leaves, vec![
+        ("admin".to_string(), "UNKNOWN_FIELD".to_string()),
+        ("tags".to_string(), "UNKNOWN_FIELD".to_string()),
+    ]);
+    assert!(err.message.contains("admin: [UNKNOWN_FIELD] Unknown field: 'admin'"));
+
+    // Unknown keys are reported together with invalid fields
+    let err = schema.validate(&json!({ "name": 1, "admin": true })).unwrap_err();
+    assert_eq!(err.flatten().len(), 2);
+
+    let schema = s.object().field("name", s.string().trim()).passthrough();
+    assert_eq!(schema.validate_to_json(&input).unwrap(), json!({ "name": "Ann", "admin": true, "tags": ["a"] }));
+
+    let mut obj = HashMap::new();
+    obj.insert("name".to_string(), Box::new("Ann".to_string()) as Box<dyn Any>);
+    obj.insert("age".to_string(), Box::new(30_i64) as Box<dyn Any>);
+    obj.insert("rank".to_string(), Box::new(5_i32) as Box<dyn Any>);
+    let result = schema.validate(&obj).unwrap();
+    assert_eq!(result.get("age").unwrap().downcast_ref::<i64>(), Some(&30));
+    assert_eq!(result.get("rank").unwrap().downcast_ref::<i32>(), Some(&5));
+
+    // Values that cannot be copied are rejected rather than dropped
+    struct Opaque;
+    obj.insert("handle".to_string(), Box::new(Opaque) as Box<dyn Any>);
+    let err = schema.validate(&obj).unwrap_err();
+    let leaves = err.flatten();
+    assert_eq!(leaves.len(), 1);
+    assert_eq!((leaves[0].1.dotted_path(), leaves[0].1.code.as_str()), ("handle".to_string(), "TYPE_ERROR"));
+
+    let schema = s.object().field("name", s.string().trim()).catchall(s.string().optional());
+    let err = schema.validate(&input).unwrap_err();
+    let leaves: Vec<_> = err.flatten().iter().map(|(_, leaf)| (leaf.dotted_path(), leaf.code.clone())).collect();
+    assert_eq!(leaves, vec![
+        ("admin".to_string(), "TYPE_ERROR".to_string()),
+        ("tags".to_string(), "TYPE_ERROR".to_string()),
+    ]);
+    assert_eq!(
+        schema.validate_to_json(&json!({ "name": "Ann", "nickname": "A", "bio": null })).unwrap(),
+        json!({ "name": "Ann", "nickname": "A", "bio": null }),
+    );
+}
+
+#[test]
+fn test_strict_discriminated_union_variants() {
+    let s = schema();
+    let schema = s.discriminated_union("type")
+        .variant("click", s.object().field("x", s.integer()).strict());
+
+    assert!(schema.validate(&json!({ "type": "click", "x": 1 })).is_ok());
+    let err = schema.validate(&json!({ "type": "click", "x": 1, "y": 2 })).unwrap_err();
+    assert_eq!(err.flatten()[0].1.dotted_path(), "y");
+}
+
+#[test]
+#[allow(clippy::result_large_err)]
+fn test_strict_tagged_enum_variants() {
+    use schema_validator::schema::tagged::{TaggedEnumSchema, Tagging};
+
+    let s = schema();
+    let schema = TaggedEnumSchema::new(Tagging::Internal { tag: "type".to_string() })
+        .variant("click", s.object().field("x", s.integer()).strict(), |fields| {
+            Ok(*fields["x"].downcast_ref::<i64>().unwrap())
+        });
+
+    // The tag is not an unknown key of the variant
+    assert_eq!(schema.validate(&json!({ "type": "click", "x": 1 })).unwrap(), 1);
+    let err = schema.validate(&json!({ "type": "click", "x": 1, "y": 2 })).unwrap_err();
+    let leaves = err.flatten();
+    assert_eq!(leaves.len(), 1);
+    assert_eq!(leaves[0].1.dotted_path(), "y");
+}
+
+fn string_field<'a>(fields: &'a HashMap<String, Box<dyn Any>>, name: &str) -> Option<&'a String> {
+    fields.get(name).and_then(|value| value.downcast_ref::<String>())
+}
+
+#[test]
+fn test_cross_field_checks() {
+    let s = schema();
+    let schema = s.object()
+        .field("password", s.string().min_length(8))
+        .field("confirm_password", s.string())
+        .field("start_date", s.string().date())
+        .field("end_date", s.string().date())
+        .check(|fields| {
+            match (string_field(fields, "password"), string_field(fields, "confirm_password")) {
+                (Some(password), Some(confirm)) if password != confirm => vec![
+                    ValidationError::custom("PASSWORD_MISMATCH", "Passwords do not match").with_path_prefix("confirm_password"),
+                ],
+                _ => Vec::new(),
+            }
+        })
+        .check(|fields| {
+            match (string_field(fields, "start_date"), string_field(fields, "end_date")) {
+                (Some(start), Some(end)) if end <= start => vec![
+                    ValidationError::custom("INVALID_DATE_RANGE", "End date must be after start date").with_path_prefix("end_date"),
+                    ValidationError::custom("INVALID_DATE_RANGE", "Start date must be before end date").with_path_prefix("start_date"),
+                ],
+                _ => Vec::new(),
+            }
+        });
+
+    let valid = json!({
+        "password": "correct horse",
+        "confirm_password": "correct horse",
+        "start_date": "2024-01-01",
+        "end_date": "2024-02-01"
+    });
+    assert!(schema.validate(&valid).is_ok());
+
+    // Cross-field errors are reported with the field errors
+    let err = schema.validate(&json!({
+        "password": "correct horse",
+        "confirm_password": "battery staple",
+        "start_date": "2024-03-01",
+        "end_date": "2024/02/01"
+    })).unwrap_err();
+    assert_eq!(err.code, "OBJECT_ERROR");
+    let leaves: Vec<_> = err.flatten().iter().map(|(_, leaf)| (leaf.dotted_path(), leaf.code.clone())).collect();
+    assert_eq!(leaves, vec![
+        ("confirm_password".to_string(), "PASSWORD_MISMATCH".to_string()),
+        ("end_date".to_string(), "INVALID_DATE".to_string()),
+    ]);
+
+    // One check may report several errors
+    let err = schema.validate(&json!({
+        "password": "correct horse",
+        "confirm_password": "correct horse",
+        "start_date": "2024-03-01",
+        "end_date": "2024-02-01"
+    })).unwrap_err();
+    let paths: Vec<_> = err.flatten().iter().map(|(_, leaf)| leaf.dotted_path()).collect();
+    assert_eq!(paths, vec!["end_date", "start_date"]);
+}
+
+#[test]
+fn test_cross_field_check_paths() {
+    let s = schema();
+    let range = s.object()
+        .field("min", s.integer().max(100))
+        .field("max", s.integer())
+        .check(|fields| {
+            let min = fields.get("min").and_then(|value| value.downcast_ref::<i64>());
+            let max = fields.get("max").and_then(|value| value.downcast_ref::<i64>());
+            match (min, max) {
+                (Some(min), Some(max)) if min > max => vec![
+                    ValidationError::custom("INVALID_RANGE", "max must not be less than min")
+                        .with_param("min", *min)
+                        .with_path_prefix("max"),
+                ],
+                (None, _) | (_, None) => vec![ValidationError::custom("INCOMPLETE_RANGE", "Both bounds are required")],
+                _ => Vec::new(),
+            }
+        });
+    let err = range.validate(&json!({ "min": 500, "max": 3 })).unwrap_err();
+    assert_eq!(err.to_string(), "[INCOMPLETE_RANGE] Both bounds are required; min: [TOO_LARGE] Range error: expected a number less than or equal to 100, got 500");
+
+    let schema = s.object().field("ranges", s.array(range));
+
+    // Errors of nested checks get the path of the object
+    let err = schema.validate(&json!({ "ranges": [{ "min": 1, "max": 2 }, { "min": 5, "max": 3 }] })).unwrap_err();
+    let (path, leaf) = err.flatten()[0];
+    assert_eq!(path, [PathSegment::from("ranges"), PathSegment::from(1), PathSegment::from("max")]);
+    assert_eq!(leaf.to_string(), "ranges[1].max: [INVALID_RANGE] max must not be less than min");
+
+    // Errors without a path are reported at the object, and errors at a
+    // field that already failed are reported together
+    let schema = s.object()
+        .field("max", s.integer().min(0))
+        .check(|_| vec![
+            ValidationError::custom("ROOT", "Object rule failed"),
+            ValidationError::custom("FIELD", "Field rule failed").with_path_prefix("max"),
+        ]);
+    let err = schema.validate(&json!({ "max": -1 })).unwrap_err();
+    let leaves: Vec<_> = err.flatten().iter().map(|(_, leaf)| (leaf.dotted_path(), leaf.code.clone())).collect();
+    assert_eq!(leaves, vec![
+        ("".to_string(), "ROOT".to_string()),
+        ("max".to_string(), "TOO_SMALL".to_string()),
+        ("max".to_string(), "FIELD".to_string()),
+    ]);
+}
@@ -0,0 +1,203 @@
+use schema_validator::{schema, Schema, Validate};
+use schema_validator::schema::openapi::{self, OpenApi};
+use serde_json::json;
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+#[validate(title = "Address")]
+struct Address {
+    #[validate(trim, min_length = 2, description = "City name", example = "Berlin")]
+    city: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+#[validate(title = "Role")]
+enum Role {
+    Admin,
+    Editor,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct User {
+    #[validate(email, description = "Login email")]
+    email: String,
+    role: Role,
+    #[validate(description = "Where invoices are sent")]
+    billing: Option<Address>,
+    addresses: Vec<Address>,
+}
+
+#[test]
+fn test_openapi_components() {
+    let document = OpenApi::new("Users", "2.0.0")
+        .description("Manages users")
+        .component_of::<Address>()
+        .component_of::<Role>()
+        .component_of::<User>()
+        .to_json();
+
+    assert_eq!(document["openapi"], "3.1.0");
+    assert_eq!(document["info"], json!({ "title": "Users", "version": "2.0.0", "description": "Manages users" }));
+    assert!(document.get("paths").is_none());
+
+    let schemas = &document["components"]["schemas"];
+    assert_eq!(schemas["Address"], json!({
+        "type": "object",
+        "title": "Address",
+        "properties": {
+            "city": { "type": "string", "minLength": 2, "description": "City name", "examples": ["Berlin"] }
+        },
+        "required": ["city"]
+    }));
+    assert_eq!(schemas["Role"], json!({ "enum": ["Admin", "Editor"], "title": "Role" }));
+    assert_eq!(schemas["User"]["properties"]["role"], openapi::schema_ref("Role"));
+    assert_eq!(schemas["User"]["properties"]["addresses"], json!({
+        "type": "array",
+        "items": openapi::schema_ref("Address")
+    }));
+    assert_eq!(schemas["User"]["properties"]["billing"], json!({
+        "anyOf": [openapi::schema_ref("Address"), { "type": "null" }],
+        "description": "Where invoices are sent"
+    }));
+    assert_eq!(schemas["User"]["properties"]["email"]["description"], "Login email");
+    assert_eq!(schemas["User"]["required"], json!(["addresses", "billing", "email", "role"]));
+}
+
+#[test]
+fn test_openapi_operations() {
+    let s = schema();
+    let credentials = s.object()
+        .field("username", s.string().min_length(3))
+        .field("password", s.string().min_length(8))
+        .title("Credentials")
+        .describe("Login credentials");
+
+    let document = OpenApi::new("Auth", "1.0.0")
+        .component("Credentials", &credentials)
+        .operation("POST", "/login", json!({
+            "requestBody": {
+                "content": { "application/json": { "schema": credentials.to_json_schema() } }
+            },
+            "responses": { "204": { "description": "Logged in" } }
+        }))
+        .operation("delete", "/login", json!({ "responses": { "204": { "description": "Logged out" } } }))
+        .to_json();
+
+    assert_eq!(document["components"]["schemas"]["Credentials"]["description"], "Login credentials");
+    let login = &document["paths"]["/login"];
+    assert_eq!(login["post"]["requestBody"]["content"]["application/json"]["schema"], json!({
+        "$ref": "#/components/schemas/Credentials",
+        "description": "Login credentials"
+    }));
+    assert_eq!(login["delete"]["responses"]["204"]["description"], "Logged out");
+
+    // Only titled schemas are linked, whatever their type
+    let document = OpenApi::new("Ids", "1.0.0")
+        .component("Id", &s.string().uuid().title("Id"))
+        .component("Item", &s.object()
+            .field("id", s.string().uuid().title("Id"))
+            .field("parent", s.string().uuid()))
+        .to_json();
+    let item = &document["components"]["schemas"]["Item"];
+    assert_eq!(item["properties"]["id"], openapi::schema_ref("Id"));
+    assert_eq!(item["properties"]["parent"]["type"], "string");
+
+    // Schemas made nullable with `anyOf` keep their null branch
+    let document = OpenApi::new("Tasks", "1.0.0")
+        .component("Status", &s.enumeration(["open", "done"]).title("Status"))
+        .component("Task", &s.object().field("status", s.enumeration(["open", "done"]).optional().title("Status")))
+        .to_json();
+    assert_eq!(document["components"]["schemas"]["Task"]["properties"]["status"], json!({
+        "anyOf": [openapi::schema_ref("Status"), { "type": "null" }]
+    }));
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+#[validate(title = "OrderStatus")]
+enum OrderStatus {
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+#[validate(title = "TicketStatus")]
+enum TicketStatus {
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Order {
+    status: OrderStatus,
+    ticket: TicketStatus,
+}
+
+#[test]
+fn test_openapi_links_by_title() {
+    let s = schema();
+    let document = OpenApi::new("Orders", "1.0.0")
+        .component_of::<OrderStatus>()
+        .component_of::<TicketStatus>()
+        .component_of::<Order>()
+        .component("Archive", &s.object().field("status", s.enumeration(vec![json!("Open"), json!("Closed")])))
+        .to_json();
+
+    // Components of the same shape are told apart by their title
+    let schemas = &document["components"]["schemas"];
+    assert_eq!(schemas["Order"]["properties"]["status"], openapi::schema_ref("OrderStatus"));
+    assert_eq!(schemas["Order"]["properties"]["ticket"], openapi::schema_ref("TicketStatus"));
+    assert_eq!(schemas["Archive"]["properties"]["status"], json!({ "enum": ["Open", "Closed"] }));
+
+    // Properties named like keywords are linked too, but data is not
+    let document = OpenApi::new("Orders", "1.0.0")
+        .component_of::<OrderStatus>()
+        .component("Settings", &s.object()
+            .field("default", OrderStatus::schema())
+            .field("required", s.array(OrderStatus::schema()))
+            .field("examples", OrderStatus::schema().optional()))
+        .operation("get", "/settings", json!({
+            "responses": {
+                "200": {
+                    "description": "The settings",
+                    "content": {
+                        "application/json": {
+                            "schema": { "type": "array", "items": OrderStatus::schema().to_json_schema() },
+                            "example": { "schema": OrderStatus::schema().to_json_schema() }
+                        }
+                    }
+                }
+            }
+        }))
+        .to_json();
+    let settings = &document["components"]["schemas"]["Settings"]["properties"];
+    assert_eq!(settings["default"], openapi::schema_ref("OrderStatus"));
+    assert_eq!(settings["required"]["items"], openapi::schema_ref("OrderStatus"));
+    assert_eq!(settings["examples"], json!({ "anyOf": [openapi::schema_ref("OrderStatus"), { "type": "null" }] }));
+    let media = &document["paths"]["/settings"]["get"]["responses"]["200"]["content"]["application/json"];
+    assert_eq!(media["schema"]["items"], openapi::schema_ref("OrderStatus"));
+    assert_eq!(media["example"]["schema"]["title"], "OrderStatus");
+
+    // A title naming no component is kept
+    let document = OpenApi::new("Orders", "1.0.0").component_of::<Order>().to_json();
+    assert_eq!(document["components"]["schemas"]["Order"]["properties"]["status"]["title"], "OrderStatus");
+}
+
+#[test]
+fn test_openapi_serialization() {
+    let s = schema();
+    let api = OpenApi::new("Tags", "1.0.0").component("Tag", &s.object().field("name", s.string()));
+
+    let text = api.to_json_string();
+    assert_eq!(serde_json::from_str::<serde_json::Value>(&text).unwrap(), api.to_json());
+    assert!(text.contains("\n  \"openapi\": \"3.1.0\""));
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn test_openapi_yaml() {
+    let s = schema();
+    let api = OpenApi::new("Tags", "1.0.0").component("Tag", &s.object().field("name", s.string()));
+
+    let yaml = api.to_yaml();
+    assert!(yaml.contains("\nopenapi: 3.1.0\n"), "{}", yaml);
+    assert_eq!(serde_yaml::from_str::<serde_json::Value>(&yaml).unwrap(), api.to_json());
+}
@@ -1,6 +1,9 @@
 use schema_validator::{schema, Schema, ValidateAs, Validate};
 use std::collections::HashMap;
 use std::any::Any;
+use std::cell::Cell;
+use std::rc::Rc;
+use serde_json::json;
 
 #[test]
 fn test_optional_string() {
@@ -139,4 +142,29 @@ fn test_optional_with_transform() {
     assert_eq!(schema.validate(&Some(42.0)).unwrap(), Some(true));
     assert_eq!(schema.validate(&Some(-1.0)).unwrap(), Some(false));
     assert_eq!(schema.validate(&None::<f64>).unwrap(), None);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_optional_transforms_run_once() {
+    let s = schema();
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    let schema = s.string()
+        .trim()
+        .transform(move |s| {
+            counter.set(counter.get() + 1);
+            format!("<{}>", s)
+        })
+        .optional();
+
+    assert_eq!(schema.validate(&Some(" a ".to_string())).unwrap(), Some("<a>".to_string()));
+    assert_eq!(calls.get(), 1);
+    assert_eq!(schema.validate(&json!(" b ")).unwrap(), Some("<b>".to_string()));
+    assert_eq!(schema.validate(&Some(json!("c"))).unwrap(), Some("<c>".to_string()));
+    assert_eq!(calls.get(), 3);
+
+    // Options of values that are not inputs are not unwrapped
+    let schema = s.string().transform(|s| s.len()).optional();
+    assert_eq!(schema.validate(&Some("abc".to_string())).unwrap(), Some(3));
+    assert!(schema.validate(&Some(3_usize)).is_err());
+}
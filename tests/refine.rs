@@ -0,0 +1,155 @@
+// Refinements return `ValidationError` by value, like the schemas themselves.
+#![allow(clippy::result_large_err)]
+
+use schema_validator::{schema, Schema};
+use schema_validator::error::{ErrorType, ValidationError};
+use serde_json::json;
+
+fn sku_checksum_valid(sku: &str) -> bool {
+    let digits: Vec<u32> = sku.chars().filter_map(|c| c.to_digit(10)).collect();
+    match digits.split_last() {
+        Some((check, rest)) if !rest.is_empty() => rest.iter().sum::<u32>() % 10 == *check,
+        _ => false,
+    }
+}
+
+#[test]
+fn test_refine_primitives() {
+    let s = schema();
+
+    let sku = s.string()
+        .pattern(r"^SKU-\d+$")
+        .refine(|sku| sku_checksum_valid(sku), "INVALID_SKU", "SKU checksum is not valid");
+    assert_eq!(sku.validate(&"SKU-1236".to_string()).unwrap(), "SKU-1236");
+    let err = sku.validate(&"SKU-1234".to_string()).unwrap_err();
+    assert_eq!(err.code, "INVALID_SKU");
+    assert_eq!(err.message, "SKU checksum is not valid");
+    assert!(matches!(err.error_type, ErrorType::Custom { ref code, ref params } if code == "INVALID_SKU" && params.is_empty()));
+    // Built-in constraints are checked first
+    assert_eq!(sku.validate(&"1236".to_string()).unwrap_err().code, "PATTERN_ERROR");
+
+    let even = s.number().refine(|n| n % 2.0 == 0.0, "NOT_EVEN", "Expected an even number");
+    assert!(even.validate(&4.0).is_ok());
+    assert_eq!(even.validate(&3.0).unwrap_err().code, "NOT_EVEN");
+    // Refinements see the transformed number
+    let doubled = s.number().transform(|n| n * 2.0).refine(|n| n % 2.0 == 0.0, "NOT_EVEN", "Expected an even number");
+    assert!(doubled.validate(&3.0).is_ok());
+
+    let accepted = s.boolean().refine(|b| *b, "TERMS_NOT_ACCEPTED", "The terms must be accepted");
+    assert!(accepted.validate(&true).is_ok());
+    assert_eq!(accepted.validate(&false).unwrap_err().code, "TERMS_NOT_ACCEPTED");
+}
+
+#[test]
+fn test_refine_transformed() {
+    let s = schema();
+
+    let schema = s.string()
+        .trim()
+        .refine(|s| !s.is_empty(), "BLANK", "Must not be blank")
+        .transform(|s| s.len())
+        .refine(|len| len % 2 == 0, "ODD_LENGTH", "Expected an even length");
+    assert_eq!(schema.validate(&" ab ".to_string()).unwrap(), 2);
+    assert_eq!(schema.validate(&"   ".to_string()).unwrap_err().code, "BLANK");
+    assert_eq!(schema.validate(&"abc".to_string()).unwrap_err().code, "ODD_LENGTH");
+
+    // Refinements of the string schema see the input before transforms
+    let schema = s.string()
+        .refine(|s| s == s.trim(), "UNTRIMMED", "Surrounding whitespace is not allowed")
+        .to_uppercase();
+    assert_eq!(schema.validate(&"abc".to_string()).unwrap(), "ABC");
+    assert_eq!(schema.validate(&" abc".to_string()).unwrap_err().code, "UNTRIMMED");
+
+    // They still run after the constraints
+    let schema = s.string()
+        .pattern(r"^\d+$")
+        .refine(|s| s.starts_with('1'), "NOT_ONE", "Must start with 1")
+        .transform(|s| s.to_uppercase());
+    assert_eq!(schema.validate(&"123".to_string()).unwrap(), "123");
+    assert_eq!(schema.validate(&"abc".to_string()).unwrap_err().code, "PATTERN_ERROR");
+    assert_eq!(schema.validate(&"234".to_string()).unwrap_err().code, "NOT_ONE");
+
+    let schema = s.object()
+        .field("min", s.integer())
+        .field("max", s.integer())
+        .transform(|fields| vec![*fields["min"].downcast_ref::<i64>().unwrap(), *fields["max"].downcast_ref::<i64>().unwrap()])
+        .refine(|range| range[0] <= range[1], "INVALID_RANGE", "min must not exceed max");
+    assert_eq!(schema.validate(&json!({ "min": 1, "max": 2 })).unwrap(), vec![1, 2]);
+    assert_eq!(schema.validate(&json!({ "min": 3, "max": 2 })).unwrap_err().code, "INVALID_RANGE");
+}
+
+#[test]
+fn test_try_refine_errors() {
+    let s = schema();
+
+    let schema = s.string().try_refine(|sku| match sku_checksum_valid(sku) {
+        true => Ok(()),
+        false => Err(ValidationError::custom("INVALID_SKU", format!("'{}' has an invalid checksum", sku))
+            .with_param("sku", sku.as_str())),
+    });
+    let err = schema.validate(&"SKU-1234".to_string()).unwrap_err();
+    assert_eq!(err.message, "'SKU-1234' has an invalid checksum");
+    match err.error_type {
+        ErrorType::Custom { code, params } => {
+            assert_eq!(code, "INVALID_SKU");
+            assert_eq!(params["sku"], json!("SKU-1234"));
+        }
+        other => panic!("unexpected error type: {:?}", other),
+    }
+
+    // Any error may be returned
+    let schema = s.number().try_refine(|n| match *n < 100.0 {
+        true => Ok(()),
+        false => Err(ValidationError::new(ErrorType::TooLarge { max: 100.0, inclusive: false, got: *n }, None)),
+    });
+    assert_eq!(schema.validate(&150.0).unwrap_err().code, "TOO_LARGE");
+
+    // Without a message, custom errors name their code
+    let err = ValidationError::new(ErrorType::Custom { code: "RULE".to_string(), params: Default::default() }, None);
+    assert_eq!(err.code, "RULE");
+    assert_eq!(err.message, "Validation failed: RULE");
+}
+
+#[test]
+fn test_refine_error_aggregation() {
+    let s = schema();
+
+    // Every refinement runs and failures are combined
+    let schema = s.string()
+        .refine(|s| s.chars().any(|c| c.is_ascii_digit()), "NO_DIGIT", "Must contain a digit")
+        .refine(|s| s.chars().any(|c| c.is_ascii_uppercase()), "NO_UPPERCASE", "Must contain an uppercase letter");
+    let err = schema.validate(&"secret".to_string()).unwrap_err();
+    assert_eq!(err.code, "MULTIPLE_ERRORS");
+    let codes: Vec<_> = err.flatten().iter().map(|(_, leaf)| leaf.code.clone()).collect();
+    assert_eq!(codes, vec!["NO_DIGIT", "NO_UPPERCASE"]);
+
+    // Refinement failures are reported at the path of the value
+    let order = s.object()
+        .field("sku", s.string().refine(|sku| sku_checksum_valid(sku), "INVALID_SKU", "SKU checksum is not valid"))
+        .field("quantity", s.integer().min(1))
+        .field("gift", s.boolean().refine(|gift| !gift, "GIFTS_UNAVAILABLE", "Gift wrapping is unavailable"));
+    let schema = s.object()
+        .field("orders", s.array(order))
+        .refine(|fields| fields.contains_key("orders"), "NO_ORDERS", "Expected orders");
+
+    let err = schema.validate(&json!({
+        "orders": [
+            { "sku": "SKU-1236", "quantity": 1, "gift": false },
+            { "sku": "SKU-1234", "quantity": 0, "gift": true }
+        ]
+    })).unwrap_err();
+    let leaves: Vec<_> = err.flatten().iter().map(|(_, leaf)| (leaf.dotted_path(), leaf.code.clone())).collect();
+    assert_eq!(leaves, vec![
+        ("orders[1].gift".to_string(), "GIFTS_UNAVAILABLE".to_string()),
+        ("orders[1].quantity".to_string(), "TOO_SMALL".to_string()),
+        ("orders[1].sku".to_string(), "INVALID_SKU".to_string()),
+    ]);
+    assert!(err.to_string().contains("orders[1].sku: [INVALID_SKU] SKU checksum is not valid"));
+
+    // Object refinements only run once every field is valid
+    let schema = s.object()
+        .field("a", s.integer())
+        .refine(|_| false, "NEVER", "Never valid");
+    assert_eq!(schema.validate(&json!({ "a": "x" })).unwrap_err().flatten()[0].1.code, "TYPE_ERROR");
+    assert_eq!(schema.validate(&json!({ "a": 1 })).unwrap_err().code, "NEVER");
+}
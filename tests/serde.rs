@@ -0,0 +1,96 @@
+use schema_validator::{schema, Validate};
+use schema_validator::serde::Validated;
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Address {
+    #[validate(trim, min_length = 2)]
+    city: String,
+    zip: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct User {
+    #[validate(email)]
+    email: String,
+    addresses: Vec<Address>,
+}
+
+fn tags<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let s = schema();
+    schema_validator::serde::validate_with(deserializer, &s.array(s.string().trim().to_lowercase()).max_items(3))
+}
+
+#[derive(Debug, Deserialize)]
+struct Post {
+    title: String,
+    #[serde(deserialize_with = "tags")]
+    tags: Vec<String>,
+    #[serde(deserialize_with = "schema_validator::serde::deserialize")]
+    author: User,
+}
+
+#[test]
+fn test_validated() {
+    let user: Validated<User> = serde_json::from_str(r#"{
+        "email": "ann@example.com",
+        "addresses": [{ "city": " Berlin ", "zip": null }]
+    }"#).unwrap();
+
+    assert_eq!(user.addresses[0].city, "Berlin");
+    assert_eq!(user.into_inner().email, "ann@example.com");
+}
+
+#[test]
+fn test_validated_error_paths() {
+    let err = serde_json::from_str::<Validated<User>>(r#"{
+        "email": "nope",
+        "addresses": [{ "city": "Paris", "zip": null }, { "city": "B", "zip": null }]
+    }"#).unwrap_err();
+
+    let message = err.to_string();
+    assert!(message.contains("email: [INVALID_EMAIL]"), "{}", message);
+    assert!(message.contains("addresses[1].city: [MIN_LENGTH_ERROR]"), "{}", message);
+
+    // Syntax errors of the format are passed through
+    let err = serde_json::from_str::<Validated<User>>("{").unwrap_err();
+    assert!(err.is_eof());
+}
+
+#[test]
+fn test_deserialize_with() {
+    let post: Post = serde_json::from_str(r#"{
+        "title": "Hello",
+        "tags": [" Rust ", "SERDE"],
+        "author": { "email": "ann@example.com", "addresses": [] }
+    }"#).unwrap();
+
+    assert_eq!(post.title, "Hello");
+    assert_eq!(post.tags, vec!["rust", "serde"]);
+    assert_eq!(post.author.email, "ann@example.com");
+
+    let err = serde_json::from_str::<Post>(r#"{
+        "title": "Hello",
+        "tags": ["a", "b", "c", "d"],
+        "author": { "email": "ann@example.com", "addresses": [] }
+    }"#).unwrap_err();
+    assert!(err.to_string().starts_with("[MAX_ITEMS_ERROR]"), "{}", err);
+}
+
+#[test]
+fn test_validate_with_other_formats() {
+    let s = schema();
+    let schema = s.record(s.coerce().integer().min(0));
+
+    // Any self-describing deserializer works, here a map of strings
+    let input: HashMap<String, String> = HashMap::from([("apples".to_string(), "3".to_string())]);
+    let deserializer = serde::de::value::MapDeserializer::<_, serde::de::value::Error>::new(input.into_iter());
+    let stock: HashMap<String, i64> = schema_validator::serde::validate_with(deserializer, &schema).unwrap();
+    assert_eq!(stock["apples"], 3);
+
+    let input: HashMap<String, String> = HashMap::from([("pears".to_string(), "-1".to_string())]);
+    let deserializer = serde::de::value::MapDeserializer::<_, serde::de::value::Error>::new(input.into_iter());
+    let err = schema_validator::serde::validate_with::<_, _, HashMap<String, i64>>(deserializer, &schema).unwrap_err();
+    assert_eq!(err.to_string(), "pears: [TOO_SMALL] Range error: expected a number greater than or equal to 0, got -1");
+}
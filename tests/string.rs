@@ -1,4 +1,5 @@
 use schema_validator::{schema, Schema};
+use schema_validator::error::ErrorType;
 
 #[test]
 fn test_string_pattern() {
@@ -178,4 +179,63 @@ fn test_string_coercion() {
     let err = schema.validate(&true).unwrap_err();
     assert_eq!(err.code, "INVALID_IPV4");
     assert!(err.message.contains("IPv4"));
-}
\ No newline at end of file
+}
+#[test]
+fn test_string_collect_errors() {
+    let s = schema();
+
+    // Abort early by default
+    let schema = s.string().min_length(5).max_length(3).pattern(r"^[a-z]+$");
+    let err = schema.validate(&"A1".to_string()).unwrap_err();
+    assert_eq!(err.code, "MIN_LENGTH_ERROR");
+
+    // Collect every failing constraint
+    let schema = s.string()
+        .min_length(5)
+        .pattern(r"^[a-z]+$")
+        .abort_early(false);
+    let err = schema.validate(&"A1".to_string()).unwrap_err();
+    assert_eq!(err.code, "MULTIPLE_ERRORS");
+    assert!(err.message.contains("[MIN_LENGTH_ERROR]"));
+    assert!(err.message.contains("[PATTERN_ERROR]"));
+    match &err.error_type {
+        ErrorType::Multiple { errors } => assert_eq!(errors.len(), 2),
+        other => panic!("unexpected error type: {:?}", other),
+    }
+
+    // A single failure is reported as is
+    let err = schema.validate(&"abc".to_string()).unwrap_err();
+    assert_eq!(err.code, "MIN_LENGTH_ERROR");
+    assert!(schema.validate(&"abcdef".to_string()).is_ok());
+
+    // Globally via the builder
+    let s = s.abort_early(false);
+    let digits = s.string().max_length(3).pattern(r"^\d+$");
+    let err = digits.validate(&"abcd".to_string()).unwrap_err();
+    let codes: Vec<_> = err.flatten().into_iter().map(|(_, leaf)| leaf.code.clone()).collect();
+    assert_eq!(codes, vec!["MAX_LENGTH_ERROR", "PATTERN_ERROR"]);
+
+    // Transformed strings use the same constraints
+    let trimmed = s.string().trim().min_length(5).pattern(r"^[a-z]+$");
+    let err = trimmed.validate(&" AB ".to_string()).unwrap_err();
+    assert_eq!(err.code, "MULTIPLE_ERRORS");
+    assert!(s.string().trim().min_length(5).validate(&" hello ".to_string()).is_ok());
+}
+
+#[test]
+fn test_collected_errors_in_object() {
+    let s = schema().abort_early(false);
+
+    let schema = s.object()
+        .field("username", s.string().min_length(5).pattern(r"^[a-z]+$"));
+
+    let err = schema.validate(&serde_json::json!({ "username": "A1" })).unwrap_err();
+    let leaves: Vec<_> = err.flatten()
+        .into_iter()
+        .map(|(_, leaf)| (leaf.dotted_path(), leaf.code.clone()))
+        .collect();
+    assert_eq!(leaves, vec![
+        ("username".to_string(), "MIN_LENGTH_ERROR".to_string()),
+        ("username".to_string(), "PATTERN_ERROR".to_string()),
+    ]);
+}
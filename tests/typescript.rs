@@ -0,0 +1,151 @@
+use schema_validator::{schema, Schema, Validate};
+use schema_validator::schema::{json_schema, typescript};
+use serde_json::json;
+use std::fs;
+use std::process;
+
+#[test]
+fn test_typescript_types() {
+    let s = schema();
+
+    assert_eq!(typescript::type_of(&s.string().email()), "string");
+    assert_eq!(typescript::type_of(&s.number()), "number");
+    assert_eq!(typescript::type_of(&s.u8()), "number");
+    assert_eq!(typescript::type_of(&s.boolean()), "boolean");
+    assert_eq!(typescript::type_of(&s.literal(42_i64)), "42");
+    assert_eq!(typescript::type_of(&s.literal(true)), "true");
+    assert_eq!(typescript::type_of(&s.string().optional()), "string | null");
+    assert_eq!(typescript::type_of(&s.enumeration(["a", "b"]).optional()), "\"a\" | \"b\" | null");
+    assert_eq!(typescript::type_of(&s.array(s.string().optional())), "(string | null)[]");
+    assert_eq!(typescript::type_of(&s.array(s.array(s.integer()))), "number[][]");
+    assert_eq!(typescript::type_of(&s.record(s.boolean())), "Record<string, boolean>");
+    assert_eq!(typescript::type_of(&s.tuple(vec![s.string().boxed(), s.number().boxed()])), "[string, number]");
+    assert_eq!(typescript::type_of(&s.string().or(s.integer()).or(s.literal("x"))), "string | number | \"x\"");
+}
+
+#[test]
+fn test_typescript_object_declaration() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("id", s.integer())
+        .field("first-name", s.string())
+        .field("address", s.object()
+            .field("city", s.string())
+            .field("zip", s.string().optional()))
+        .field("kind", s.literal("person"))
+        .field("title", s.enumeration(["Dr", "Prof"]).optional());
+
+    assert_eq!(typescript::declaration("Person", &schema), "\
+export interface Person {
+  address: {
+    city: string;
+    zip?: string | null;
+  };
+  \"first-name\": string;
+  id: number;
+  kind: \"person\";
+  title?: \"Dr\" | \"Prof\" | null;
+}
+");
+
+    assert_eq!(typescript::declaration("Ids", &s.array(s.integer())), "export type Ids = number[];\n");
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Address {
+    city: String,
+    zip: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+struct Customer {
+    #[validate(rename = "fullName")]
+    name: String,
+    addresses: Vec<Address>,
+}
+
+#[derive(Debug, Clone, PartialEq, Validate)]
+enum Command {
+    Quit,
+    Move(i32, i32),
+}
+
+#[test]
+fn test_typescript_derived_declarations() {
+    assert_eq!(typescript::declaration_of::<Customer>(), "\
+export interface Customer {
+  addresses: {
+    city: string;
+    zip?: string | null;
+  }[];
+  fullName: string;
+}
+");
+
+    assert_eq!(typescript::declaration_of::<Command>(), "\
+export type Command = \"Quit\" | {
+  Move: [number, number];
+};
+");
+}
+
+#[test]
+fn test_typescript_from_json_schema() {
+    let document = json!({
+        "description": "A node of a tree",
+        "type": "object",
+        "properties": {
+            "value": { "type": ["integer", "string"] },
+            "children": { "type": "array", "items": { "$ref": "#" } },
+            "meta": { "allOf": [{ "$ref": "#/definitions/tagged" }, { "type": "object", "properties": {} }] },
+            "extra": { "type": "object", "properties": { "a": true }, "additionalProperties": { "type": "string" } }
+        },
+        "required": ["value"],
+        "definitions": {
+            "tagged": { "anyOf": [{ "type": "string" }, { "type": "null" }] }
+        }
+    });
+
+    assert_eq!(typescript::declarations_from_json_schema("TreeNode", &document), "\
+/** A node of a tree */
+export interface TreeNode {
+  children?: TreeNode[];
+  extra?: {
+    a?: unknown;
+    [key: string]: unknown;
+  };
+  meta?: Tagged & {};
+  value: number | string;
+}
+
+export type Tagged = string | null;
+");
+}
+
+#[test]
+fn test_schema_ts_binary() {
+    let s = schema();
+    let dir = std::env::temp_dir().join(format!("schema-ts-{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let schema = s.object().field("name", s.string()).field("age", s.integer().optional());
+    let input = dir.join("user-profile.json");
+    fs::write(&input, json_schema::document(&schema).to_string()).unwrap();
+
+    let output = process::Command::new(env!("CARGO_BIN_EXE_schema-ts")).arg(&input).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(fs::read_to_string(dir.join("user-profile.d.ts")).unwrap(), "\
+// Generated by schema-ts from user-profile.json. Do not edit.
+
+export interface UserProfile {
+  age?: number | null;
+  name: string;
+}
+");
+
+    let output = process::Command::new(env!("CARGO_BIN_EXE_schema-ts")).arg(dir.join("missing.json")).output().unwrap();
+    assert!(!output.status.success());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
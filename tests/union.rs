@@ -0,0 +1,206 @@
+use schema_validator::{schema, Schema};
+use schema_validator::error::ErrorType;
+use schema_validator::schema::union::UnionValue;
+use serde_json::json;
+
+#[test]
+fn test_union_first_match_wins() {
+    let s = schema();
+
+    let schema = s.union(vec![
+        s.string().email().boxed(),
+        s.string().boxed(),
+        s.number().boxed(),
+    ]);
+
+    let value = schema.validate(&"john@example.com".to_string()).unwrap();
+    assert_eq!(value.index(), 0);
+
+    let value = schema.validate(&"john".to_string()).unwrap();
+    assert_eq!(value.index(), 1);
+    assert_eq!(value.downcast_ref::<String>().unwrap(), "john");
+
+    let value = schema.validate(&1.5).unwrap();
+    assert_eq!(value.index(), 2);
+    assert!(value.is::<f64>());
+    assert_eq!(*value.into_inner().downcast::<f64>().unwrap(), 1.5);
+}
+
+#[test]
+fn test_union_reports_every_branch() {
+    let s = schema();
+
+    let schema = s.string().min_length(3).or(s.integer().min(0));
+
+    let err = schema.validate(&json!(-1)).unwrap_err();
+    assert_eq!(err.code, "UNION_ERROR");
+    match &err.error_type {
+        ErrorType::Union { errors } => {
+            assert_eq!(errors.len(), 2);
+            assert_eq!(errors[0].code, "TYPE_ERROR");
+            assert_eq!(errors[1].code, "TOO_SMALL");
+        }
+        other => panic!("unexpected error type: {:?}", other),
+    }
+    assert!(err.message.contains("variant 0: [TYPE_ERROR]"));
+    assert!(err.message.contains("variant 1: [TOO_SMALL]"));
+
+    // The union is a single failure when flattened
+    assert_eq!(err.flatten().len(), 1);
+}
+
+#[test]
+fn test_union_in_object() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("ids", s.array(s.string().or(s.integer())));
+
+    let result = schema.validate(&json!({ "ids": ["a", 2] })).unwrap();
+    let ids = result.get("ids").unwrap().downcast_ref::<Vec<UnionValue>>().unwrap();
+    assert_eq!(ids[0].downcast_ref::<String>().unwrap(), "a");
+    assert_eq!(ids[1].downcast_ref::<i64>(), Some(&2));
+
+    let err = schema.validate(&json!({ "ids": ["a", true] })).unwrap_err();
+    let leaves = err.flatten();
+    assert_eq!(leaves.len(), 1);
+    assert_eq!(leaves[0].1.code, "UNION_ERROR");
+    assert_eq!(leaves[0].1.dotted_path(), "ids[1]");
+
+    // Branch errors carry the path as well
+    if let ErrorType::Union { errors } = &leaves[0].1.error_type {
+        assert_eq!(errors[0].dotted_path(), "ids[1]");
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Scroll {
+    delta: f64,
+}
+
+impl schema_validator::schema::clone::CloneAny for Scroll {
+    fn clone_any(&self) -> Box<dyn std::any::Any> {
+        Box::new(self.clone())
+    }
+}
+
+fn event_schema() -> schema_validator::schema::union::DiscriminatedUnionSchema {
+    let s = schema();
+    s.discriminated_union("type")
+        .variant("click", s.object()
+            .field("x", s.integer())
+            .field("y", s.integer()))
+        .variant("scroll", s.object()
+            .field("delta", s.number())
+            .transform(|fields| Scroll {
+                delta: *fields.get("delta").unwrap().downcast_ref::<f64>().unwrap(),
+            }))
+}
+
+#[test]
+fn test_discriminated_union_dispatch() {
+    let schema = event_schema();
+
+    let event = schema.validate(&json!({ "type": "click", "x": 1, "y": 2 })).unwrap();
+    assert_eq!(event.index(), 0);
+    assert_eq!(event.tag(), Some("click"));
+    let fields = event.downcast_ref::<std::collections::HashMap<String, Box<dyn std::any::Any>>>().unwrap();
+    assert_eq!(fields.get("x").unwrap().downcast_ref::<i64>(), Some(&1));
+
+    let event = schema.validate(&json!({ "type": "scroll", "delta": 2.5 })).unwrap();
+    assert_eq!(event.downcast_ref::<Scroll>(), Some(&Scroll { delta: 2.5 }));
+
+    // Only the selected variant reports errors
+    let err = schema.validate(&json!({ "type": "click", "x": 1 })).unwrap_err();
+    assert_eq!(err.code, "OBJECT_ERROR");
+    let leaves = err.flatten();
+    assert_eq!(leaves.len(), 1);
+    assert_eq!(leaves[0].1.dotted_path(), "y");
+}
+
+#[test]
+fn test_union_value_clone() {
+    use schema_validator::schema::clone::CloneAny;
+
+    let s = schema();
+    let events = event_schema();
+
+    // Cloning keeps the type of the member's output
+    let event = events.validate(&json!({ "type": "scroll", "delta": 2.5 })).unwrap();
+    let copy = event.clone_any().downcast::<UnionValue>().unwrap();
+    assert_eq!(copy.tag(), Some("scroll"));
+    assert_eq!(copy.downcast_ref::<Scroll>(), Some(&Scroll { delta: 2.5 }));
+
+    let event = events.validate(&json!({ "type": "click", "x": 1, "y": 2 })).unwrap();
+    let copy = event.clone_any().downcast::<UnionValue>().unwrap();
+    let fields = copy.downcast_ref::<std::collections::HashMap<String, Box<dyn std::any::Any>>>().unwrap();
+    assert_eq!(fields.get("y").unwrap().downcast_ref::<i64>(), Some(&2));
+
+    let schema = s.union(vec![s.integer().boxed(), s.object().field("name", s.string()).boxed()]);
+    let value = schema.validate(&json!({ "name": "a" })).unwrap();
+    let copy = value.clone_any().downcast::<UnionValue>().unwrap();
+    let fields = copy.downcast_ref::<std::collections::HashMap<String, Box<dyn std::any::Any>>>().unwrap();
+    assert_eq!(fields.get("name").unwrap().downcast_ref::<String>(), Some(&"a".to_string()));
+}
+
+#[test]
+fn test_boxed_schemas_are_not_boxed_twice() {
+    let s = schema();
+
+    let schema = s.string().boxed().or(s.integer().boxed());
+    let value = schema.validate(&"abc".to_string()).unwrap();
+    assert_eq!(value.downcast_ref::<String>(), Some(&"abc".to_string()));
+    assert_eq!(schema.validate(&42_i64).unwrap().downcast_ref::<i64>(), Some(&42));
+
+    let schema = s.object()
+        .field("x", s.string().boxed())
+        .catchall(s.integer().boxed());
+    let fields = schema.validate(&json!({ "x": "abc", "y": 1 })).unwrap();
+    assert_eq!(fields["x"].downcast_ref::<String>(), Some(&"abc".to_string()));
+    assert_eq!(fields["y"].downcast_ref::<i64>(), Some(&1));
+}
+
+#[test]
+fn test_discriminated_union_invalid_tag() {
+    let schema = event_schema();
+
+    let err = schema.validate(&json!({ "type": "hover" })).unwrap_err();
+    assert_eq!(err.code, "INVALID_DISCRIMINATOR");
+    assert_eq!(err.json_pointer(), "/type");
+    match &err.error_type {
+        ErrorType::InvalidDiscriminator { field, expected, got } => {
+            assert_eq!(field, "type");
+            assert_eq!(expected, &vec!["click".to_string(), "scroll".to_string()]);
+            assert_eq!(got, "hover");
+        }
+        other => panic!("unexpected error type: {:?}", other),
+    }
+
+    let err = schema.validate(&json!({ "x": 1 })).unwrap_err();
+    assert_eq!(err.code, "MISSING_FIELD");
+    assert_eq!(err.dotted_path(), "type");
+
+    let err = schema.validate(&json!({ "type": 1 })).unwrap_err();
+    assert_eq!(err.code, "TYPE_ERROR");
+
+    assert_eq!(schema.validate(&json!("click")).unwrap_err().code, "TYPE_ERROR");
+}
+
+#[test]
+fn test_discriminated_union_nested() {
+    let s = schema();
+
+    let schema = s.object()
+        .field("events", s.array(event_schema()));
+
+    let err = schema.validate(&json!({
+        "events": [
+            { "type": "click", "x": 1, "y": 2 },
+            { "type": "drag" }
+        ]
+    })).unwrap_err();
+    let leaves = err.flatten();
+    assert_eq!(leaves.len(), 1);
+    assert_eq!(leaves[0].1.code, "INVALID_DISCRIMINATOR");
+    assert_eq!(leaves[0].1.dotted_path(), "events[1].type");
+}